@@ -0,0 +1,336 @@
+//! Benchmark of `cblas_sgemm`, `cblas_dgemm`, `cblas_cgemm` and `cblas_zgemm` on square col-major
+//! matrices.
+//!
+//! Run it by `cargo bench --bench gemm`, and the sizes could be given after `--`, such as
+//! `cargo bench --bench gemm -- 512 1024 2048`. The GFLOPS of each size is the best of a few runs.
+//!
+//! With the `openblas` feature, `sgemm_`, `dgemm_`, `cgemm_` and `zgemm_` of the system OpenBLAS
+//! are linked and measured on the same matrices, and the ratio of the two is printed as well.
+
+use roblas::common::{CBlasOrder, CBlasTranspose, Complex32, Complex64};
+use roblas::level3::{cblas_cgemm, cblas_dgemm, cblas_sgemm, cblas_zgemm};
+use std::time::Instant;
+
+#[cfg(feature = "openblas")]
+#[link(name = "openblas")]
+extern "C" {
+    fn sgemm_(
+        transa: *const u8,
+        transb: *const u8,
+        m: *const i32,
+        n: *const i32,
+        k: *const i32,
+        alpha: *const f32,
+        a: *const f32,
+        lda: *const i32,
+        b: *const f32,
+        ldb: *const i32,
+        beta: *const f32,
+        c: *mut f32,
+        ldc: *const i32,
+    );
+    fn dgemm_(
+        transa: *const u8,
+        transb: *const u8,
+        m: *const i32,
+        n: *const i32,
+        k: *const i32,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const i32,
+        b: *const f64,
+        ldb: *const i32,
+        beta: *const f64,
+        c: *mut f64,
+        ldc: *const i32,
+    );
+    fn cgemm_(
+        transa: *const u8,
+        transb: *const u8,
+        m: *const i32,
+        n: *const i32,
+        k: *const i32,
+        alpha: *const Complex32,
+        a: *const Complex32,
+        lda: *const i32,
+        b: *const Complex32,
+        ldb: *const i32,
+        beta: *const Complex32,
+        c: *mut Complex32,
+        ldc: *const i32,
+    );
+    fn zgemm_(
+        transa: *const u8,
+        transb: *const u8,
+        m: *const i32,
+        n: *const i32,
+        k: *const i32,
+        alpha: *const Complex64,
+        a: *const Complex64,
+        lda: *const i32,
+        b: *const Complex64,
+        ldb: *const i32,
+        beta: *const Complex64,
+        c: *mut Complex64,
+        ldc: *const i32,
+    );
+}
+
+/// the number of runs of each size
+const RUNS: usize = 3;
+
+/// the best GFLOPS of `RUNS` runs of `f`, which multiplies two n by n real matrices
+fn gflops<F: FnMut()>(n: usize, f: F) -> f64 {
+    flops_per_sec(2.0 * (n as f64).powi(3), f)
+}
+
+/// the best GFLOPS of `RUNS` runs of `f`, which multiplies two n by n complex matrices, where a
+/// complex multiply-add is counted as 8 floating point operations
+fn complex_gflops<F: FnMut()>(n: usize, f: F) -> f64 {
+    flops_per_sec(8.0 * (n as f64).powi(3), f)
+}
+
+fn flops_per_sec<F: FnMut()>(flops: f64, mut f: F) -> f64 {
+    let mut best = f64::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        f();
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    flops / best / 1e9
+}
+
+fn bench_sgemm(n: usize) {
+    let a: Vec<f32> = (0..n * n).map(|i| (i % 7) as f32 - 3.0).collect();
+    let b: Vec<f32> = (0..n * n).map(|i| (i % 5) as f32 - 2.0).collect();
+    let mut c = vec![0_f32; n * n];
+    let ni = n as i32;
+    let roblas = gflops(n, || unsafe {
+        cblas_sgemm(
+            CBlasOrder::ColMajor,
+            CBlasTranspose::NoTrans,
+            CBlasTranspose::NoTrans,
+            ni,
+            ni,
+            ni,
+            1.0,
+            a.as_ptr(),
+            ni,
+            b.as_ptr(),
+            ni,
+            0.0,
+            c.as_mut_ptr(),
+            ni,
+        )
+    });
+    print!("sgemm {:>5}: roblas {:>8.2} GFLOPS", n, roblas);
+    #[cfg(feature = "openblas")]
+    {
+        let openblas = gflops(n, || unsafe {
+            sgemm_(
+                &b'N',
+                &b'N',
+                &ni,
+                &ni,
+                &ni,
+                &1.0,
+                a.as_ptr(),
+                &ni,
+                b.as_ptr(),
+                &ni,
+                &0.0,
+                c.as_mut_ptr(),
+                &ni,
+            )
+        });
+        print!(
+            ", openblas {:>8.2} GFLOPS, ratio {:.2}",
+            openblas,
+            openblas / roblas
+        );
+    }
+    println!();
+}
+
+fn bench_dgemm(n: usize) {
+    let a: Vec<f64> = (0..n * n).map(|i| (i % 7) as f64 - 3.0).collect();
+    let b: Vec<f64> = (0..n * n).map(|i| (i % 5) as f64 - 2.0).collect();
+    let mut c = vec![0_f64; n * n];
+    let ni = n as i32;
+    let roblas = gflops(n, || unsafe {
+        cblas_dgemm(
+            CBlasOrder::ColMajor,
+            CBlasTranspose::NoTrans,
+            CBlasTranspose::NoTrans,
+            ni,
+            ni,
+            ni,
+            1.0,
+            a.as_ptr(),
+            ni,
+            b.as_ptr(),
+            ni,
+            0.0,
+            c.as_mut_ptr(),
+            ni,
+        )
+    });
+    print!("dgemm {:>5}: roblas {:>8.2} GFLOPS", n, roblas);
+    #[cfg(feature = "openblas")]
+    {
+        let openblas = gflops(n, || unsafe {
+            dgemm_(
+                &b'N',
+                &b'N',
+                &ni,
+                &ni,
+                &ni,
+                &1.0,
+                a.as_ptr(),
+                &ni,
+                b.as_ptr(),
+                &ni,
+                &0.0,
+                c.as_mut_ptr(),
+                &ni,
+            )
+        });
+        print!(
+            ", openblas {:>8.2} GFLOPS, ratio {:.2}",
+            openblas,
+            openblas / roblas
+        );
+    }
+    println!();
+}
+
+fn bench_cgemm(n: usize) {
+    let a: Vec<Complex32> = (0..n * n)
+        .map(|i| Complex32::new((i % 7) as f32 - 3.0, (i % 3) as f32 - 1.0))
+        .collect();
+    let b: Vec<Complex32> = (0..n * n)
+        .map(|i| Complex32::new((i % 5) as f32 - 2.0, (i % 4) as f32 - 1.5))
+        .collect();
+    let mut c = vec![Complex32::new(0.0, 0.0); n * n];
+    let (one, zero) = (Complex32::new(1.0, 0.0), Complex32::new(0.0, 0.0));
+    let ni = n as i32;
+    let roblas = complex_gflops(n, || unsafe {
+        cblas_cgemm(
+            CBlasOrder::ColMajor,
+            CBlasTranspose::NoTrans,
+            CBlasTranspose::NoTrans,
+            ni,
+            ni,
+            ni,
+            &one,
+            a.as_ptr(),
+            ni,
+            b.as_ptr(),
+            ni,
+            &zero,
+            c.as_mut_ptr(),
+            ni,
+        )
+    });
+    print!("cgemm {:>5}: roblas {:>8.2} GFLOPS", n, roblas);
+    #[cfg(feature = "openblas")]
+    {
+        let openblas = complex_gflops(n, || unsafe {
+            cgemm_(
+                &b'N',
+                &b'N',
+                &ni,
+                &ni,
+                &ni,
+                &one,
+                a.as_ptr(),
+                &ni,
+                b.as_ptr(),
+                &ni,
+                &zero,
+                c.as_mut_ptr(),
+                &ni,
+            )
+        });
+        print!(
+            ", openblas {:>8.2} GFLOPS, ratio {:.2}",
+            openblas,
+            openblas / roblas
+        );
+    }
+    println!();
+}
+
+fn bench_zgemm(n: usize) {
+    let a: Vec<Complex64> = (0..n * n)
+        .map(|i| Complex64::new((i % 7) as f64 - 3.0, (i % 3) as f64 - 1.0))
+        .collect();
+    let b: Vec<Complex64> = (0..n * n)
+        .map(|i| Complex64::new((i % 5) as f64 - 2.0, (i % 4) as f64 - 1.5))
+        .collect();
+    let mut c = vec![Complex64::new(0.0, 0.0); n * n];
+    let (one, zero) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0));
+    let ni = n as i32;
+    let roblas = complex_gflops(n, || unsafe {
+        cblas_zgemm(
+            CBlasOrder::ColMajor,
+            CBlasTranspose::NoTrans,
+            CBlasTranspose::NoTrans,
+            ni,
+            ni,
+            ni,
+            &one,
+            a.as_ptr(),
+            ni,
+            b.as_ptr(),
+            ni,
+            &zero,
+            c.as_mut_ptr(),
+            ni,
+        )
+    });
+    print!("zgemm {:>5}: roblas {:>8.2} GFLOPS", n, roblas);
+    #[cfg(feature = "openblas")]
+    {
+        let openblas = complex_gflops(n, || unsafe {
+            zgemm_(
+                &b'N',
+                &b'N',
+                &ni,
+                &ni,
+                &ni,
+                &one,
+                a.as_ptr(),
+                &ni,
+                b.as_ptr(),
+                &ni,
+                &zero,
+                c.as_mut_ptr(),
+                &ni,
+            )
+        });
+        print!(
+            ", openblas {:>8.2} GFLOPS, ratio {:.2}",
+            openblas,
+            openblas / roblas
+        );
+    }
+    println!();
+}
+
+fn main() {
+    // `cargo bench` passes `--bench` to the benchmark, which is not a size
+    let mut sizes: Vec<usize> = std::env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+    if sizes.is_empty() {
+        sizes = vec![256, 512, 1024, 2048];
+    }
+    for &n in &sizes {
+        bench_sgemm(n);
+        bench_dgemm(n);
+        bench_cgemm(n);
+        bench_zgemm(n);
+    }
+}
@@ -0,0 +1,100 @@
+//! Benchmark of `cblas_sgemv` and `cblas_dgemv` on square col-major matrices with unit stride
+//! vectors.
+//!
+//! Run it by `cargo bench --bench gemv`, and the sizes could be given after `--`, such as
+//! `cargo bench --bench gemv -- 512 4096`. The GFLOPS of each size and operation is the best of a
+//! few runs. `ROBLAS_CORETYPE=generic` gives the numbers of the portable kernels to compare with.
+
+use roblas::common::{CBlasOrder, CBlasTranspose};
+use roblas::level2::{cblas_dgemv, cblas_sgemv};
+use std::time::Instant;
+
+/// the number of runs of each size
+const RUNS: usize = 5;
+
+/// the best GFLOPS of `RUNS` runs of `f`, which multiplies an n by n matrix and a vector
+fn gflops<F: FnMut()>(n: usize, mut f: F) -> f64 {
+    let mut best = f64::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        f();
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    2.0 * (n as f64).powi(2) / best / 1e9
+}
+
+/// the operation of gemv, which is made for each call since the enums are not `Copy`
+fn op(trans: bool) -> CBlasTranspose {
+    if trans {
+        CBlasTranspose::Trans
+    } else {
+        CBlasTranspose::NoTrans
+    }
+}
+
+fn bench_sgemv(n: usize) {
+    let a: Vec<f32> = (0..n * n).map(|i| (i % 7) as f32 - 3.0).collect();
+    let x: Vec<f32> = (0..n).map(|i| (i % 5) as f32 - 2.0).collect();
+    let mut y = vec![0_f32; n];
+    let ni = n as i32;
+    for &(name, trans) in &[("N", false), ("T", true)] {
+        let roblas = gflops(n, || unsafe {
+            cblas_sgemv(
+                CBlasOrder::ColMajor,
+                op(trans),
+                ni,
+                ni,
+                1.0,
+                a.as_ptr(),
+                ni,
+                x.as_ptr(),
+                1,
+                0.0,
+                y.as_mut_ptr(),
+                1,
+            )
+        });
+        println!("sgemv {} {:>5}: {:>8.2} GFLOPS", name, n, roblas);
+    }
+}
+
+fn bench_dgemv(n: usize) {
+    let a: Vec<f64> = (0..n * n).map(|i| (i % 7) as f64 - 3.0).collect();
+    let x: Vec<f64> = (0..n).map(|i| (i % 5) as f64 - 2.0).collect();
+    let mut y = vec![0_f64; n];
+    let ni = n as i32;
+    for &(name, trans) in &[("N", false), ("T", true)] {
+        let roblas = gflops(n, || unsafe {
+            cblas_dgemv(
+                CBlasOrder::ColMajor,
+                op(trans),
+                ni,
+                ni,
+                1.0,
+                a.as_ptr(),
+                ni,
+                x.as_ptr(),
+                1,
+                0.0,
+                y.as_mut_ptr(),
+                1,
+            )
+        });
+        println!("dgemv {} {:>5}: {:>8.2} GFLOPS", name, n, roblas);
+    }
+}
+
+fn main() {
+    // `cargo bench` passes `--bench` to the benchmark, which is not a size
+    let mut sizes: Vec<usize> = std::env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+    if sizes.is_empty() {
+        sizes = vec![256, 1024, 4096];
+    }
+    for &n in &sizes {
+        bench_sgemv(n);
+        bench_dgemv(n);
+    }
+}
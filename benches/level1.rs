@@ -0,0 +1,133 @@
+//! Benchmark of the unit stride level 1 routines of f32 and f64.
+//!
+//! Run it by `cargo bench --bench level1`, and the lengths could be given after `--`, such as
+//! `cargo bench --bench level1 -- 1000 1000000`. The memory bandwidth of each routine is the best
+//! of a few runs, where every element of the vectors is counted once for each read or write.
+//! `ROBLAS_CORETYPE=generic` gives the numbers of the portable kernels to compare with.
+
+use roblas::level1::*;
+use std::time::Instant;
+
+/// the number of runs of each routine
+const RUNS: usize = 5;
+
+/// the best GB/s of `RUNS` runs of `f`, which moves `bytes` bytes
+fn bandwidth<F: FnMut()>(bytes: usize, mut f: F) -> f64 {
+    let mut best = f64::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        f();
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    bytes as f64 / best / 1e9
+}
+
+fn bench_f32(n: usize) {
+    let x: Vec<f32> = (0..n).map(|i| (i % 7) as f32 - 3.0).collect();
+    let mut y: Vec<f32> = (0..n).map(|i| (i % 5) as f32 - 2.0).collect();
+    let ni = n as i32;
+    let bytes = n * 4;
+    let mut sink = 0_f32;
+    let mut isink = 0_usize;
+    let results = [
+        (
+            "saxpy",
+            bandwidth(3 * bytes, || unsafe {
+                cblas_saxpy(ni, 1e-3, x.as_ptr(), 1, y.as_mut_ptr(), 1)
+            }),
+        ),
+        (
+            "sdot",
+            bandwidth(2 * bytes, || unsafe {
+                sink += cblas_sdot(ni, x.as_ptr(), 1, y.as_ptr(), 1)
+            }),
+        ),
+        (
+            "sscal",
+            bandwidth(2 * bytes, || unsafe {
+                cblas_sscal(ni, 1.0, y.as_mut_ptr(), 1)
+            }),
+        ),
+        (
+            "sasum",
+            bandwidth(bytes, || unsafe { sink += cblas_sasum(ni, x.as_ptr(), 1) }),
+        ),
+        (
+            "snrm2",
+            bandwidth(bytes, || unsafe { sink += cblas_snrm2(ni, x.as_ptr(), 1) }),
+        ),
+        (
+            "isamax",
+            bandwidth(bytes, || unsafe {
+                isink += cblas_isamax(ni, x.as_ptr(), 1)
+            }),
+        ),
+    ];
+    for (name, gbs) in results.iter() {
+        println!("{:<7} {:>9}: {:>8.2} GB/s", name, n, gbs);
+    }
+    // keep the results alive
+    assert!(sink.is_finite() && isink < n * RUNS);
+}
+
+fn bench_f64(n: usize) {
+    let x: Vec<f64> = (0..n).map(|i| (i % 7) as f64 - 3.0).collect();
+    let mut y: Vec<f64> = (0..n).map(|i| (i % 5) as f64 - 2.0).collect();
+    let ni = n as i32;
+    let bytes = n * 8;
+    let mut sink = 0_f64;
+    let mut isink = 0_usize;
+    let results = [
+        (
+            "daxpy",
+            bandwidth(3 * bytes, || unsafe {
+                cblas_daxpy(ni, 1e-3, x.as_ptr(), 1, y.as_mut_ptr(), 1)
+            }),
+        ),
+        (
+            "ddot",
+            bandwidth(2 * bytes, || unsafe {
+                sink += cblas_ddot(ni, x.as_ptr(), 1, y.as_ptr(), 1)
+            }),
+        ),
+        (
+            "dscal",
+            bandwidth(2 * bytes, || unsafe {
+                cblas_dscal(ni, 1.0, y.as_mut_ptr(), 1)
+            }),
+        ),
+        (
+            "dasum",
+            bandwidth(bytes, || unsafe { sink += cblas_dasum(ni, x.as_ptr(), 1) }),
+        ),
+        (
+            "dnrm2",
+            bandwidth(bytes, || unsafe { sink += cblas_dnrm2(ni, x.as_ptr(), 1) }),
+        ),
+        (
+            "idamax",
+            bandwidth(bytes, || unsafe {
+                isink += cblas_idamax(ni, x.as_ptr(), 1)
+            }),
+        ),
+    ];
+    for (name, gbs) in results.iter() {
+        println!("{:<7} {:>9}: {:>8.2} GB/s", name, n, gbs);
+    }
+    assert!(sink.is_finite() && isink < n * RUNS);
+}
+
+fn main() {
+    // `cargo bench` passes `--bench` to the benchmark, which is not a length
+    let mut lengths: Vec<usize> = std::env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+    if lengths.is_empty() {
+        lengths = vec![4096, 1 << 20, 1 << 24];
+    }
+    for &n in &lengths {
+        bench_f32(n);
+        bench_f64(n);
+    }
+}
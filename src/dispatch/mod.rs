@@ -0,0 +1,150 @@
+//! Runtime dispatch of the kernels to the running CPU.
+//!
+//! The features of the CPU are detected once, on the first call of a routine, and the best kernel
+//! set for them is chosen. A kernel set is a `Table` of function pointers, through which the
+//! routines call their kernels, in the same way as DYNAMIC_ARCH of OpenBLAS. So one binary runs
+//! the fastest kernels on every machine it is deployed to.
+//!
+//! These are the kernel sets, each of which inherits the kernels of the one before it and replaces
+//! some of them:
+//! - `generic` - the portable kernels, which leave the vectorization to the compiler,
+//! - `sse2` - SSE2 gemm micro-kernels of f32 and f64,
+//! - `haswell` - AVX2 and FMA kernels of level 1, gemv and the real and complex gemm,
+//! - `skylakex` - AVX-512 gemm micro-kernels of the real and complex types.
+//!
+//! Every routine of level 3 runs on the gemm micro-kernel of its type, which is shown by
+//! `CoreType::gemm_isa`.
+//!
+//! The environment variable `ROBLAS_CORETYPE` forces a kernel set by its name, such as
+//! `ROBLAS_CORETYPE=generic`, which is useful for testing the kernels of older CPUs on a newer one.
+//! `avx2` and `avx512` are accepted as the names of `haswell` and `skylakex` as well. If the forced
+//! kernel set is unknown or not supported by the CPU, the detected one is used instead.
+
+mod table;
+
+pub(crate) use table::Table;
+
+use std::sync::OnceLock;
+
+/// The environment variable which forces a kernel set.
+pub const CORETYPE_ENV: &str = "ROBLAS_CORETYPE";
+
+/// The kernel sets, from the oldest CPU to the newest one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CoreType {
+    Generic,
+    Sse2,
+    Haswell,
+    SkylakeX,
+}
+
+impl CoreType {
+    /// All the kernel sets.
+    pub const ALL: [CoreType; 4] = [
+        CoreType::Generic,
+        CoreType::Sse2,
+        CoreType::Haswell,
+        CoreType::SkylakeX,
+    ];
+
+    /// The name of the kernel set, which is accepted by `ROBLAS_CORETYPE`.
+    pub fn name(self) -> &'static str {
+        match self {
+            CoreType::Generic => "generic",
+            CoreType::Sse2 => "sse2",
+            CoreType::Haswell => "haswell",
+            CoreType::SkylakeX => "skylakex",
+        }
+    }
+
+    /// Find the kernel set by its name or the name of its instruction set, case-insensitive.
+    pub fn from_name(name: &str) -> Option<CoreType> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "generic" => Some(CoreType::Generic),
+            "sse2" => Some(CoreType::Sse2),
+            "haswell" | "avx2" => Some(CoreType::Haswell),
+            "skylakex" | "avx512" => Some(CoreType::SkylakeX),
+            _ => None,
+        }
+    }
+
+    /// Whether the running CPU has every feature the kernel set needs.
+    pub fn is_supported(self) -> bool {
+        match self {
+            CoreType::Generic => true,
+            #[cfg(target_arch = "x86_64")]
+            CoreType::Sse2 => is_x86_feature_detected!("sse2"),
+            #[cfg(target_arch = "x86_64")]
+            CoreType::Haswell => {
+                is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma")
+            }
+            #[cfg(target_arch = "x86_64")]
+            CoreType::SkylakeX => {
+                CoreType::Haswell.is_supported() && is_x86_feature_detected!("avx512f")
+            }
+            #[cfg(not(target_arch = "x86_64"))]
+            _ => false,
+        }
+    }
+
+    /// The newest kernel set supported by the running CPU.
+    pub fn detect() -> CoreType {
+        CoreType::ALL
+            .iter()
+            .rev()
+            .copied()
+            .find(|core| core.is_supported())
+            .unwrap_or(CoreType::Generic)
+    }
+
+    /// The instruction sets of the gemm micro-kernels of s, d, c and z in the kernel set, such as
+    /// `["avx2", "avx2", "avx2", "avx2"]` for `haswell`.
+    pub fn gemm_isa(self) -> [&'static str; 4] {
+        let table = Table::new(self);
+        [
+            table.s.gemm.isa,
+            table.d.gemm.isa,
+            table.c.gemm.isa,
+            table.z.gemm.isa,
+        ]
+    }
+}
+
+/// The kernel set used by the routines, which is chosen on the first call.
+pub fn core_type() -> CoreType {
+    static CORE: OnceLock<CoreType> = OnceLock::new();
+    *CORE.get_or_init(|| {
+        let forced = match std::env::var(CORETYPE_ENV) {
+            Ok(name) => name,
+            Err(_) => return CoreType::detect(),
+        };
+        match CoreType::from_name(&forced) {
+            Some(core) if core.is_supported() => core,
+            Some(_) => {
+                eprintln!(
+                    "{}={} is not supported by this CPU, use {} instead",
+                    CORETYPE_ENV,
+                    forced,
+                    CoreType::detect().name()
+                );
+                CoreType::detect()
+            }
+            None => {
+                eprintln!(
+                    "{}={} is unknown, use {} instead",
+                    CORETYPE_ENV,
+                    forced,
+                    CoreType::detect().name()
+                );
+                CoreType::detect()
+            }
+        }
+    })
+}
+
+/// The kernels of the chosen kernel set.
+#[inline(always)]
+pub(crate) fn table() -> &'static Table {
+    static TABLE: OnceLock<Table> = OnceLock::new();
+    TABLE.get_or_init(|| Table::new(core_type()))
+}
@@ -0,0 +1,168 @@
+use super::CoreType;
+use crate::common::{BlasIndex, BlasInt, Complex};
+use crate::level1::naive::core as l1;
+#[cfg(target_arch = "x86_64")]
+use crate::level1::x86_64 as l1_x86_64;
+use crate::level2::naive::core as l2;
+#[cfg(target_arch = "x86_64")]
+use crate::level2::x86_64 as l2_x86_64;
+use crate::level3::engine::{self, Kernel};
+
+type Axpy<T, S> = unsafe fn(BlasInt, S, *const T, BlasInt, *mut T, BlasInt);
+type Dot<T> = unsafe fn(BlasInt, *const T, BlasInt, *const T, BlasInt) -> T;
+type Scal<T, S> = unsafe fn(BlasInt, S, *mut T, BlasInt);
+/// asum and nrm2
+type Norm<T, R> = unsafe fn(BlasInt, *const T, BlasInt) -> R;
+type Iamax<T> = unsafe fn(BlasInt, *const T, BlasInt) -> BlasIndex;
+/// `S` is the type of the scalars, which are passed by pointers for complex numbers
+type Gemv<T, S> =
+    unsafe fn(char, BlasInt, BlasInt, S, *const T, BlasInt, *const T, BlasInt, S, *mut T, BlasInt);
+
+/// The kernels of s or d, which have the same arguments as the cores.
+pub(crate) struct RealKernels<T> {
+    pub axpy: Axpy<T, T>,
+    pub dot: Dot<T>,
+    pub scal: Scal<T, T>,
+    pub asum: Norm<T, T>,
+    pub nrm2: Norm<T, T>,
+    pub iamax: Iamax<T>,
+    pub gemv: Gemv<T, T>,
+    pub gemm: Kernel<T>,
+}
+
+/// The kernels of c or z, which have the same arguments as the cores.
+pub(crate) struct ComplexKernels<T> {
+    pub axpy: Axpy<Complex<T>, *const Complex<T>>,
+    pub dotu: Dot<Complex<T>>,
+    pub dotc: Dot<Complex<T>>,
+    pub scal: Scal<Complex<T>, *const Complex<T>>,
+    pub asum: Norm<Complex<T>, T>,
+    pub iamax: Iamax<Complex<T>>,
+    pub gemv: Gemv<Complex<T>, *const Complex<T>>,
+    pub gemm: Kernel<Complex<T>>,
+}
+
+/// A kernel set.
+pub(crate) struct Table {
+    pub s: RealKernels<f32>,
+    pub d: RealKernels<f64>,
+    pub c: ComplexKernels<f32>,
+    pub z: ComplexKernels<f64>,
+}
+
+impl Table {
+    /// The kernel set of `core`, which must be supported by the running CPU before any of its
+    /// kernels is called.
+    pub(crate) fn new(core: CoreType) -> Table {
+        match core {
+            CoreType::Generic => Table::generic(),
+            #[cfg(target_arch = "x86_64")]
+            CoreType::Sse2 => Table::sse2(),
+            #[cfg(target_arch = "x86_64")]
+            CoreType::Haswell => Table::haswell(),
+            #[cfg(target_arch = "x86_64")]
+            CoreType::SkylakeX => Table::skylakex(),
+            #[cfg(not(target_arch = "x86_64"))]
+            _ => Table::generic(),
+        }
+    }
+
+    fn generic() -> Table {
+        Table {
+            s: RealKernels {
+                axpy: l1::sd_axpy,
+                dot: l1::sd_sdot,
+                scal: l1::sd_scal,
+                asum: l1::sd_asum,
+                nrm2: l1::sd_nrm2,
+                iamax: l1::sd_iamax,
+                gemv: l2::sd_gemv,
+                gemm: engine::sgemm_generic(),
+            },
+            d: RealKernels {
+                axpy: l1::sd_axpy,
+                dot: l1::sd_sdot,
+                scal: l1::sd_scal,
+                asum: l1::sd_asum,
+                nrm2: l1::sd_nrm2,
+                iamax: l1::sd_iamax,
+                gemv: l2::sd_gemv,
+                gemm: engine::dgemm_generic(),
+            },
+            c: ComplexKernels {
+                axpy: l1::cz_axpy,
+                dotu: l1::cz_dotu,
+                dotc: l1::cz_dotc,
+                scal: l1::cz_scal,
+                asum: l1::cz_asum,
+                iamax: l1::cz_iamax,
+                gemv: l2::cz_gemv,
+                gemm: engine::cgemm_generic(),
+            },
+            z: ComplexKernels {
+                axpy: l1::cz_axpy,
+                dotu: l1::cz_dotu,
+                dotc: l1::cz_dotc,
+                scal: l1::cz_scal,
+                asum: l1::cz_asum,
+                iamax: l1::cz_iamax,
+                gemv: l2::cz_gemv,
+                gemm: engine::zgemm_generic(),
+            },
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn sse2() -> Table {
+        let mut table = Table::generic();
+        table.s.gemm = engine::sgemm_sse2();
+        table.d.gemm = engine::dgemm_sse2();
+        table
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn haswell() -> Table {
+        let mut table = Table::sse2();
+        table.s.axpy = l1_x86_64::saxpy;
+        table.s.dot = l1_x86_64::sdot;
+        table.s.scal = l1_x86_64::sscal;
+        table.s.asum = l1_x86_64::sasum;
+        table.s.nrm2 = l1_x86_64::snrm2;
+        table.s.iamax = l1_x86_64::isamax;
+        table.s.gemv = l2_x86_64::sgemv;
+        table.s.gemm = engine::sgemm_avx2();
+        table.d.axpy = l1_x86_64::daxpy;
+        table.d.dot = l1_x86_64::ddot;
+        table.d.scal = l1_x86_64::dscal;
+        table.d.asum = l1_x86_64::dasum;
+        table.d.nrm2 = l1_x86_64::dnrm2;
+        table.d.iamax = l1_x86_64::idamax;
+        table.d.gemv = l2_x86_64::dgemv;
+        table.d.gemm = engine::dgemm_avx2();
+        table.c.gemm = engine::cgemm_avx2();
+        table.z.gemm = engine::zgemm_avx2();
+        table.c.axpy = l1_x86_64::caxpy;
+        table.c.dotu = l1_x86_64::cdotu;
+        table.c.dotc = l1_x86_64::cdotc;
+        table.c.scal = l1_x86_64::cscal;
+        table.c.asum = l1_x86_64::scasum;
+        table.c.iamax = l1_x86_64::icamax;
+        table.z.axpy = l1_x86_64::zaxpy;
+        table.z.dotu = l1_x86_64::zdotu;
+        table.z.dotc = l1_x86_64::zdotc;
+        table.z.scal = l1_x86_64::zscal;
+        table.z.asum = l1_x86_64::dzasum;
+        table.z.iamax = l1_x86_64::izamax;
+        table
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn skylakex() -> Table {
+        let mut table = Table::haswell();
+        table.s.gemm = engine::sgemm_avx512();
+        table.d.gemm = engine::dgemm_avx512();
+        table.c.gemm = engine::cgemm_avx512();
+        table.z.gemm = engine::zgemm_avx512();
+        table
+    }
+}
@@ -3,7 +3,8 @@
 /// # Arguments
 /// `row_major` - indicate if the matrix is row major.
 ///
-/// `param_info` - the number of info error.
+/// `param_info` - the number of info error, counted as in the parameter list of the col-major
+/// cblas routine, it is transformed to the number of the parameter the user passed if `row_major`.
 ///
 /// `rout` - the name of routine.
 ///
@@ -14,14 +15,14 @@ macro_rules! xerbla {
     ($row_major:expr,$param_info:expr,$rout:expr) => {
         let param_info = crate::error::param_info_transform($row_major, $param_info as isize, $rout);
         if param_info != 0 {
-            eprintln!("Parameter {} to routine {} was incorrect", $param_info, $rout);
+            eprintln!("Parameter {} to routine {} was incorrect", param_info, $rout);
         }
         std::process::exit(-1);
     };
     ($row_major:expr,$param_info:expr,$rout:expr,$form:expr) => {
         let param_info = crate::error::param_info_transform($row_major, $param_info as isize, $rout);
         if param_info != 0 {
-            eprintln!("Parameter {} to routine {} was incorrect", $param_info, $rout);
+            eprintln!("Parameter {} to routine {} was incorrect", param_info, $rout);
         }
         eprint!($form);
         std::process::exit(-1);
@@ -29,7 +30,7 @@ macro_rules! xerbla {
     ($row_major:expr,$param_info:expr,$rout:expr,$form:expr,$($args:tt)*) => {
         let param_info = crate::error::param_info_transform($row_major, $param_info as isize, $rout);
         if param_info != 0 {
-            eprintln!("Parameter {} to routine {} was incorrect", $param_info, $rout);
+            eprintln!("Parameter {} to routine {} was incorrect", param_info, $rout);
         }
         eprint!($form, $($args)*);
         std::process::exit(-1);
@@ -40,7 +41,15 @@ pub fn param_info_transform(row_major: bool, param_info: isize, rout: &str) -> i
     if !row_major {
         param_info
     } else {
-        if rout.contains("gemm") {
+        if rout.contains("gemm_batch_strided") {
+            match param_info {
+                5 => 4,
+                4 => 5,
+                12 => 9,
+                9 => 12,
+                _ => param_info,
+            }
+        } else if rout.contains("gemm") {
             match param_info {
                 5 => 4,
                 4 => 5,
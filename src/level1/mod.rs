@@ -121,5 +121,7 @@
 //!
 //! - [x] IZAMIN - index of min abs value
 
-mod naive;
+pub(crate) mod naive;
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod x86_64;
 pub use naive::*;
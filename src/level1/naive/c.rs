@@ -1,5 +1,6 @@
 use super::core;
 use crate::common::{BlasIndex, BlasInt, Complex32};
+use crate::dispatch::table;
 
 /// CROTG construct givens plane rotation.
 ///
@@ -174,7 +175,7 @@ pub unsafe extern "C" fn cblas_cscal(
     x: *mut Complex32,
     inc_x: BlasInt,
 ) {
-    core::cz_scal(n, p_alpha, x, inc_x);
+    (table().c.scal)(n, p_alpha, x, inc_x);
 }
 
 /// CSSCAL scales a complex vector by a real constant.
@@ -273,7 +274,7 @@ pub unsafe extern "C" fn cblas_caxpy(
     y: *mut Complex32,
     inc_y: BlasInt,
 ) {
-    core::cz_axpy(n, a, x, inc_x, y, inc_y);
+    (table().c.axpy)(n, a, x, inc_x, y, inc_y);
 }
 
 /// CDOTU forms the dot product of two complex vectors
@@ -312,7 +313,7 @@ pub unsafe extern "C" fn cblas_cdotu(
     y: *const Complex32,
     inc_y: BlasInt,
 ) -> Complex32 {
-    core::cz_dotu(n, x, inc_x, y, inc_y)
+    (table().c.dotu)(n, x, inc_x, y, inc_y)
 }
 
 /// CDOTC forms the dot product of two complex vectors
@@ -353,7 +354,7 @@ pub unsafe extern "C" fn cblas_cdotc(
     cy: *const Complex32,
     inc_y: BlasInt,
 ) -> Complex32 {
-    core::cz_dotc(n, cx, inc_x, cy, inc_y)
+    (table().c.dotc)(n, cx, inc_x, cy, inc_y)
 }
 
 /// SCASUM takes the sum of the $(|Re(.)| + |Im(.)|)$'s of a complex vector and
@@ -374,7 +375,7 @@ pub unsafe extern "C" fn cblas_cdotc(
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_scasum(n: BlasInt, cx: *const Complex32, inc_x: BlasInt) -> f32 {
-    core::cz_asum(n, cx, inc_x)
+    (table().c.asum)(n, cx, inc_x)
 }
 
 /// ICAMAX finds the index of the first element having maximum $|Re(.)| + |Im(.)|$
@@ -402,7 +403,7 @@ pub unsafe extern "C" fn cblas_icamax(
     cx: *const Complex32,
     inc_x: BlasInt,
 ) -> BlasIndex {
-    core::cz_iamax(n, cx, inc_x)
+    (table().c.iamax)(n, cx, inc_x)
 }
 
 /// ICAMIN finds the index of the first element having minimum $|Re(.)| + |Im(.)|$
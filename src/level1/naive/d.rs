@@ -1,5 +1,6 @@
 use super::core;
 use crate::common::{BlasIndex, BlasInt};
+use crate::dispatch::table;
 
 /// DROTG construct givens plane rotation.
 ///
@@ -253,7 +254,7 @@ pub unsafe extern "C" fn cblas_dswap(
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_dscal(n: BlasInt, alpha: f64, x: *mut f64, inc_x: BlasInt) {
-    core::sd_scal(n, alpha, x, inc_x);
+    (table().d.scal)(n, alpha, x, inc_x);
 }
 
 /// DCOPY copies a vector, x, to a vector, y.
@@ -327,7 +328,7 @@ pub unsafe extern "C" fn cblas_daxpy(
     y: *mut f64,
     inc_y: BlasInt,
 ) {
-    core::sd_axpy(n, a, x, inc_x, y, inc_y);
+    (table().d.axpy)(n, a, x, inc_x, y, inc_y);
 }
 
 /// DDOT computes a dot product of two double precision vectors (l double precision inner product).
@@ -363,7 +364,7 @@ pub unsafe extern "C" fn cblas_ddot(
     y: *const f64,
     inc_y: BlasInt,
 ) -> f64 {
-    core::sd_sdot(n, x, inc_x, y, inc_y)
+    (table().d.dot)(n, x, inc_x, y, inc_y)
 }
 
 /// DSDOT computes a dot product (inner product) of two real vectors in double precision.
@@ -463,7 +464,7 @@ pub unsafe extern "C" fn cblas_dsdot(
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_dasum(n: BlasInt, x: *const f64, inc_x: BlasInt) -> f64 {
-    core::sd_asum(n, x, inc_x)
+    (table().d.asum)(n, x, inc_x)
 }
 
 /// DNRM2 computes the Euclidean norm of a vector.
@@ -487,7 +488,7 @@ pub unsafe extern "C" fn cblas_dasum(n: BlasInt, x: *const f64, inc_x: BlasInt)
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_dnrm2(n: BlasInt, x: *const f64, inc_x: BlasInt) -> f64 {
-    core::sd_nrm2(n, x, inc_x)
+    (table().d.nrm2)(n, x, inc_x)
 }
 
 /// IDAMAX finds the index of the element with the largest absolute value in a vector.
@@ -518,7 +519,7 @@ pub unsafe extern "C" fn cblas_dnrm2(n: BlasInt, x: *const f64, inc_x: BlasInt)
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_idamax(n: BlasInt, x: *const f64, inc_x: BlasInt) -> BlasIndex {
-    core::sd_iamax(n, x, inc_x)
+    (table().d.iamax)(n, x, inc_x)
 }
 
 /// IDAMIN finds the index of the element with the smallest absolute value in a vector.
@@ -1,5 +1,5 @@
 mod c;
-mod core;
+pub(crate) mod core;
 mod d;
 mod s;
 mod z;
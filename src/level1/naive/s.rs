@@ -1,5 +1,6 @@
 use super::core;
 use crate::common::{BlasIndex, BlasInt};
+use crate::dispatch::table;
 
 /// SROTG construct givens plane rotation.
 ///
@@ -253,7 +254,7 @@ pub unsafe extern "C" fn cblas_sswap(
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_sscal(n: BlasInt, alpha: f32, x: *mut f32, inc_x: BlasInt) {
-    core::sd_scal(n, alpha, x, inc_x);
+    (table().s.scal)(n, alpha, x, inc_x);
 }
 
 /// SCOPY copies a vector, x, to a vector, y.
@@ -327,7 +328,7 @@ pub unsafe extern "C" fn cblas_saxpy(
     y: *mut f32,
     inc_y: BlasInt,
 ) {
-    core::sd_axpy(n, a, x, inc_x, y, inc_y);
+    (table().s.axpy)(n, a, x, inc_x, y, inc_y);
 }
 
 /// SDOT computes a dot product of two real vectors (l real inner product).
@@ -363,7 +364,7 @@ pub unsafe extern "C" fn cblas_sdot(
     y: *const f32,
     inc_y: BlasInt,
 ) -> f32 {
-    core::sd_sdot(n, x, inc_x, y, inc_y)
+    (table().s.dot)(n, x, inc_x, y, inc_y)
 }
 
 /// SDSDOT computes a dot product (inner product) of two real vectors in double precision.
@@ -464,7 +465,7 @@ pub unsafe extern "C" fn cblas_sdsdot(
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_sasum(n: BlasInt, x: *const f32, inc_x: BlasInt) -> f32 {
-    core::sd_asum(n, x, inc_x)
+    (table().s.asum)(n, x, inc_x)
 }
 
 /// SNRM2 computes the Euclidean norm of a vector.
@@ -488,7 +489,7 @@ pub unsafe extern "C" fn cblas_sasum(n: BlasInt, x: *const f32, inc_x: BlasInt)
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_snrm2(n: BlasInt, x: *const f32, inc_x: BlasInt) -> f32 {
-    core::sd_nrm2(n, x, inc_x)
+    (table().s.nrm2)(n, x, inc_x)
 }
 
 /// ISAMAX finds the index of the element with the largest absolute value in a vector.
@@ -519,7 +520,7 @@ pub unsafe extern "C" fn cblas_snrm2(n: BlasInt, x: *const f32, inc_x: BlasInt)
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_isamax(n: BlasInt, x: *const f32, inc_x: BlasInt) -> BlasIndex {
-    core::sd_iamax(n, x, inc_x)
+    (table().s.iamax)(n, x, inc_x)
 }
 
 /// ISAMIN finds the index of the element with the smallest absolute value in a vector.
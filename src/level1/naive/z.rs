@@ -1,5 +1,6 @@
 use super::core;
 use crate::common::{BlasIndex, BlasInt, Complex64};
+use crate::dispatch::table;
 
 /// ZROTG construct givens plane rotation.
 ///
@@ -174,7 +175,7 @@ pub unsafe extern "C" fn cblas_zscal(
     x: *mut Complex64,
     inc_x: BlasInt,
 ) {
-    core::cz_scal(n, p_alpha, x, inc_x);
+    (table().z.scal)(n, p_alpha, x, inc_x);
 }
 
 /// ZSSCAL scales a complex vector by a real constant.
@@ -273,7 +274,7 @@ pub unsafe extern "C" fn cblas_zaxpy(
     y: *mut Complex64,
     inc_y: BlasInt,
 ) {
-    core::cz_axpy(n, a, x, inc_x, y, inc_y);
+    (table().z.axpy)(n, a, x, inc_x, y, inc_y);
 }
 
 /// ZDOTU forms the dot product of two complex vectors
@@ -312,7 +313,7 @@ pub unsafe extern "C" fn cblas_zdotu(
     y: *const Complex64,
     inc_y: BlasInt,
 ) -> Complex64 {
-    core::cz_dotu(n, x, inc_x, y, inc_y)
+    (table().z.dotu)(n, x, inc_x, y, inc_y)
 }
 
 /// ZDOTC forms the dot product of two complex vectors
@@ -353,7 +354,7 @@ pub unsafe extern "C" fn cblas_zdotc(
     cy: *const Complex64,
     inc_y: BlasInt,
 ) -> Complex64 {
-    core::cz_dotc(n, cx, inc_x, cy, inc_y)
+    (table().z.dotc)(n, cx, inc_x, cy, inc_y)
 }
 
 /// DZASUM takes the sum of the $(|Re(.)| + |Im(.)|)$'s of a complex vector and
@@ -374,7 +375,7 @@ pub unsafe extern "C" fn cblas_zdotc(
 #[no_mangle]
 #[inline(always)]
 pub unsafe extern "C" fn cblas_dzasum(n: BlasInt, cx: *const Complex64, inc_x: BlasInt) -> f64 {
-    core::cz_asum(n, cx, inc_x)
+    (table().z.asum)(n, cx, inc_x)
 }
 
 /// IZAMAX finds the index of the first element having maximum $|Re(.)| + |Im(.)|$
@@ -402,7 +403,7 @@ pub unsafe extern "C" fn cblas_izamax(
     cx: *const Complex64,
     inc_x: BlasInt,
 ) -> BlasIndex {
-    core::cz_iamax(n, cx, inc_x)
+    (table().z.iamax)(n, cx, inc_x)
 }
 
 /// IZAMIN finds the index of the first element having minimum $|Re(.)| + |Im(.)|$
@@ -0,0 +1,793 @@
+//! AVX2 and FMA kernels of level 1. They have the same arguments as the cores, take the case of
+//! unit increments and leave the strided and negative increments to the cores.
+//!
+//! The complex vectors are treated as vectors of interleaved real and imaginary parts, so a
+//! register of 8 f32 holds 4 complex numbers. The unit stride loops only stream `x` and `y`
+//! through the registers, so they are bound by the memory bandwidth for long vectors.
+
+use super::naive::core;
+use crate::common::{BlasIndex, BlasInt, Complex32, Complex64};
+use std::arch::x86_64::*;
+
+/// The sum of the lanes of `v`.
+#[target_feature(enable = "avx2,fma")]
+pub(crate) unsafe fn hsum_ps(v: __m256) -> f32 {
+    let v = _mm_add_ps(_mm256_castps256_ps128(v), _mm256_extractf128_ps(v, 1));
+    let v = _mm_add_ps(v, _mm_movehl_ps(v, v));
+    let v = _mm_add_ss(v, _mm_shuffle_ps(v, v, 1));
+    _mm_cvtss_f32(v)
+}
+
+/// The sum of the lanes of `v`.
+#[target_feature(enable = "avx2,fma")]
+pub(crate) unsafe fn hsum_pd(v: __m256d) -> f64 {
+    let v = _mm_add_pd(_mm256_castpd256_pd128(v), _mm256_extractf128_pd(v, 1));
+    let v = _mm_add_sd(v, _mm_unpackhi_pd(v, v));
+    _mm_cvtsd_f64(v)
+}
+
+/// The lanes of `v` with the sign of the odd ones flipped, the real parts of the products of the
+/// complex numbers are the sums of them.
+#[target_feature(enable = "avx2,fma")]
+unsafe fn flip_odd_ps(v: __m256) -> __m256 {
+    _mm256_xor_ps(v, _mm256_setr_ps(0., -0., 0., -0., 0., -0., 0., -0.))
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn flip_odd_pd(v: __m256d) -> __m256d {
+    _mm256_xor_pd(v, _mm256_setr_pd(0., -0., 0., -0.))
+}
+
+/// Pick the lane with the largest value from the lanes of `values` and `indices`, the one of the
+/// smallest index among the equal ones, just as the first maximum is kept by the cores.
+fn first_max<T: Copy + PartialOrd>(values: &[T], indices: &[usize], init: T) -> (T, usize) {
+    let (mut best, mut ibest) = (init, 0);
+    for (&v, &i) in values.iter().zip(indices) {
+        if v > best || (v == best && i < ibest) {
+            best = v;
+            ibest = i;
+        }
+    }
+    (best, ibest)
+}
+
+// axpy
+
+pub(crate) unsafe fn saxpy(
+    n: BlasInt,
+    a: f32,
+    x: *const f32,
+    inc_x: BlasInt,
+    y: *mut f32,
+    inc_y: BlasInt,
+) {
+    if n > 0 && inc_x == 1 && inc_y == 1 && a != 0. {
+        saxpy_kernel(n as usize, a, x, y);
+    } else {
+        core::sd_axpy(n, a, x, inc_x, y, inc_y);
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn saxpy_kernel(n: usize, a: f32, x: *const f32, y: *mut f32) {
+    let av = _mm256_set1_ps(a);
+    let mut i = 0;
+    while i + 8 <= n {
+        let yi = y.add(i);
+        let xv = _mm256_loadu_ps(x.add(i));
+        _mm256_storeu_ps(yi, _mm256_fmadd_ps(av, xv, _mm256_loadu_ps(yi)));
+        i += 8;
+    }
+    for i in i..n {
+        *y.add(i) += a * *x.add(i);
+    }
+}
+
+pub(crate) unsafe fn daxpy(
+    n: BlasInt,
+    a: f64,
+    x: *const f64,
+    inc_x: BlasInt,
+    y: *mut f64,
+    inc_y: BlasInt,
+) {
+    if n > 0 && inc_x == 1 && inc_y == 1 && a != 0. {
+        daxpy_kernel(n as usize, a, x, y);
+    } else {
+        core::sd_axpy(n, a, x, inc_x, y, inc_y);
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn daxpy_kernel(n: usize, a: f64, x: *const f64, y: *mut f64) {
+    let av = _mm256_set1_pd(a);
+    let mut i = 0;
+    while i + 4 <= n {
+        let yi = y.add(i);
+        let xv = _mm256_loadu_pd(x.add(i));
+        _mm256_storeu_pd(yi, _mm256_fmadd_pd(av, xv, _mm256_loadu_pd(yi)));
+        i += 4;
+    }
+    for i in i..n {
+        *y.add(i) += a * *x.add(i);
+    }
+}
+
+pub(crate) unsafe fn caxpy(
+    n: BlasInt,
+    a: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    y: *mut Complex32,
+    inc_y: BlasInt,
+) {
+    if n > 0 && inc_x == 1 && inc_y == 1 && (*a).l1_norm() != 0. {
+        caxpy_kernel(n as usize, *a, x, y);
+    } else {
+        core::cz_axpy(n, a, x, inc_x, y, inc_y);
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn caxpy_kernel(n: usize, a: Complex32, x: *const Complex32, y: *mut Complex32) {
+    let (ar, ai) = (_mm256_set1_ps(a.re), _mm256_set1_ps(a.im));
+    let (xf, yf) = (x as *const f32, y as *mut f32);
+    let mut i = 0;
+    while i + 4 <= n {
+        let yi = yf.add(2 * i);
+        let xv = _mm256_loadu_ps(xf.add(2 * i));
+        // (ar * xr - ai * xi, ar * xi + ai * xr)
+        let xs = _mm256_permute_ps(xv, 0b10_11_00_01);
+        let ax = _mm256_fmaddsub_ps(ar, xv, _mm256_mul_ps(ai, xs));
+        _mm256_storeu_ps(yi, _mm256_add_ps(_mm256_loadu_ps(yi), ax));
+        i += 4;
+    }
+    for i in i..n {
+        *y.add(i) = *y.add(i) + a * *x.add(i);
+    }
+}
+
+pub(crate) unsafe fn zaxpy(
+    n: BlasInt,
+    a: *const Complex64,
+    x: *const Complex64,
+    inc_x: BlasInt,
+    y: *mut Complex64,
+    inc_y: BlasInt,
+) {
+    if n > 0 && inc_x == 1 && inc_y == 1 && (*a).l1_norm() != 0. {
+        zaxpy_kernel(n as usize, *a, x, y);
+    } else {
+        core::cz_axpy(n, a, x, inc_x, y, inc_y);
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn zaxpy_kernel(n: usize, a: Complex64, x: *const Complex64, y: *mut Complex64) {
+    let (ar, ai) = (_mm256_set1_pd(a.re), _mm256_set1_pd(a.im));
+    let (xf, yf) = (x as *const f64, y as *mut f64);
+    let mut i = 0;
+    while i + 2 <= n {
+        let yi = yf.add(2 * i);
+        let xv = _mm256_loadu_pd(xf.add(2 * i));
+        let xs = _mm256_permute_pd(xv, 0b0101);
+        let ax = _mm256_fmaddsub_pd(ar, xv, _mm256_mul_pd(ai, xs));
+        _mm256_storeu_pd(yi, _mm256_add_pd(_mm256_loadu_pd(yi), ax));
+        i += 2;
+    }
+    for i in i..n {
+        *y.add(i) = *y.add(i) + a * *x.add(i);
+    }
+}
+
+// dot
+
+pub(crate) unsafe fn sdot(
+    n: BlasInt,
+    x: *const f32,
+    inc_x: BlasInt,
+    y: *const f32,
+    inc_y: BlasInt,
+) -> f32 {
+    if n > 0 && inc_x == 1 && inc_y == 1 {
+        sdot_kernel(n as usize, x, y)
+    } else {
+        core::sd_sdot(n, x, inc_x, y, inc_y)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sdot_kernel(n: usize, x: *const f32, y: *const f32) -> f32 {
+    // independent accumulators hide the latency of fma
+    let mut acc = [_mm256_setzero_ps(); 4];
+    let mut i = 0;
+    while i + 32 <= n {
+        for (u, acc) in acc.iter_mut().enumerate() {
+            let (xv, yv) = (
+                _mm256_loadu_ps(x.add(i + 8 * u)),
+                _mm256_loadu_ps(y.add(i + 8 * u)),
+            );
+            *acc = _mm256_fmadd_ps(xv, yv, *acc);
+        }
+        i += 32;
+    }
+    while i + 8 <= n {
+        acc[0] = _mm256_fmadd_ps(_mm256_loadu_ps(x.add(i)), _mm256_loadu_ps(y.add(i)), acc[0]);
+        i += 8;
+    }
+    let acc = _mm256_add_ps(_mm256_add_ps(acc[0], acc[1]), _mm256_add_ps(acc[2], acc[3]));
+    let mut dot = hsum_ps(acc);
+    for i in i..n {
+        dot += *x.add(i) * *y.add(i);
+    }
+    dot
+}
+
+pub(crate) unsafe fn ddot(
+    n: BlasInt,
+    x: *const f64,
+    inc_x: BlasInt,
+    y: *const f64,
+    inc_y: BlasInt,
+) -> f64 {
+    if n > 0 && inc_x == 1 && inc_y == 1 {
+        ddot_kernel(n as usize, x, y)
+    } else {
+        core::sd_sdot(n, x, inc_x, y, inc_y)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn ddot_kernel(n: usize, x: *const f64, y: *const f64) -> f64 {
+    let mut acc = [_mm256_setzero_pd(); 4];
+    let mut i = 0;
+    while i + 16 <= n {
+        for (u, acc) in acc.iter_mut().enumerate() {
+            let (xv, yv) = (
+                _mm256_loadu_pd(x.add(i + 4 * u)),
+                _mm256_loadu_pd(y.add(i + 4 * u)),
+            );
+            *acc = _mm256_fmadd_pd(xv, yv, *acc);
+        }
+        i += 16;
+    }
+    while i + 4 <= n {
+        acc[0] = _mm256_fmadd_pd(_mm256_loadu_pd(x.add(i)), _mm256_loadu_pd(y.add(i)), acc[0]);
+        i += 4;
+    }
+    let acc = _mm256_add_pd(_mm256_add_pd(acc[0], acc[1]), _mm256_add_pd(acc[2], acc[3]));
+    let mut dot = hsum_pd(acc);
+    for i in i..n {
+        dot += *x.add(i) * *y.add(i);
+    }
+    dot
+}
+
+pub(crate) unsafe fn cdotu(
+    n: BlasInt,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    y: *const Complex32,
+    inc_y: BlasInt,
+) -> Complex32 {
+    if n > 0 && inc_x == 1 && inc_y == 1 {
+        cdot_kernel(n as usize, x, y, false)
+    } else {
+        core::cz_dotu(n, x, inc_x, y, inc_y)
+    }
+}
+
+pub(crate) unsafe fn cdotc(
+    n: BlasInt,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    y: *const Complex32,
+    inc_y: BlasInt,
+) -> Complex32 {
+    if n > 0 && inc_x == 1 && inc_y == 1 {
+        cdot_kernel(n as usize, x, y, true)
+    } else {
+        core::cz_dotc(n, x, inc_x, y, inc_y)
+    }
+}
+
+/// The dot product of `x` and `y`, or of the conjugate of `x` and `y` if `conj` is true.
+#[target_feature(enable = "avx2,fma")]
+unsafe fn cdot_kernel(n: usize, x: *const Complex32, y: *const Complex32, conj: bool) -> Complex32 {
+    // the lanes of `re` are (xr * yr, xi * yi) and the lanes of `im` are (xr * yi, xi * yr)
+    let mut re = [_mm256_setzero_ps(); 2];
+    let mut im = [_mm256_setzero_ps(); 2];
+    let (xf, yf) = (x as *const f32, y as *const f32);
+    let mut i = 0;
+    while i + 8 <= n {
+        for u in 0..2 {
+            let xv = _mm256_loadu_ps(xf.add(2 * i + 8 * u));
+            let yv = _mm256_loadu_ps(yf.add(2 * i + 8 * u));
+            re[u] = _mm256_fmadd_ps(xv, yv, re[u]);
+            im[u] = _mm256_fmadd_ps(xv, _mm256_permute_ps(yv, 0b10_11_00_01), im[u]);
+        }
+        i += 8;
+    }
+    while i + 4 <= n {
+        let xv = _mm256_loadu_ps(xf.add(2 * i));
+        let yv = _mm256_loadu_ps(yf.add(2 * i));
+        re[0] = _mm256_fmadd_ps(xv, yv, re[0]);
+        im[0] = _mm256_fmadd_ps(xv, _mm256_permute_ps(yv, 0b10_11_00_01), im[0]);
+        i += 4;
+    }
+    let (re, im) = (_mm256_add_ps(re[0], re[1]), _mm256_add_ps(im[0], im[1]));
+    let mut dot = if conj {
+        Complex32::new(hsum_ps(re), hsum_ps(flip_odd_ps(im)))
+    } else {
+        Complex32::new(hsum_ps(flip_odd_ps(re)), hsum_ps(im))
+    };
+    for i in i..n {
+        let xi = if conj { (*x.add(i)).conj() } else { *x.add(i) };
+        dot += xi * *y.add(i);
+    }
+    dot
+}
+
+pub(crate) unsafe fn zdotu(
+    n: BlasInt,
+    x: *const Complex64,
+    inc_x: BlasInt,
+    y: *const Complex64,
+    inc_y: BlasInt,
+) -> Complex64 {
+    if n > 0 && inc_x == 1 && inc_y == 1 {
+        zdot_kernel(n as usize, x, y, false)
+    } else {
+        core::cz_dotu(n, x, inc_x, y, inc_y)
+    }
+}
+
+pub(crate) unsafe fn zdotc(
+    n: BlasInt,
+    x: *const Complex64,
+    inc_x: BlasInt,
+    y: *const Complex64,
+    inc_y: BlasInt,
+) -> Complex64 {
+    if n > 0 && inc_x == 1 && inc_y == 1 {
+        zdot_kernel(n as usize, x, y, true)
+    } else {
+        core::cz_dotc(n, x, inc_x, y, inc_y)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn zdot_kernel(n: usize, x: *const Complex64, y: *const Complex64, conj: bool) -> Complex64 {
+    let mut re = [_mm256_setzero_pd(); 2];
+    let mut im = [_mm256_setzero_pd(); 2];
+    let (xf, yf) = (x as *const f64, y as *const f64);
+    let mut i = 0;
+    while i + 4 <= n {
+        for u in 0..2 {
+            let xv = _mm256_loadu_pd(xf.add(2 * i + 4 * u));
+            let yv = _mm256_loadu_pd(yf.add(2 * i + 4 * u));
+            re[u] = _mm256_fmadd_pd(xv, yv, re[u]);
+            im[u] = _mm256_fmadd_pd(xv, _mm256_permute_pd(yv, 0b0101), im[u]);
+        }
+        i += 4;
+    }
+    while i + 2 <= n {
+        let xv = _mm256_loadu_pd(xf.add(2 * i));
+        let yv = _mm256_loadu_pd(yf.add(2 * i));
+        re[0] = _mm256_fmadd_pd(xv, yv, re[0]);
+        im[0] = _mm256_fmadd_pd(xv, _mm256_permute_pd(yv, 0b0101), im[0]);
+        i += 2;
+    }
+    let (re, im) = (_mm256_add_pd(re[0], re[1]), _mm256_add_pd(im[0], im[1]));
+    let mut dot = if conj {
+        Complex64::new(hsum_pd(re), hsum_pd(flip_odd_pd(im)))
+    } else {
+        Complex64::new(hsum_pd(flip_odd_pd(re)), hsum_pd(im))
+    };
+    for i in i..n {
+        let xi = if conj { (*x.add(i)).conj() } else { *x.add(i) };
+        dot += xi * *y.add(i);
+    }
+    dot
+}
+
+// scal
+
+pub(crate) unsafe fn sscal(n: BlasInt, alpha: f32, x: *mut f32, inc_x: BlasInt) {
+    if n > 0 && inc_x == 1 {
+        sscal_kernel(n as usize, alpha, x);
+    } else {
+        core::sd_scal(n, alpha, x, inc_x);
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sscal_kernel(n: usize, alpha: f32, x: *mut f32) {
+    let av = _mm256_set1_ps(alpha);
+    let mut i = 0;
+    while i + 8 <= n {
+        let xi = x.add(i);
+        _mm256_storeu_ps(xi, _mm256_mul_ps(av, _mm256_loadu_ps(xi)));
+        i += 8;
+    }
+    for i in i..n {
+        *x.add(i) = alpha * *x.add(i);
+    }
+}
+
+pub(crate) unsafe fn dscal(n: BlasInt, alpha: f64, x: *mut f64, inc_x: BlasInt) {
+    if n > 0 && inc_x == 1 {
+        dscal_kernel(n as usize, alpha, x);
+    } else {
+        core::sd_scal(n, alpha, x, inc_x);
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dscal_kernel(n: usize, alpha: f64, x: *mut f64) {
+    let av = _mm256_set1_pd(alpha);
+    let mut i = 0;
+    while i + 4 <= n {
+        let xi = x.add(i);
+        _mm256_storeu_pd(xi, _mm256_mul_pd(av, _mm256_loadu_pd(xi)));
+        i += 4;
+    }
+    for i in i..n {
+        *x.add(i) = alpha * *x.add(i);
+    }
+}
+
+pub(crate) unsafe fn cscal(n: BlasInt, alpha: *const Complex32, x: *mut Complex32, inc_x: BlasInt) {
+    if n > 0 && inc_x == 1 {
+        cscal_kernel(n as usize, *alpha, x);
+    } else {
+        core::cz_scal(n, alpha, x, inc_x);
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn cscal_kernel(n: usize, alpha: Complex32, x: *mut Complex32) {
+    let (ar, ai) = (_mm256_set1_ps(alpha.re), _mm256_set1_ps(alpha.im));
+    let xf = x as *mut f32;
+    let mut i = 0;
+    while i + 4 <= n {
+        let xi = xf.add(2 * i);
+        let xv = _mm256_loadu_ps(xi);
+        let xs = _mm256_permute_ps(xv, 0b10_11_00_01);
+        _mm256_storeu_ps(xi, _mm256_fmaddsub_ps(ar, xv, _mm256_mul_ps(ai, xs)));
+        i += 4;
+    }
+    for i in i..n {
+        *x.add(i) = alpha * *x.add(i);
+    }
+}
+
+pub(crate) unsafe fn zscal(n: BlasInt, alpha: *const Complex64, x: *mut Complex64, inc_x: BlasInt) {
+    if n > 0 && inc_x == 1 {
+        zscal_kernel(n as usize, *alpha, x);
+    } else {
+        core::cz_scal(n, alpha, x, inc_x);
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn zscal_kernel(n: usize, alpha: Complex64, x: *mut Complex64) {
+    let (ar, ai) = (_mm256_set1_pd(alpha.re), _mm256_set1_pd(alpha.im));
+    let xf = x as *mut f64;
+    let mut i = 0;
+    while i + 2 <= n {
+        let xi = xf.add(2 * i);
+        let xv = _mm256_loadu_pd(xi);
+        let xs = _mm256_permute_pd(xv, 0b0101);
+        _mm256_storeu_pd(xi, _mm256_fmaddsub_pd(ar, xv, _mm256_mul_pd(ai, xs)));
+        i += 2;
+    }
+    for i in i..n {
+        *x.add(i) = alpha * *x.add(i);
+    }
+}
+
+// asum, the sum of the absolute values of the real and imaginary parts for complex numbers
+
+pub(crate) unsafe fn sasum(n: BlasInt, x: *const f32, inc_x: BlasInt) -> f32 {
+    if n > 0 && inc_x == 1 {
+        sasum_kernel(n as usize, x)
+    } else {
+        core::sd_asum(n, x, inc_x)
+    }
+}
+
+pub(crate) unsafe fn scasum(n: BlasInt, x: *const Complex32, inc_x: BlasInt) -> f32 {
+    if n > 0 && inc_x == 1 {
+        sasum_kernel(2 * n as usize, x as *const f32)
+    } else {
+        core::cz_asum(n, x, inc_x)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sasum_kernel(n: usize, x: *const f32) -> f32 {
+    let sign = _mm256_set1_ps(-0.);
+    let mut acc = [_mm256_setzero_ps(); 4];
+    let mut i = 0;
+    while i + 32 <= n {
+        for (u, acc) in acc.iter_mut().enumerate() {
+            let xv = _mm256_loadu_ps(x.add(i + 8 * u));
+            *acc = _mm256_add_ps(*acc, _mm256_andnot_ps(sign, xv));
+        }
+        i += 32;
+    }
+    while i + 8 <= n {
+        acc[0] = _mm256_add_ps(acc[0], _mm256_andnot_ps(sign, _mm256_loadu_ps(x.add(i))));
+        i += 8;
+    }
+    let acc = _mm256_add_ps(_mm256_add_ps(acc[0], acc[1]), _mm256_add_ps(acc[2], acc[3]));
+    let mut asum = hsum_ps(acc);
+    for i in i..n {
+        asum += (*x.add(i)).abs();
+    }
+    asum
+}
+
+pub(crate) unsafe fn dasum(n: BlasInt, x: *const f64, inc_x: BlasInt) -> f64 {
+    if n > 0 && inc_x == 1 {
+        dasum_kernel(n as usize, x)
+    } else {
+        core::sd_asum(n, x, inc_x)
+    }
+}
+
+pub(crate) unsafe fn dzasum(n: BlasInt, x: *const Complex64, inc_x: BlasInt) -> f64 {
+    if n > 0 && inc_x == 1 {
+        dasum_kernel(2 * n as usize, x as *const f64)
+    } else {
+        core::cz_asum(n, x, inc_x)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dasum_kernel(n: usize, x: *const f64) -> f64 {
+    let sign = _mm256_set1_pd(-0.);
+    let mut acc = [_mm256_setzero_pd(); 4];
+    let mut i = 0;
+    while i + 16 <= n {
+        for (u, acc) in acc.iter_mut().enumerate() {
+            let xv = _mm256_loadu_pd(x.add(i + 4 * u));
+            *acc = _mm256_add_pd(*acc, _mm256_andnot_pd(sign, xv));
+        }
+        i += 16;
+    }
+    while i + 4 <= n {
+        acc[0] = _mm256_add_pd(acc[0], _mm256_andnot_pd(sign, _mm256_loadu_pd(x.add(i))));
+        i += 4;
+    }
+    let acc = _mm256_add_pd(_mm256_add_pd(acc[0], acc[1]), _mm256_add_pd(acc[2], acc[3]));
+    let mut asum = hsum_pd(acc);
+    for i in i..n {
+        asum += (*x.add(i)).abs();
+    }
+    asum
+}
+
+// nrm2
+//
+// The sum of squares is formed directly, which is only accurate if none of the squares overflows
+// and the underflowed ones are negligible. Otherwise the scaled algorithm of the cores is used.
+
+pub(crate) unsafe fn snrm2(n: BlasInt, x: *const f32, inc_x: BlasInt) -> f32 {
+    if n > 1 && inc_x == 1 {
+        let ssq = sdot_kernel(n as usize, x, x);
+        if ssq.is_finite() && ssq >= n as f32 * f32::MIN_POSITIVE / f32::EPSILON {
+            return ssq.sqrt();
+        }
+    }
+    core::sd_nrm2(n, x, inc_x)
+}
+
+pub(crate) unsafe fn dnrm2(n: BlasInt, x: *const f64, inc_x: BlasInt) -> f64 {
+    if n > 1 && inc_x == 1 {
+        let ssq = ddot_kernel(n as usize, x, x);
+        if ssq.is_finite() && ssq >= n as f64 * f64::MIN_POSITIVE / f64::EPSILON {
+            return ssq.sqrt();
+        }
+    }
+    core::sd_nrm2(n, x, inc_x)
+}
+
+// iamax
+//
+// Each lane keeps its own maximum and the index of it, and the lanes are merged at last. A NaN
+// is never larger than the maximum, so it is skipped as by the cores. The cores return 0 if the
+// first element is NaN, which is left to them.
+
+pub(crate) unsafe fn isamax(n: BlasInt, x: *const f32, inc_x: BlasInt) -> BlasIndex {
+    if n > 1 && inc_x == 1 && !(*x).is_nan() {
+        isamax_kernel(n as usize, x)
+    } else {
+        core::sd_iamax(n, x, inc_x)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn isamax_kernel(n: usize, x: *const f32) -> BlasIndex {
+    let sign = _mm256_set1_ps(-0.);
+    // independent maxima hide the latency of the comparisons and the blends
+    let mut max = [_mm256_set1_ps(-1.); 4];
+    let mut imax = [_mm256_setzero_ps(); 4];
+    let lane = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+    let mut i = 0;
+    while i + 32 <= n {
+        for (u, (max, imax)) in max.iter_mut().zip(imax.iter_mut()).enumerate() {
+            let j = i + 8 * u;
+            let v = _mm256_andnot_ps(sign, _mm256_loadu_ps(x.add(j)));
+            let idx = _mm256_add_epi32(lane, _mm256_set1_epi32(j as i32));
+            let gt = _mm256_cmp_ps(v, *max, _CMP_GT_OQ);
+            *max = _mm256_blendv_ps(*max, v, gt);
+            *imax = _mm256_blendv_ps(*imax, _mm256_castsi256_ps(idx), gt);
+        }
+        i += 32;
+    }
+    let mut values = [0_f32; 32];
+    let mut lanes = [0_i32; 32];
+    for (u, (max, imax)) in max.iter().zip(imax.iter()).enumerate() {
+        _mm256_storeu_ps(values.as_mut_ptr().add(8 * u), *max);
+        let lanes_u = lanes.as_mut_ptr().add(8 * u) as *mut __m256i;
+        _mm256_storeu_si256(lanes_u, _mm256_castps_si256(*imax));
+    }
+    let indices = lanes.map(|i| i as usize);
+    let (mut best, mut ibest) = first_max(&values, &indices, -1.);
+    for i in i..n {
+        let v = (*x.add(i)).abs();
+        if v > best {
+            best = v;
+            ibest = i;
+        }
+    }
+    ibest
+}
+
+pub(crate) unsafe fn idamax(n: BlasInt, x: *const f64, inc_x: BlasInt) -> BlasIndex {
+    if n > 1 && inc_x == 1 && !(*x).is_nan() {
+        idamax_kernel(n as usize, x)
+    } else {
+        core::sd_iamax(n, x, inc_x)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn idamax_kernel(n: usize, x: *const f64) -> BlasIndex {
+    let sign = _mm256_set1_pd(-0.);
+    // independent maxima hide the latency of the comparisons and the blends
+    let mut max = [_mm256_set1_pd(-1.); 4];
+    let mut imax = [_mm256_setzero_pd(); 4];
+    let lane = _mm256_setr_epi64x(0, 1, 2, 3);
+    let mut i = 0;
+    while i + 16 <= n {
+        for (u, (max, imax)) in max.iter_mut().zip(imax.iter_mut()).enumerate() {
+            let j = i + 4 * u;
+            let v = _mm256_andnot_pd(sign, _mm256_loadu_pd(x.add(j)));
+            let idx = _mm256_add_epi64(lane, _mm256_set1_epi64x(j as i64));
+            let gt = _mm256_cmp_pd(v, *max, _CMP_GT_OQ);
+            *max = _mm256_blendv_pd(*max, v, gt);
+            *imax = _mm256_blendv_pd(*imax, _mm256_castsi256_pd(idx), gt);
+        }
+        i += 16;
+    }
+    let mut values = [0_f64; 16];
+    let mut lanes = [0_i64; 16];
+    for (u, (max, imax)) in max.iter().zip(imax.iter()).enumerate() {
+        _mm256_storeu_pd(values.as_mut_ptr().add(4 * u), *max);
+        let lanes_u = lanes.as_mut_ptr().add(4 * u) as *mut __m256i;
+        _mm256_storeu_si256(lanes_u, _mm256_castpd_si256(*imax));
+    }
+    let indices = lanes.map(|i| i as usize);
+    let (mut best, mut ibest) = first_max(&values, &indices, -1.);
+    for i in i..n {
+        let v = (*x.add(i)).abs();
+        if v > best {
+            best = v;
+            ibest = i;
+        }
+    }
+    ibest
+}
+
+pub(crate) unsafe fn icamax(n: BlasInt, x: *const Complex32, inc_x: BlasInt) -> BlasIndex {
+    if n > 1 && inc_x == 1 && !(*x).l1_norm().is_nan() {
+        icamax_kernel(n as usize, x)
+    } else {
+        core::cz_iamax(n, x, inc_x)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn icamax_kernel(n: usize, x: *const Complex32) -> BlasIndex {
+    let sign = _mm256_set1_ps(-0.);
+    let xf = x as *const f32;
+    // independent maxima hide the latency of the comparisons and the blends
+    let mut max = [_mm256_set1_ps(-1.); 4];
+    let mut imax = [_mm256_setzero_ps(); 4];
+    // the lanes of the pairwise sums of two registers are in this order
+    let lane = _mm256_setr_epi32(0, 1, 4, 5, 2, 3, 6, 7);
+    let mut i = 0;
+    while i + 32 <= n {
+        for (u, (max, imax)) in max.iter_mut().zip(imax.iter_mut()).enumerate() {
+            let j = i + 8 * u;
+            let v0 = _mm256_andnot_ps(sign, _mm256_loadu_ps(xf.add(2 * j)));
+            let v1 = _mm256_andnot_ps(sign, _mm256_loadu_ps(xf.add(2 * j + 8)));
+            let v = _mm256_hadd_ps(v0, v1);
+            let idx = _mm256_add_epi32(lane, _mm256_set1_epi32(j as i32));
+            let gt = _mm256_cmp_ps(v, *max, _CMP_GT_OQ);
+            *max = _mm256_blendv_ps(*max, v, gt);
+            *imax = _mm256_blendv_ps(*imax, _mm256_castsi256_ps(idx), gt);
+        }
+        i += 32;
+    }
+    let mut values = [0_f32; 32];
+    let mut lanes = [0_i32; 32];
+    for (u, (max, imax)) in max.iter().zip(imax.iter()).enumerate() {
+        _mm256_storeu_ps(values.as_mut_ptr().add(8 * u), *max);
+        let lanes_u = lanes.as_mut_ptr().add(8 * u) as *mut __m256i;
+        _mm256_storeu_si256(lanes_u, _mm256_castps_si256(*imax));
+    }
+    let indices = lanes.map(|i| i as usize);
+    let (mut best, mut ibest) = first_max(&values, &indices, -1.);
+    for i in i..n {
+        let v = (*x.add(i)).l1_norm();
+        if v > best {
+            best = v;
+            ibest = i;
+        }
+    }
+    ibest
+}
+
+pub(crate) unsafe fn izamax(n: BlasInt, x: *const Complex64, inc_x: BlasInt) -> BlasIndex {
+    if n > 1 && inc_x == 1 && !(*x).l1_norm().is_nan() {
+        izamax_kernel(n as usize, x)
+    } else {
+        core::cz_iamax(n, x, inc_x)
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn izamax_kernel(n: usize, x: *const Complex64) -> BlasIndex {
+    let sign = _mm256_set1_pd(-0.);
+    let xf = x as *const f64;
+    // independent maxima hide the latency of the comparisons and the blends
+    let mut max = [_mm256_set1_pd(-1.); 4];
+    let mut imax = [_mm256_setzero_pd(); 4];
+    // the lanes of the pairwise sums of two registers are in this order
+    let lane = _mm256_setr_epi64x(0, 2, 1, 3);
+    let mut i = 0;
+    while i + 16 <= n {
+        for (u, (max, imax)) in max.iter_mut().zip(imax.iter_mut()).enumerate() {
+            let j = i + 4 * u;
+            let v0 = _mm256_andnot_pd(sign, _mm256_loadu_pd(xf.add(2 * j)));
+            let v1 = _mm256_andnot_pd(sign, _mm256_loadu_pd(xf.add(2 * j + 4)));
+            let v = _mm256_hadd_pd(v0, v1);
+            let idx = _mm256_add_epi64(lane, _mm256_set1_epi64x(j as i64));
+            let gt = _mm256_cmp_pd(v, *max, _CMP_GT_OQ);
+            *max = _mm256_blendv_pd(*max, v, gt);
+            *imax = _mm256_blendv_pd(*imax, _mm256_castsi256_pd(idx), gt);
+        }
+        i += 16;
+    }
+    let mut values = [0_f64; 16];
+    let mut lanes = [0_i64; 16];
+    for (u, (max, imax)) in max.iter().zip(imax.iter()).enumerate() {
+        _mm256_storeu_pd(values.as_mut_ptr().add(4 * u), *max);
+        let lanes_u = lanes.as_mut_ptr().add(4 * u) as *mut __m256i;
+        _mm256_storeu_si256(lanes_u, _mm256_castpd_si256(*imax));
+    }
+    let indices = lanes.map(|i| i as usize);
+    let (mut best, mut ibest) = first_max(&values, &indices, -1.);
+    for i in i..n {
+        let v = (*x.add(i)).l1_norm();
+        if v > best {
+            best = v;
+            ibest = i;
+        }
+    }
+    ibest
+}
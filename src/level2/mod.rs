@@ -2,37 +2,158 @@
 //!
 //! This is a list of functions:
 //! s-functions:
-//! - [ ] SGEMV - matrix vector multiply
+//! - [x] SGEMV - matrix vector multiply
 //!
-//! - [ ] SGBMV - banded matrix vector multiply
+//! - [x] SGBMV - banded matrix vector multiply
 //!
-//! - [ ] SSYMV - symmetric matrix vector multiply
+//! - [x] SSYMV - symmetric matrix vector multiply
 //!
-//! - [ ] SSBMV - symmetric banded matrix vector multiply
+//! - [x] SSBMV - symmetric banded matrix vector multiply
 //!
-//! - [ ] SSPMV - symmetric packed matrix vector multiply
+//! - [x] SSPMV - symmetric packed matrix vector multiply
 //!
-//! - [ ] STRMV - triangular matrix vector multiply
+//! - [x] STRMV - triangular matrix vector multiply
 //!
-//! - [ ] STBMV - triangular banded matrix vector multiply
+//! - [x] STBMV - triangular banded matrix vector multiply
 //!
-//! - [ ] STPMV - triangular packed matrix vector multiply
+//! - [x] STPMV - triangular packed matrix vector multiply
 //!
-//! - [ ] STRSV - solving triangular matrix problems
+//! - [x] STRSV - solving triangular matrix problems
 //!
-//! - [ ] STBSV - solving triangular banded matrix problems
+//! - [x] STBSV - solving triangular banded matrix problems
 //!
-//! - [ ] STPSV - solving triangular packed matrix problems
+//! - [x] STPSV - solving triangular packed matrix problems
 //!
-//! - [ ] SGER - performs the rank 1 operation A := alpha*x*y' + A
+//! - [x] SGER - performs the rank 1 operation A := alpha*x*y' + A
 //!
-//! - [ ] SSYR - performs the symmetric rank 1 operation A := alpha*x*x' + A
+//! - [x] SSYR - performs the symmetric rank 1 operation A := alpha*x*x' + A
 //!
-//! - [ ] SSPR - symmetric packed rank 1 operation A := alpha*x*x' + A
+//! - [x] SSPR - symmetric packed rank 1 operation A := alpha*x*x' + A
 //!
-//! - [ ] SSYR2 - performs the symmetric rank 2 operation, A := alpha*x*y' + alpha*y*x' + A
+//! - [x] SSYR2 - performs the symmetric rank 2 operation, A := alpha*x*y' + alpha*y*x' + A
 //!
-//! - [ ] SSPR2 - performs the symmetric packed rank 2 operation, A := alpha*x*y' + alpha*y*x' + A
+//! - [x] SSPR2 - performs the symmetric packed rank 2 operation, A := alpha*x*y' + alpha*y*x' + A
 //!
-mod naive;
+//! d-functions:
+//! - [x] DGEMV - matrix vector multiply
+//!
+//! - [x] DGBMV - banded matrix vector multiply
+//!
+//! - [x] DSYMV - symmetric matrix vector multiply
+//!
+//! - [x] DSBMV - symmetric banded matrix vector multiply
+//!
+//! - [x] DSPMV - symmetric packed matrix vector multiply
+//!
+//! - [x] DTRMV - triangular matrix vector multiply
+//!
+//! - [x] DTBMV - triangular banded matrix vector multiply
+//!
+//! - [x] DTPMV - triangular packed matrix vector multiply
+//!
+//! - [x] DTRSV - solving triangular matrix problems
+//!
+//! - [x] DTBSV - solving triangular banded matrix problems
+//!
+//! - [x] DTPSV - solving triangular packed matrix problems
+//!
+//! - [x] DGER - performs the rank 1 operation A := alpha*x*y' + A
+//!
+//! - [x] DSYR - performs the symmetric rank 1 operation A := alpha*x*x' + A
+//!
+//! - [x] DSPR - symmetric packed rank 1 operation A := alpha*x*x' + A
+//!
+//! - [x] DSYR2 - performs the symmetric rank 2 operation, A := alpha*x*y' + alpha*y*x' + A
+//!
+//! - [x] DSPR2 - performs the symmetric packed rank 2 operation, A := alpha*x*y' + alpha*y*x' + A
+//!
+//! c-functions:
+//! - [x] CGEMV - matrix vector multiply
+//!
+//! - [x] CGBMV - banded matrix vector multiply
+//!
+//! - [x] CHEMV - hermitian matrix vector multiply
+//!
+//! - [x] CHBMV - hermitian banded matrix vector multiply
+//!
+//! - [x] CHPMV - hermitian packed matrix vector multiply
+//!
+//! - [x] CTRMV - triangular matrix vector multiply
+//!
+//! - [x] CTBMV - triangular banded matrix vector multiply
+//!
+//! - [x] CTPMV - triangular packed matrix vector multiply
+//!
+//! - [x] CTRSV - solving triangular matrix problems
+//!
+//! - [x] CTBSV - solving triangular banded matrix problems
+//!
+//! - [x] CTPSV - solving triangular packed matrix problems
+//!
+//! - [x] CGERU - performs the rank 1 operation A := alpha*x*y' + A
+//!
+//! - [x] CGERC - performs the rank 1 operation A := alpha*x*conjg( y' ) + A
+//!
+//! - [x] CHER - hermitian rank 1 operation A := alpha*x*conjg(x') + A
+//!
+//! - [x] CHPR - hermitian packed rank 1 operation A := alpha*x*conjg( x' ) + A
+//!
+//! - [x] CHER2 - hermitian rank 2 operation
+//!
+//! - [x] CHPR2 - hermitian packed rank 2 operation
+//!
+//! - [x] CSYMV - complex symmetric matrix vector multiply
+//!
+//! - [x] CSPMV - complex symmetric packed matrix vector multiply
+//!
+//! - [x] CSYR - complex symmetric rank 1 operation A := alpha*x*x' + A
+//!
+//! - [x] CSPR - complex symmetric packed rank 1 operation A := alpha*x*x' + A
+//!
+//! z-functions:
+//! - [x] ZGEMV - matrix vector multiply
+//!
+//! - [x] ZGBMV - banded matrix vector multiply
+//!
+//! - [x] ZHEMV - hermitian matrix vector multiply
+//!
+//! - [x] ZHBMV - hermitian banded matrix vector multiply
+//!
+//! - [x] ZHPMV - hermitian packed matrix vector multiply
+//!
+//! - [x] ZTRMV - triangular matrix vector multiply
+//!
+//! - [x] ZTBMV - triangular banded matrix vector multiply
+//!
+//! - [x] ZTPMV - triangular packed matrix vector multiply
+//!
+//! - [x] ZTRSV - solving triangular matrix problems
+//!
+//! - [x] ZTBSV - solving triangular banded matrix problems
+//!
+//! - [x] ZTPSV - solving triangular packed matrix problems
+//!
+//! - [x] ZGERU - performs the rank 1 operation A := alpha*x*y' + A
+//!
+//! - [x] ZGERC - performs the rank 1 operation A := alpha*x*conjg( y' ) + A
+//!
+//! - [x] ZHER - hermitian rank 1 operation A := alpha*x*conjg(x') + A
+//!
+//! - [x] ZHPR - hermitian packed rank 1 operation A := alpha*x*conjg( x' ) + A
+//!
+//! - [x] ZHER2 - hermitian rank 2 operation
+//!
+//! - [x] ZHPR2 - hermitian packed rank 2 operation
+//!
+//! - [x] ZSYMV - complex symmetric matrix vector multiply
+//!
+//! - [x] ZSPMV - complex symmetric packed matrix vector multiply
+//!
+//! - [x] ZSYR - complex symmetric rank 1 operation A := alpha*x*x' + A
+//!
+//! - [x] ZSPR - complex symmetric packed rank 1 operation A := alpha*x*x' + A
+//!
+pub(crate) mod naive;
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod x86_64;
 pub use naive::*;
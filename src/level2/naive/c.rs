@@ -0,0 +1,1738 @@
+use super::core;
+use crate::common::{BlasInt, CBlasDiag, CBlasOrder, CBlasTranspose, CBlasUpLo, Complex32};
+use crate::dispatch::table;
+
+/// CGEMV perform one of the matrix-vector operations $y=\alpha * op(\boldsymbol{A}) * \vec{x} + \beta * \vec{y}$.
+///
+/// # Description
+/// CGEMV performs one of the matrix-vector operations
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+/// or
+/// $$\vec{y}=\alpha * \boldsymbol{A}^T * \vec{x} + \beta * \vec{y}$$
+/// or
+/// $$\vec{y}=\alpha * \boldsymbol{A}^H * \vec{x} + \beta * \vec{y}$$
+/// or
+/// $$\vec{y}=\alpha * \bar{\boldsymbol{A}} * \vec{x} + \beta * \vec{y}$$
+///
+/// The last one is selected by `CBlasTranspose::ConjNoTrans`, which is an extension of OpenBLAS.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed and/or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `m`(in) - the number of rows.
+///
+/// `n`(in) - the number of cols.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `lda`(in) - if `a` is col-major, `lda` should be the number of rows of the outer matrix;
+/// else, `lda` should be the number of cols of the outer matrix.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_cgemv(
+    order: CBlasOrder,
+    trans_a: CBlasTranspose,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex32,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    beta: *const Complex32,
+    y: *mut Complex32,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        (table().c.gemv)(ta, m, n, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so `trans_a` is flipped while keeping the conjugation.
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'R',
+            CBlasTranspose::ConjNoTrans => 'C',
+        };
+        (table().c.gemv)(ta, n, m, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_cgemv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CGBMV perform one of the matrix-vector operations $y=\alpha * op(\boldsymbol{A}) * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a banded matrix.
+///
+/// # Description
+/// CGBMV performs one of the matrix-vector operations
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+/// or
+/// $$\vec{y}=\alpha * \boldsymbol{A}^T * \vec{x} + \beta * \vec{y}$$
+/// or
+/// $$\vec{y}=\alpha * \boldsymbol{A}^H * \vec{x} + \beta * \vec{y}$$
+/// or
+/// $$\vec{y}=\alpha * \bar{\boldsymbol{A}} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an m by n band matrix, with `kl` sub-diagonals and `ku` super-diagonals.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed and/or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `m`(in) - the number of rows.
+///
+/// `n`(in) - the number of cols.
+///
+/// `kl`(in) - the number of sub-diagonals of matrix `a`.
+///
+/// `ku`(in) - the number of super-diagonals of matrix `a`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the band matrix. If `a` is col-major, $A_{ij}$ is stored in `a[(ku + i - j) + j * lda]`;
+/// else, $A_{ij}$ is stored in `a[i * lda + (kl + j - i)]`.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `kl + ku + 1`.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_cgbmv(
+    order: CBlasOrder,
+    trans_a: CBlasTranspose,
+    m: BlasInt,
+    n: BlasInt,
+    kl: BlasInt,
+    ku: BlasInt,
+    alpha: *const Complex32,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    beta: *const Complex32,
+    y: *mut Complex32,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        core::cz_gbmv(
+            ta,
+            m,
+            n,
+            kl,
+            ku,
+            alpha,
+            a,
+            lda,
+            x,
+            inc_x,
+            beta,
+            y,
+            inc_y,
+            false,
+            "cblas_cgbmv",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major band matrix is the col-major band storage of its transpose
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'R',
+            CBlasTranspose::ConjNoTrans => 'C',
+        };
+        core::cz_gbmv(
+            ta,
+            n,
+            m,
+            ku,
+            kl,
+            alpha,
+            a,
+            lda,
+            x,
+            inc_x,
+            beta,
+            y,
+            inc_y,
+            true,
+            "cblas_cgbmv",
+        );
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_cgbmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CHEMV perform the matrix-vector operation $y=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a hermitian matrix.
+///
+/// # Description
+/// CHEMV performs the matrix-vector operation
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an n by n hermitian matrix. Only the triangle selected by `uplo` is referenced,
+/// and the imaginary parts of the diagonal elements are assumed to be zero.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the hermitian matrix.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_chemv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: *const Complex32,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    beta: *const Complex32,
+    y: *mut Complex32,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_hemv(ul, false, n, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one,
+        // and for a hermitian matrix the transpose is the conjugate.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_hemv(ul, true, n, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_chemv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CSYMV perform the matrix-vector operation $y=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a complex symmetric matrix.
+///
+/// # Description
+/// CSYMV performs the matrix-vector operation
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an n by n complex symmetric matrix, $\boldsymbol{A}=\boldsymbol{A}^T$. Only the triangle selected by `uplo` is referenced.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the complex symmetric matrix.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_csymv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: *const Complex32,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    beta: *const Complex32,
+    y: *mut Complex32,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_symv(ul, n, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_symv(ul, n, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_csymv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CHBMV perform the matrix-vector operation $y=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a hermitian banded matrix.
+///
+/// # Description
+/// CHBMV performs the matrix-vector operation
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an n by n hermitian band matrix, with `k` super-diagonals.
+/// The imaginary parts of the diagonal elements are assumed to be zero.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the band matrix is supplied in `a`.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `k`(in) - the number of super-diagonals (or sub-diagonals) of matrix `a`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the band matrix, stored in the same way as [`cblas_ssbmv`](crate::level2::cblas_ssbmv).
+///
+/// `lda`(in) - the leading dimension of `a`, at least `k + 1`.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_chbmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex32,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    beta: *const Complex32,
+    y: *mut Complex32,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_hbmv(ul, false, n, k, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper band of a row-major matrix is the lower band of a col-major one,
+        // and for a hermitian matrix the transpose is the conjugate.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_hbmv(ul, true, n, k, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_chbmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CHPMV perform the matrix-vector operation $y=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a hermitian matrix in packed form.
+///
+/// # Description
+/// CHPMV performs the matrix-vector operation
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an n by n hermitian matrix, supplied in packed form.
+/// The imaginary parts of the diagonal elements are assumed to be zero.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the matrix is supplied in `ap`.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `ap`(in) - the packed matrix, with at least n * (n + 1) / 2 elements, stored in the same way as
+/// [`cblas_sspmv`](crate::level2::cblas_sspmv).
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_chpmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: *const Complex32,
+    ap: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    beta: *const Complex32,
+    y: *mut Complex32,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_hpmv(ul, false, n, alpha, ap, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle packed by rows is the lower triangle packed by columns,
+        // and for a hermitian matrix the transpose is the conjugate.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_hpmv(ul, true, n, alpha, ap, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_chpmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CSPMV perform the matrix-vector operation $y=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a complex symmetric matrix in packed form.
+///
+/// # Description
+/// CSPMV performs the matrix-vector operation
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an n by n complex symmetric matrix, $\boldsymbol{A}=\boldsymbol{A}^T$, supplied in packed form.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the matrix is supplied in `ap`.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `ap`(in) - the packed matrix, with at least n * (n + 1) / 2 elements, stored in the same way as
+/// [`cblas_sspmv`](crate::level2::cblas_sspmv).
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_cspmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: *const Complex32,
+    ap: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    beta: *const Complex32,
+    y: *mut Complex32,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_spmv(ul, n, alpha, ap, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle packed by rows is the lower triangle packed by columns
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_spmv(ul, n, alpha, ap, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_cspmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CTRMV perform one of the matrix-vector operations $\vec{x}=op(\boldsymbol{A}) * \vec{x}$,
+/// where $\boldsymbol{A}$ is a triangular matrix.
+///
+/// # Description
+/// CTRMV performs one of the matrix-vector operations
+/// $$\vec{x}=\boldsymbol{A} * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^T * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^H * \vec{x}$$
+/// or
+/// $$\vec{x}=\bar{\boldsymbol{A}} * \vec{x}$$
+///
+/// where $\boldsymbol{A}$ is an n by n unit, or non-unit, upper or lower triangular matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed and/or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether matrix `a` is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `a`(in) - the triangular matrix. Only the triangle selected by `uplo` is referenced.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+/// `x`(in, out) - vector x. On exit, it is overwritten with the transformed vector.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ctrmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *mut Complex32,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        core::cz_trmv(ul, ta, dg, n, a, lda, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        // while keeping the conjugation.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'R',
+            CBlasTranspose::ConjNoTrans => 'C',
+        };
+        core::cz_trmv(ul, ta, dg, n, a, lda, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_ctrmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CTRSV solve one of the systems of equations $op(\boldsymbol{A}) * \vec{x} = \vec{b}$,
+/// where $\boldsymbol{A}$ is a triangular matrix.
+///
+/// # Description
+/// CTRSV solves one of the systems of equations
+/// $$\boldsymbol{A} * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^T * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^H * \vec{x} = \vec{b}$$
+/// or
+/// $$\bar{\boldsymbol{A}} * \vec{x} = \vec{b}$$
+///
+/// where $\vec{b}$ and $\vec{x}$ are n element vectors and $\boldsymbol{A}$ is an n by n unit, or non-unit,
+/// upper or lower triangular matrix.
+///
+/// No test for singularity or near-singularity is included in this routine.
+/// Such tests must be performed before calling this routine.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed and/or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether matrix `a` is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `a`(in) - the triangular matrix. Only the triangle selected by `uplo` is referenced.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+/// `x`(in, out) - On entry, the right-hand side vector b. On exit, it is overwritten with the solution vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ctrsv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *mut Complex32,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        core::cz_trsv(ul, ta, dg, n, a, lda, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        // while keeping the conjugation.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'R',
+            CBlasTranspose::ConjNoTrans => 'C',
+        };
+        core::cz_trsv(ul, ta, dg, n, a, lda, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_ctrsv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CTBMV perform one of the matrix-vector operations $\vec{x}=op(\boldsymbol{A}) * \vec{x}$,
+/// where $\boldsymbol{A}$ is a triangular band matrix.
+///
+/// # Description
+/// CTBMV performs one of the matrix-vector operations
+/// $$\vec{x}=\boldsymbol{A} * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^T * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^H * \vec{x}$$
+/// or
+/// $$\vec{x}=\bar{\boldsymbol{A}} * \vec{x}$$
+///
+/// where $\boldsymbol{A}$ is an n by n unit, or non-unit, upper or lower triangular band matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether the matrix would be transposed and/or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether the matrix is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `k`(in) - the number of super-diagonals (or sub-diagonals) of the matrix.
+///
+/// `a`(in) - the band matrix. If `a` is col-major and `uplo` is `Upper`, $A_{ij}$ is stored in
+/// `a[(k + i - j) + j * lda]`; if `uplo` is `Lower`, $A_{ij}$ is stored in `a[(i - j) + j * lda]`.
+/// If `a` is row-major and `uplo` is `Upper`, $A_{ij}$ is stored in `a[i * lda + (j - i)]`;
+/// if `uplo` is `Lower`, $A_{ij}$ is stored in `a[i * lda + (k + j - i)]`.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `k + 1`.
+///
+/// `x`(in, out) - vector x. On exit, it is overwritten with the transformed vector.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ctbmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    k: BlasInt,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *mut Complex32,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        core::cz_tbmv(ul, ta, dg, n, k, a, lda, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        // while keeping the conjugation.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'R',
+            CBlasTranspose::ConjNoTrans => 'C',
+        };
+        core::cz_tbmv(ul, ta, dg, n, k, a, lda, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_ctbmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CTBSV solve one of the systems of equations $op(\boldsymbol{A}) * \vec{x} = \vec{b}$,
+/// where $\boldsymbol{A}$ is a triangular band matrix.
+///
+/// # Description
+/// CTBSV solves one of the systems of equations
+/// $$\boldsymbol{A} * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^T * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^H * \vec{x} = \vec{b}$$
+/// or
+/// $$\bar{\boldsymbol{A}} * \vec{x} = \vec{b}$$
+///
+/// where $\vec{b}$ and $\vec{x}$ are n element vectors and $\boldsymbol{A}$ is an n by n unit, or non-unit,
+/// upper or lower triangular band matrix.
+///
+/// No test for singularity or near-singularity is included in this routine.
+/// Such tests must be performed before calling this routine.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether the matrix would be transposed and/or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether the matrix is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `k`(in) - the number of super-diagonals (or sub-diagonals) of the matrix.
+///
+/// `a`(in) - the band matrix. If `a` is col-major and `uplo` is `Upper`, $A_{ij}$ is stored in
+/// `a[(k + i - j) + j * lda]`; if `uplo` is `Lower`, $A_{ij}$ is stored in `a[(i - j) + j * lda]`.
+/// If `a` is row-major and `uplo` is `Upper`, $A_{ij}$ is stored in `a[i * lda + (j - i)]`;
+/// if `uplo` is `Lower`, $A_{ij}$ is stored in `a[i * lda + (k + j - i)]`.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `k + 1`.
+///
+/// `x`(in, out) - On entry, the right-hand side vector b. On exit, it is overwritten with the solution vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ctbsv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    k: BlasInt,
+    a: *const Complex32,
+    lda: BlasInt,
+    x: *mut Complex32,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        core::cz_tbsv(ul, ta, dg, n, k, a, lda, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        // while keeping the conjugation.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'R',
+            CBlasTranspose::ConjNoTrans => 'C',
+        };
+        core::cz_tbsv(ul, ta, dg, n, k, a, lda, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_ctbsv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CTPMV perform one of the matrix-vector operations $\vec{x}=op(\boldsymbol{A}) * \vec{x}$,
+/// where $\boldsymbol{A}$ is a triangular packed matrix.
+///
+/// # Description
+/// CTPMV performs one of the matrix-vector operations
+/// $$\vec{x}=\boldsymbol{A} * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^T * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^H * \vec{x}$$
+/// or
+/// $$\vec{x}=\bar{\boldsymbol{A}} * \vec{x}$$
+///
+/// where $\boldsymbol{A}$ is an n by n unit, or non-unit, upper or lower triangular packed matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `ap` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether the matrix would be transposed and/or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether the matrix is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `ap`(in) - the packed matrix, with at least n * (n + 1) / 2 elements. The selected triangle is packed
+/// column by column if `ap` is col-major, or row by row if `ap` is row-major.
+///
+/// `x`(in, out) - vector x. On exit, it is overwritten with the transformed vector.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ctpmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    ap: *const Complex32,
+    x: *mut Complex32,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        core::cz_tpmv(ul, ta, dg, n, ap, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        // while keeping the conjugation.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'R',
+            CBlasTranspose::ConjNoTrans => 'C',
+        };
+        core::cz_tpmv(ul, ta, dg, n, ap, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_ctpmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CTPSV solve one of the systems of equations $op(\boldsymbol{A}) * \vec{x} = \vec{b}$,
+/// where $\boldsymbol{A}$ is a triangular packed matrix.
+///
+/// # Description
+/// CTPSV solves one of the systems of equations
+/// $$\boldsymbol{A} * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^T * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^H * \vec{x} = \vec{b}$$
+/// or
+/// $$\bar{\boldsymbol{A}} * \vec{x} = \vec{b}$$
+///
+/// where $\vec{b}$ and $\vec{x}$ are n element vectors and $\boldsymbol{A}$ is an n by n unit, or non-unit,
+/// upper or lower triangular packed matrix.
+///
+/// No test for singularity or near-singularity is included in this routine.
+/// Such tests must be performed before calling this routine.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `ap` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether the matrix would be transposed and/or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether the matrix is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `ap`(in) - the packed matrix, with at least n * (n + 1) / 2 elements. The selected triangle is packed
+/// column by column if `ap` is col-major, or row by row if `ap` is row-major.
+///
+/// `x`(in, out) - On entry, the right-hand side vector b. On exit, it is overwritten with the solution vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ctpsv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    ap: *const Complex32,
+    x: *mut Complex32,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        core::cz_tpsv(ul, ta, dg, n, ap, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        // while keeping the conjugation.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'R',
+            CBlasTranspose::ConjNoTrans => 'C',
+        };
+        core::cz_tpsv(ul, ta, dg, n, ap, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_ctpsv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CGERU perform the rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^T + \boldsymbol{A}$.
+///
+/// # Description
+/// CGERU performs the rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^T + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an m element vector, $\vec{y}$ is an n element vector and $\boldsymbol{A}$ is an m by n matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `m`(in) - the number of rows.
+///
+/// `n`(in) - the number of cols.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `y`(in) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+/// `a`(in, out) - matrix a. On exit, it is overwritten by the updated matrix.
+///
+/// `lda`(in) - if `a` is col-major, `lda` should be the number of rows of the outer matrix;
+/// else, `lda` should be the number of cols of the outer matrix.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_cgeru(
+    order: CBlasOrder,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    y: *const Complex32,
+    inc_y: BlasInt,
+    a: *mut Complex32,
+    lda: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        core::cz_ger(
+            false,
+            false,
+            m,
+            n,
+            alpha,
+            x,
+            inc_x,
+            y,
+            inc_y,
+            a,
+            lda,
+            false,
+            "cblas_cgeru",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, and A^T = alpha * y * x^T + A^T
+        core::cz_ger(
+            false,
+            false,
+            n,
+            m,
+            alpha,
+            y,
+            inc_y,
+            x,
+            inc_x,
+            a,
+            lda,
+            true,
+            "cblas_cgeru",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_cgeru",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CGERC perform the rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^H + \boldsymbol{A}$.
+///
+/// # Description
+/// CGERC performs the rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^H + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an m element vector, $\vec{y}$ is an n element vector and $\boldsymbol{A}$ is an m by n matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `m`(in) - the number of rows.
+///
+/// `n`(in) - the number of cols.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `y`(in) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+/// `a`(in, out) - matrix a. On exit, it is overwritten by the updated matrix.
+///
+/// `lda`(in) - if `a` is col-major, `lda` should be the number of rows of the outer matrix;
+/// else, `lda` should be the number of cols of the outer matrix.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_cgerc(
+    order: CBlasOrder,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    y: *const Complex32,
+    inc_y: BlasInt,
+    a: *mut Complex32,
+    lda: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        core::cz_ger(
+            false,
+            true,
+            m,
+            n,
+            alpha,
+            x,
+            inc_x,
+            y,
+            inc_y,
+            a,
+            lda,
+            false,
+            "cblas_cgerc",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, and A^T = alpha * conj(y) * x^T + A^T
+        core::cz_ger(
+            true,
+            false,
+            n,
+            m,
+            alpha,
+            y,
+            inc_y,
+            x,
+            inc_x,
+            a,
+            lda,
+            true,
+            "cblas_cgerc",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_cgerc",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CHER perform the hermitian rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^H + \boldsymbol{A}$.
+///
+/// # Description
+/// CHER performs the hermitian rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^H + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an n element vector and $\boldsymbol{A}$ is an n by n hermitian matrix.
+/// Only the triangle selected by `uplo` is referenced and updated, and the imaginary parts of the
+/// diagonal elements are set to zero.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `alpha`(in) - real constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `a`(in, out) - the hermitian matrix. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_cher(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: f32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    a: *mut Complex32,
+    lda: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_her(ul, false, n, alpha, x, inc_x, a, lda);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one,
+        // and for a hermitian matrix the transpose is the conjugate.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_her(ul, true, n, alpha, x, inc_x, a, lda);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_cher",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CSYR perform the complex symmetric rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^T + \boldsymbol{A}$.
+///
+/// # Description
+/// CSYR performs the complex symmetric rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^T + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an n element vector and $\boldsymbol{A}$ is an n by n complex symmetric matrix, $\boldsymbol{A}=\boldsymbol{A}^T$.
+/// Only the triangle selected by `uplo` is referenced and updated.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `a`(in, out) - the complex symmetric matrix. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_csyr(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    a: *mut Complex32,
+    lda: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_syr(ul, n, alpha, x, inc_x, a, lda);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_syr(ul, n, alpha, x, inc_x, a, lda);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_csyr",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CHER2 perform the hermitian rank 2 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^H + \bar{\alpha} * \vec{y} * \vec{x}^H + \boldsymbol{A}$.
+///
+/// # Description
+/// CHER2 performs the hermitian rank 2 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^H + \bar{\alpha} * \vec{y} * \vec{x}^H + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ and $\vec{y}$ are n element vectors and $\boldsymbol{A}$ is an n by n hermitian matrix.
+/// Only the triangle selected by `uplo` is referenced and updated, and the imaginary parts of the
+/// diagonal elements are set to zero.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `y`(in) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+/// `a`(in, out) - the hermitian matrix. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_cher2(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    y: *const Complex32,
+    inc_y: BlasInt,
+    a: *mut Complex32,
+    lda: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_her2(
+            ul,
+            false,
+            n,
+            alpha,
+            x,
+            inc_x,
+            y,
+            inc_y,
+            a,
+            lda,
+            false,
+            "cblas_cher2",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one,
+        // and for a hermitian matrix the transpose is the conjugate. x and y are swapped as in
+        // the reference cblas, which conjugates alpha.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let alpha = (*alpha).conj();
+        core::cz_her2(
+            ul,
+            true,
+            n,
+            &alpha,
+            y,
+            inc_y,
+            x,
+            inc_x,
+            a,
+            lda,
+            true,
+            "cblas_cher2",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_cher2",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CHPR perform the hermitian rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^H + \boldsymbol{A}$.
+///
+/// # Description
+/// CHPR performs the hermitian rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^H + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an n element vector and $\boldsymbol{A}$ is an n by n hermitian matrix, supplied in packed form.
+/// Only the triangle selected by `uplo` is stored and updated, and the imaginary parts of the
+/// diagonal elements are set to zero.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the matrix is supplied in `ap`.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `alpha`(in) - real constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `ap`(in, out) - the packed hermitian matrix, with at least n * (n + 1) / 2 elements, stored in the same
+/// way as [`cblas_sspmv`](crate::level2::cblas_sspmv). On exit, it is overwritten by the updated matrix.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_chpr(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: f32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    ap: *mut Complex32,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_hpr(ul, false, n, alpha, x, inc_x, ap);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one,
+        // and for a hermitian matrix the transpose is the conjugate.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_hpr(ul, true, n, alpha, x, inc_x, ap);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_chpr",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CSPR perform the complex symmetric rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^T + \boldsymbol{A}$.
+///
+/// # Description
+/// CSPR performs the complex symmetric rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^T + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an n element vector and $\boldsymbol{A}$ is an n by n complex symmetric matrix, $\boldsymbol{A}=\boldsymbol{A}^T$, supplied in packed form.
+/// Only the triangle selected by `uplo` is stored and updated.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the matrix is supplied in `ap`.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `ap`(in, out) - the packed complex symmetric matrix, with at least n * (n + 1) / 2 elements, stored in the same
+/// way as [`cblas_sspmv`](crate::level2::cblas_sspmv). On exit, it is overwritten by the updated matrix.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_cspr(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    ap: *mut Complex32,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_spr(ul, n, alpha, x, inc_x, ap);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_spr(ul, n, alpha, x, inc_x, ap);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_cspr",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// CHPR2 perform the hermitian rank 2 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^H + \bar{\alpha} * \vec{y} * \vec{x}^H + \boldsymbol{A}$.
+///
+/// # Description
+/// CHPR2 performs the hermitian rank 2 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^H + \bar{\alpha} * \vec{y} * \vec{x}^H + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ and $\vec{y}$ are n element vectors and $\boldsymbol{A}$ is an n by n hermitian matrix, supplied in packed form.
+/// Only the triangle selected by `uplo` is stored and updated, and the imaginary parts of the
+/// diagonal elements are set to zero.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the matrix is supplied in `ap`.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `y`(in) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+/// `ap`(in, out) - the packed hermitian matrix, with at least n * (n + 1) / 2 elements, stored in the same
+/// way as [`cblas_sspmv`](crate::level2::cblas_sspmv). On exit, it is overwritten by the updated matrix.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_chpr2(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: *const Complex32,
+    x: *const Complex32,
+    inc_x: BlasInt,
+    y: *const Complex32,
+    inc_y: BlasInt,
+    ap: *mut Complex32,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_hpr2(
+            ul,
+            false,
+            n,
+            alpha,
+            x,
+            inc_x,
+            y,
+            inc_y,
+            ap,
+            false,
+            "cblas_chpr2",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one,
+        // and for a hermitian matrix the transpose is the conjugate. x and y are swapped as in
+        // the reference cblas, which conjugates alpha.
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let alpha = (*alpha).conj();
+        core::cz_hpr2(
+            ul,
+            true,
+            n,
+            &alpha,
+            y,
+            inc_y,
+            x,
+            inc_x,
+            ap,
+            true,
+            "cblas_chpr2",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_chpr2",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
@@ -1,8 +1,16 @@
-use crate::common::BlasInt;
-use crate::utils::{col_major_index, letter_same};
-use num_traits::Float;
-use std::cmp::max;
-use std::ops::AddAssign;
+use crate::common::{BlasInt, Complex};
+use crate::utils::{col_major_index, letter_same, vec_start_index};
+use num_traits::{Float, Zero};
+use std::cmp::{max, min};
+use std::ops::{Add, AddAssign, Div, Mul, Sub};
+
+/// Form y := alpha * op(A) * x + y, where A is an m by n col-major matrix and both x and y have
+/// unit increments. The arguments are `m`, `n`, `alpha`, `a`, `lda`, `x` and `y`.
+pub(crate) type GemvKernel<T> = unsafe fn(usize, usize, T, *const T, usize, *const T, *mut T);
+
+/// The number of rows in a block of the unit stride gemv kernels. The block of y, or of x in the
+/// transposed case, stays in the L1 cache while the cols of A stream through.
+pub(crate) const GEMV_MB: usize = 2048;
 
 #[inline(always)]
 pub unsafe fn sd_gemv<T>(
@@ -19,6 +27,43 @@ pub unsafe fn sd_gemv<T>(
     inc_y: BlasInt,
 ) where
     T: Float + From<i8> + AddAssign,
+{
+    sd_gemv_with(
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        inc_x,
+        beta,
+        y,
+        inc_y,
+        sd_gemv_n_unit,
+        sd_gemv_t_unit,
+    );
+}
+
+/// The same as `sd_gemv`, but the unit stride cases are formed by `kernel_n` for $\boldsymbol{A}$
+/// and by `kernel_t` for $\boldsymbol{A}^T$.
+#[inline(always)]
+pub(crate) unsafe fn sd_gemv_with<T>(
+    trans: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    x: *const T,
+    inc_x: BlasInt,
+    beta: T,
+    y: *mut T,
+    inc_y: BlasInt,
+    kernel_n: GemvKernel<T>,
+    kernel_t: GemvKernel<T>,
+) where
+    T: Float + From<i8> + AddAssign,
 {
     let zero: T = From::from(0);
     let one: T = From::from(1);
@@ -46,104 +91,2955 @@ pub unsafe fn sd_gemv<T>(
         return;
     }
 
-    let len_x;
-    let len_y;
     // set `len_x` and `len_y`, the lengths of the vectors x and y,
     // and set up the start points in X and Y.
-    if letter_same(trans, 'N') {
-        len_x = n as usize;
-        len_y = m as usize;
-    } else {
-        len_x = m as usize;
-        len_y = n as usize;
-    }
-    let kx;
-    if inc_x > 0 {
-        kx = 0_usize;
-    } else {
-        kx = (len_x - 1) * (-inc_x) as usize;
-    }
-    let ky;
-    if inc_y > 0 {
-        ky = 0_usize;
+    let (len_x, len_y) = if letter_same(trans, 'N') {
+        (n as usize, m as usize)
     } else {
-        ky = (len_y - 1) * (-inc_y) as usize;
-    }
+        (m as usize, n as usize)
+    };
+    let kx = vec_start_index(len_x, inc_x);
+    let ky = vec_start_index(len_y, inc_y);
 
     // Start the operations.
     // In this version the elements of A are accessed sequentially with one pass through A.
     //
     // First form y := beta * y
     if beta != one {
-        if inc_y == 1 {
+        let mut iy = ky;
+        for _ in 0..len_y {
             if beta == zero {
-                for i in 0..len_y {
-                    *y.add(i) = zero;
-                }
+                *y.offset(iy) = zero;
             } else {
-                for i in 0..len_y {
-                    *y.add(i) = beta * (*y.add(i));
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y as isize;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    if inc_x == 1 && inc_y == 1 {
+        let kernel = if letter_same(trans, 'N') {
+            kernel_n
+        } else {
+            kernel_t
+        };
+        kernel(m as usize, n as usize, alpha, a, lda as usize, x, y);
+        return;
+    }
+    let (m, n) = (m as usize, n as usize);
+    if letter_same(trans, 'N') {
+        // Form y := alpha * A * x + y
+        let mut jx = kx;
+        for j in 0..n {
+            let temp = alpha * *x.offset(jx);
+            let mut iy = ky;
+            for i in 0..m {
+                *y.offset(iy) += temp * *a.add(col_major_index(i, j, lda));
+                iy += inc_y as isize;
+            }
+            jx += inc_x as isize;
+        }
+    } else {
+        // Form y := alpha * A^T * x + y
+        let mut jy = ky;
+        for j in 0..n {
+            let mut temp = zero;
+            let mut ix = kx;
+            for i in 0..m {
+                temp += *a.add(col_major_index(i, j, lda)) * *x.offset(ix);
+                ix += inc_x as isize;
+            }
+            *y.offset(jy) += alpha * temp;
+            jy += inc_y as isize;
+        }
+    }
+}
+
+/// The portable gemv kernel for $\boldsymbol{A}$. Four cols of A are added to a block of y at a
+/// time, so y is read and written once for every four cols.
+pub(crate) unsafe fn sd_gemv_n_unit<T>(
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: *const T,
+    lda: usize,
+    x: *const T,
+    y: *mut T,
+) where
+    T: Float + AddAssign,
+{
+    for i0 in (0..m).step_by(GEMV_MB) {
+        let mb = min(GEMV_MB, m - i0);
+        let y = std::slice::from_raw_parts_mut(y.add(i0), mb);
+        let col = |j: usize| std::slice::from_raw_parts(a.add(i0 + j * lda), mb);
+        let mut j = 0;
+        while j + 4 <= n {
+            let (t0, t1) = (alpha * *x.add(j), alpha * *x.add(j + 1));
+            let (t2, t3) = (alpha * *x.add(j + 2), alpha * *x.add(j + 3));
+            let cols = col(j)
+                .iter()
+                .zip(col(j + 1))
+                .zip(col(j + 2))
+                .zip(col(j + 3));
+            for (yi, (((&a0, &a1), &a2), &a3)) in y.iter_mut().zip(cols) {
+                *yi += t0 * a0 + t1 * a1 + t2 * a2 + t3 * a3;
+            }
+            j += 4;
+        }
+        for j in j..n {
+            let temp = alpha * *x.add(j);
+            for (yi, &aij) in y.iter_mut().zip(col(j)) {
+                *yi += temp * aij;
+            }
+        }
+    }
+}
+
+/// The portable gemv kernel for $\boldsymbol{A}^T$. The dot product of each col of A and a block
+/// of x is split into four partial sums, which are independent of each other.
+pub(crate) unsafe fn sd_gemv_t_unit<T>(
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: *const T,
+    lda: usize,
+    x: *const T,
+    y: *mut T,
+) where
+    T: Float + AddAssign,
+{
+    for i0 in (0..m).step_by(GEMV_MB) {
+        let mb = min(GEMV_MB, m - i0);
+        let x = std::slice::from_raw_parts(x.add(i0), mb);
+        for j in 0..n {
+            let col = std::slice::from_raw_parts(a.add(i0 + j * lda), mb);
+            let mut acc = [T::zero(); 4];
+            let (col_chunks, x_chunks) = (col.chunks_exact(4), x.chunks_exact(4));
+            let tail: T = col_chunks
+                .remainder()
+                .iter()
+                .zip(x_chunks.remainder())
+                .fold(T::zero(), |sum, (&aij, &xi)| sum + aij * xi);
+            for (ac, xc) in col_chunks.zip(x_chunks) {
+                for ((acc, &aij), &xi) in acc.iter_mut().zip(ac).zip(xc) {
+                    *acc += aij * xi;
                 }
             }
+            *y.add(j) += alpha * ((acc[0] + acc[1]) + (acc[2] + acc[3]) + tail);
+        }
+    }
+}
+
+/// Complex version of gemv. Besides 'N', 'T' and 'C', `trans` also accepts 'R', which means
+/// $\bar{\boldsymbol{A}}$ (conjugate without transpose).
+#[inline(always)]
+pub unsafe fn cz_gemv<T>(
+    trans: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    beta: *const Complex<T>,
+    y: *mut Complex<T>,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8>,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    // first, check `trans`
+    let mut info = 0;
+    if !letter_same(trans, 'N')
+        && !letter_same(trans, 'T')
+        && !letter_same(trans, 'C')
+        && !letter_same(trans, 'R')
+    {
+        info = 1;
+    } else if m < 0 {
+        info = 2;
+    } else if n < 0 {
+        info = 3;
+    } else if lda < max(1, m) {
+        info = 6;
+    } else if inc_x == 0 {
+        info = 8;
+    } else if inc_y == 0 {
+        info = 11;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CGEMV");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
         } else {
+            aij
+        }
+    };
+
+    // set `len_x` and `len_y`, the lengths of the vectors x and y,
+    // and set up the start points in X and Y.
+    let (len_x, len_y) = if no_trans {
+        (n as usize, m as usize)
+    } else {
+        (m as usize, n as usize)
+    };
+    let kx = vec_start_index(len_x, inc_x);
+    let ky = vec_start_index(len_y, inc_y);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..len_y {
             if beta == zero {
-                for i in (0..(len_y * inc_y as usize)).step_by(inc_y as usize) {
-                    *y.add(i) = zero;
-                }
+                *y.offset(iy) = zero;
             } else {
-                for i in (0..(len_y * inc_y as usize)).step_by(inc_y as usize) {
-                    *y.add(i) = beta * (*y.add(i));
-                }
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y as isize;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    if no_trans {
+        // Form y := alpha * A * x + y or y := alpha * conj(A) * x + y
+        let mut jx = kx;
+        for j in 0..n as usize {
+            let temp = alpha * *x.offset(jx);
+            let mut iy = ky;
+            for i in 0..m as usize {
+                *y.offset(iy) = *y.offset(iy) + temp * elem(i, j);
+                iy += inc_y as isize;
+            }
+            jx += inc_x as isize;
+        }
+    } else {
+        // Form y := alpha * A^T * x + y or y := alpha * A^H * x + y
+        let mut jy = ky;
+        for j in 0..n as usize {
+            let mut temp = zero;
+            let mut ix = kx;
+            for i in 0..m as usize {
+                temp = temp + elem(i, j) * *x.offset(ix);
+                ix += inc_x as isize;
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp;
+            jy += inc_y as isize;
+        }
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_gbmv<T>(
+    trans: char,
+    m: BlasInt,
+    n: BlasInt,
+    kl: BlasInt,
+    ku: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    x: *const T,
+    inc_x: BlasInt,
+    beta: T,
+    y: *mut T,
+    inc_y: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // first, check `trans`
+    let mut info = 0;
+    if !letter_same(trans, 'N') && !letter_same(trans, 'T') && !letter_same(trans, 'C') {
+        info = 1;
+    } else if m < 0 {
+        info = 2;
+    } else if n < 0 {
+        info = 3;
+    } else if kl < 0 {
+        info = 4;
+    } else if ku < 0 {
+        info = 5;
+    } else if lda < kl + ku + 1 {
+        info = 8;
+    } else if inc_x == 0 {
+        info = 10;
+    } else if inc_y == 0 {
+        info = 13;
+    }
+    if info != 0 {
+        // `rout` is the cblas routine, whose parameters start with `order`
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let (len_x, len_y) = if letter_same(trans, 'N') {
+        (n as usize, m as usize)
+    } else {
+        (m as usize, n as usize)
+    };
+    let kx = vec_start_index(len_x, inc_x);
+    let ky = vec_start_index(len_y, inc_y);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..len_y {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
             }
+            iy += inc_y as isize;
         }
     }
     if alpha == zero {
         return;
     }
+    // the element A(i, j) is stored in a(ku + i - j, j)
+    let (m, n, kl, ku) = (m as usize, n as usize, kl as usize, ku as usize);
     if letter_same(trans, 'N') {
         // Form y := alpha * A * x + y
         let mut jx = kx;
-        if inc_y == 1 {
-            for j in 0..n as usize {
-                let temp = alpha * *x.add(jx);
-                for i in 0..m as usize {
-                    *y.add(i) += temp * *a.add(col_major_index(i, j, lda));
-                }
-                jx += inc_x as usize;
-            }
-        } else {
-            for j in 0..n as usize {
-                let temp = alpha * *x.add(jx);
-                let mut iy = ky;
-                for i in 0..m as usize {
-                    *y.add(iy) += temp * *a.add(col_major_index(i, j, lda));
-                    iy += inc_y as usize;
-                }
-                jx += inc_x as usize;
+        for j in 0..n {
+            let temp = alpha * *x.offset(jx);
+            let i_start = j.saturating_sub(ku);
+            let i_end = min(m, j + kl + 1);
+            let mut iy = ky + i_start as isize * inc_y as isize;
+            for i in i_start..i_end {
+                *y.offset(iy) += temp * *a.add(col_major_index(ku + i - j, j, lda));
+                iy += inc_y as isize;
             }
+            jx += inc_x as isize;
         }
     } else {
         // Form y := alpha * A^T * x + y
         let mut jy = ky;
-        if inc_x == 1 {
-            for j in 0..n as usize {
-                let mut temp = zero;
-                for i in 0..m as usize {
-                    temp += *a.add(col_major_index(i, j, lda)) * *x.add(i);
-                }
-                *y.add(jy) += alpha * temp;
-                jy += inc_y as usize;
+        for j in 0..n {
+            let mut temp = zero;
+            let i_start = j.saturating_sub(ku);
+            let i_end = min(m, j + kl + 1);
+            let mut ix = kx + i_start as isize * inc_x as isize;
+            for i in i_start..i_end {
+                temp += *a.add(col_major_index(ku + i - j, j, lda)) * *x.offset(ix);
+                ix += inc_x as isize;
             }
+            *y.offset(jy) += alpha * temp;
+            jy += inc_y as isize;
+        }
+    }
+}
+
+/// Complex version of gbmv. `trans` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemv`.
+#[inline(always)]
+pub unsafe fn cz_gbmv<T>(
+    trans: char,
+    m: BlasInt,
+    n: BlasInt,
+    kl: BlasInt,
+    ku: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    beta: *const Complex<T>,
+    y: *mut Complex<T>,
+    inc_y: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    // first, check `trans`
+    let mut info = 0;
+    if !letter_same(trans, 'N')
+        && !letter_same(trans, 'T')
+        && !letter_same(trans, 'C')
+        && !letter_same(trans, 'R')
+    {
+        info = 1;
+    } else if m < 0 {
+        info = 2;
+    } else if n < 0 {
+        info = 3;
+    } else if kl < 0 {
+        info = 4;
+    } else if ku < 0 {
+        info = 5;
+    } else if lda < kl + ku + 1 {
+        info = 8;
+    } else if inc_x == 0 {
+        info = 10;
+    } else if inc_y == 0 {
+        info = 13;
+    }
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
         } else {
-            for j in 0..n as usize {
-                let mut temp = zero;
-                let mut ix = kx;
-                for i in 0..m as usize {
-                    temp += *a.add(col_major_index(i, j, lda)) * *x.add(ix);
-                    ix += inc_x as usize;
-                }
-                *y.add(jy) += alpha * temp;
-                jy += inc_y as usize;
+            aij
+        }
+    };
+
+    let (len_x, len_y) = if no_trans {
+        (n as usize, m as usize)
+    } else {
+        (m as usize, n as usize)
+    };
+    let kx = vec_start_index(len_x, inc_x);
+    let ky = vec_start_index(len_y, inc_y);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..len_y {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y as isize;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    // the element A(i, j) is stored in a(ku + i - j, j)
+    let (m, n, kl, ku) = (m as usize, n as usize, kl as usize, ku as usize);
+    if no_trans {
+        // Form y := alpha * A * x + y or y := alpha * conj(A) * x + y
+        let mut jx = kx;
+        for j in 0..n {
+            let temp = alpha * *x.offset(jx);
+            let i_start = j.saturating_sub(ku);
+            let i_end = min(m, j + kl + 1);
+            let mut iy = ky + i_start as isize * inc_y as isize;
+            for i in i_start..i_end {
+                *y.offset(iy) = *y.offset(iy) + temp * elem(ku + i - j, j);
+                iy += inc_y as isize;
+            }
+            jx += inc_x as isize;
+        }
+    } else {
+        // Form y := alpha * A^T * x + y or y := alpha * A^H * x + y
+        let mut jy = ky;
+        for j in 0..n {
+            let mut temp = zero;
+            let i_start = j.saturating_sub(ku);
+            let i_end = min(m, j + kl + 1);
+            let mut ix = kx + i_start as isize * inc_x as isize;
+            for i in i_start..i_end {
+                temp = temp + elem(ku + i - j, j) * *x.offset(ix);
+                ix += inc_x as isize;
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp;
+            jy += inc_y as isize;
+        }
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_symv<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    x: *const T,
+    inc_x: BlasInt,
+    beta: T,
+    y: *mut T,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // first, check `uplo`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if lda < max(1, n) {
+        info = 5;
+    } else if inc_x == 0 {
+        info = 7;
+    } else if inc_y == 0 {
+        info = 10;
+    }
+    if info != 0 {
+        xerbla!(false, info, "SSYMV");
+    }
+
+    // quick return if possible
+    if n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    let (inc_x, inc_y) = (inc_x as isize, inc_y as isize);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..n {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    let mut jx = kx;
+    let mut jy = ky;
+    if letter_same(uplo, 'U') {
+        // Form y when A is stored in upper triangle.
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            let mut ix = kx;
+            let mut iy = ky;
+            for i in 0..j {
+                let aij = *a.add(col_major_index(i, j, lda));
+                *y.offset(iy) += temp1 * aij;
+                temp2 += aij * *x.offset(ix);
+                ix += inc_x;
+                iy += inc_y;
+            }
+            *y.offset(jy) += temp1 * *a.add(col_major_index(j, j, lda)) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    } else {
+        // Form y when A is stored in lower triangle.
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            *y.offset(jy) += temp1 * *a.add(col_major_index(j, j, lda));
+            let mut ix = jx;
+            let mut iy = jy;
+            for i in (j + 1)..n {
+                ix += inc_x;
+                iy += inc_y;
+                let aij = *a.add(col_major_index(i, j, lda));
+                *y.offset(iy) += temp1 * aij;
+                temp2 += aij * *x.offset(ix);
+            }
+            *y.offset(jy) += alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    }
+}
+
+/// Hermitian matrix vector multiply. Only the `uplo` triangle of `a` is referenced and the
+/// imaginary parts of the diagonal are assumed to be zero.
+///
+/// If `conj` is true, the stored triangle holds $\bar{\boldsymbol{A}}$ instead of $\boldsymbol{A}$,
+/// which is the case when a row-major hermitian matrix is viewed as a col-major one.
+#[inline(always)]
+pub unsafe fn cz_hemv<T>(
+    uplo: char,
+    conj: bool,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    beta: *const Complex<T>,
+    y: *mut Complex<T>,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8>,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    // first, check `uplo`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if lda < max(1, n) {
+        info = 5;
+    } else if inc_x == 0 {
+        info = 7;
+    } else if inc_y == 0 {
+        info = 10;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CHEMV");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    let (inc_x, inc_y) = (inc_x as isize, inc_y as isize);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..n {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    let mut jx = kx;
+    let mut jy = ky;
+    if letter_same(uplo, 'U') {
+        // Form y when A is stored in upper triangle.
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            let mut ix = kx;
+            let mut iy = ky;
+            for i in 0..j {
+                let aij = elem(i, j);
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij.conj() * *x.offset(ix);
+                ix += inc_x;
+                iy += inc_y;
+            }
+            *y.offset(jy) = *y.offset(jy) + temp1 * elem(j, j).re + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    } else {
+        // Form y when A is stored in lower triangle.
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            *y.offset(jy) = *y.offset(jy) + temp1 * elem(j, j).re;
+            let mut ix = jx;
+            let mut iy = jy;
+            for i in (j + 1)..n {
+                ix += inc_x;
+                iy += inc_y;
+                let aij = elem(i, j);
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij.conj() * *x.offset(ix);
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    }
+}
+
+/// Complex symmetric matrix vector multiply, where $\boldsymbol{A}=\boldsymbol{A}^T$ without any
+/// conjugation. Only the `uplo` triangle of `a` is referenced.
+#[inline(always)]
+pub unsafe fn cz_symv<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    beta: *const Complex<T>,
+    y: *mut Complex<T>,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8>,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    // first, check `uplo`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if lda < max(1, n) {
+        info = 5;
+    } else if inc_x == 0 {
+        info = 7;
+    } else if inc_y == 0 {
+        info = 10;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CSYMV");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    let (inc_x, inc_y) = (inc_x as isize, inc_y as isize);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..n {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    let mut jx = kx;
+    let mut jy = ky;
+    if letter_same(uplo, 'U') {
+        // Form y when A is stored in upper triangle.
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            let mut ix = kx;
+            let mut iy = ky;
+            for i in 0..j {
+                let aij = *a.add(col_major_index(i, j, lda));
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij * *x.offset(ix);
+                ix += inc_x;
+                iy += inc_y;
+            }
+            *y.offset(jy) =
+                *y.offset(jy) + temp1 * *a.add(col_major_index(j, j, lda)) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    } else {
+        // Form y when A is stored in lower triangle.
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            *y.offset(jy) = *y.offset(jy) + temp1 * *a.add(col_major_index(j, j, lda));
+            let mut ix = jx;
+            let mut iy = jy;
+            for i in (j + 1)..n {
+                ix += inc_x;
+                iy += inc_y;
+                let aij = *a.add(col_major_index(i, j, lda));
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij * *x.offset(ix);
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_sbmv<T>(
+    uplo: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    x: *const T,
+    inc_x: BlasInt,
+    beta: T,
+    y: *mut T,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // first, check `uplo`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if k < 0 {
+        info = 3;
+    } else if lda < k + 1 {
+        info = 6;
+    } else if inc_x == 0 {
+        info = 8;
+    } else if inc_y == 0 {
+        info = 11;
+    }
+    if info != 0 {
+        xerbla!(false, info, "SSBMV");
+    }
+
+    // quick return if possible
+    if n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let (n, k) = (n as usize, k as usize);
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    let (inc_x, inc_y) = (inc_x as isize, inc_y as isize);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..n {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    let mut jx = kx;
+    let mut jy = ky;
+    if letter_same(uplo, 'U') {
+        // Form y when upper triangle of A is stored, A(i, j) is in a(k + i - j, j).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            let i_start = j.saturating_sub(k);
+            let mut ix = kx + i_start as isize * inc_x;
+            let mut iy = ky + i_start as isize * inc_y;
+            for i in i_start..j {
+                let aij = *a.add(col_major_index(k + i - j, j, lda));
+                *y.offset(iy) += temp1 * aij;
+                temp2 += aij * *x.offset(ix);
+                ix += inc_x;
+                iy += inc_y;
+            }
+            *y.offset(jy) += temp1 * *a.add(col_major_index(k, j, lda)) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    } else {
+        // Form y when lower triangle of A is stored, A(i, j) is in a(i - j, j).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            *y.offset(jy) += temp1 * *a.add(col_major_index(0, j, lda));
+            let mut ix = jx;
+            let mut iy = jy;
+            for i in (j + 1)..min(n, j + k + 1) {
+                ix += inc_x;
+                iy += inc_y;
+                let aij = *a.add(col_major_index(i - j, j, lda));
+                *y.offset(iy) += temp1 * aij;
+                temp2 += aij * *x.offset(ix);
+            }
+            *y.offset(jy) += alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    }
+}
+
+/// Hermitian banded matrix vector multiply. `conj` has the same meaning as in `cz_hemv`.
+#[inline(always)]
+pub unsafe fn cz_hbmv<T>(
+    uplo: char,
+    conj: bool,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    beta: *const Complex<T>,
+    y: *mut Complex<T>,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8>,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    // first, check `uplo`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if k < 0 {
+        info = 3;
+    } else if lda < k + 1 {
+        info = 6;
+    } else if inc_x == 0 {
+        info = 8;
+    } else if inc_y == 0 {
+        info = 11;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CHBMV");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    let (n, k) = (n as usize, k as usize);
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    let (inc_x, inc_y) = (inc_x as isize, inc_y as isize);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..n {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    let mut jx = kx;
+    let mut jy = ky;
+    if letter_same(uplo, 'U') {
+        // Form y when upper triangle of A is stored, A(i, j) is in a(k + i - j, j).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            let i_start = j.saturating_sub(k);
+            let mut ix = kx + i_start as isize * inc_x;
+            let mut iy = ky + i_start as isize * inc_y;
+            for i in i_start..j {
+                let aij = elem(k + i - j, j);
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij.conj() * *x.offset(ix);
+                ix += inc_x;
+                iy += inc_y;
+            }
+            *y.offset(jy) = *y.offset(jy) + temp1 * elem(k, j).re + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    } else {
+        // Form y when lower triangle of A is stored, A(i, j) is in a(i - j, j).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            *y.offset(jy) = *y.offset(jy) + temp1 * elem(0, j).re;
+            let mut ix = jx;
+            let mut iy = jy;
+            for i in (j + 1)..min(n, j + k + 1) {
+                ix += inc_x;
+                iy += inc_y;
+                let aij = elem(i - j, j);
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij.conj() * *x.offset(ix);
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+        }
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_spmv<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: T,
+    ap: *const T,
+    x: *const T,
+    inc_x: BlasInt,
+    beta: T,
+    y: *mut T,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // first, check `uplo`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 6;
+    } else if inc_y == 0 {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(false, info, "SSPMV");
+    }
+
+    // quick return if possible
+    if n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    let (inc_x, inc_y) = (inc_x as isize, inc_y as isize);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..n {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    // `kk` is the index of the first stored element of column j in `ap`
+    let mut kk = 0_usize;
+    let mut jx = kx;
+    let mut jy = ky;
+    if letter_same(uplo, 'U') {
+        // Form y when ap contains the upper triangle, A(i, j) is in ap(kk + i).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            let mut ix = kx;
+            let mut iy = ky;
+            for i in 0..j {
+                let aij = *ap.add(kk + i);
+                *y.offset(iy) += temp1 * aij;
+                temp2 += aij * *x.offset(ix);
+                ix += inc_x;
+                iy += inc_y;
+            }
+            *y.offset(jy) += temp1 * *ap.add(kk + j) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+            kk += j + 1;
+        }
+    } else {
+        // Form y when ap contains the lower triangle, A(i, j) is in ap(kk + i - j).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            *y.offset(jy) += temp1 * *ap.add(kk);
+            let mut ix = jx;
+            let mut iy = jy;
+            for i in (j + 1)..n {
+                ix += inc_x;
+                iy += inc_y;
+                let aij = *ap.add(kk + i - j);
+                *y.offset(iy) += temp1 * aij;
+                temp2 += aij * *x.offset(ix);
+            }
+            *y.offset(jy) += alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+            kk += n - j;
+        }
+    }
+}
+
+/// Hermitian packed matrix vector multiply. `conj` has the same meaning as in `cz_hemv`.
+#[inline(always)]
+pub unsafe fn cz_hpmv<T>(
+    uplo: char,
+    conj: bool,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    ap: *const Complex<T>,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    beta: *const Complex<T>,
+    y: *mut Complex<T>,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8>,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    // first, check `uplo`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 6;
+    } else if inc_y == 0 {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CHPMV");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let elem = |k: usize| {
+        let ak = *ap.add(k);
+        if conj {
+            ak.conj()
+        } else {
+            ak
+        }
+    };
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    let (inc_x, inc_y) = (inc_x as isize, inc_y as isize);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..n {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    // `kk` is the index of the first stored element of column j in `ap`
+    let mut kk = 0_usize;
+    let mut jx = kx;
+    let mut jy = ky;
+    if letter_same(uplo, 'U') {
+        // Form y when ap contains the upper triangle, A(i, j) is in ap(kk + i).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            let mut ix = kx;
+            let mut iy = ky;
+            for i in 0..j {
+                let aij = elem(kk + i);
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij.conj() * *x.offset(ix);
+                ix += inc_x;
+                iy += inc_y;
+            }
+            *y.offset(jy) = *y.offset(jy) + temp1 * elem(kk + j).re + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+            kk += j + 1;
+        }
+    } else {
+        // Form y when ap contains the lower triangle, A(i, j) is in ap(kk + i - j).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            *y.offset(jy) = *y.offset(jy) + temp1 * elem(kk).re;
+            let mut ix = jx;
+            let mut iy = jy;
+            for i in (j + 1)..n {
+                ix += inc_x;
+                iy += inc_y;
+                let aij = elem(kk + i - j);
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij.conj() * *x.offset(ix);
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+            kk += n - j;
+        }
+    }
+}
+
+/// Complex symmetric packed matrix vector multiply, the packed counterpart of `cz_symv`.
+#[inline(always)]
+pub unsafe fn cz_spmv<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    ap: *const Complex<T>,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    beta: *const Complex<T>,
+    y: *mut Complex<T>,
+    inc_y: BlasInt,
+) where
+    T: Float + From<i8>,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    // first, check `uplo`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 6;
+    } else if inc_y == 0 {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CSPMV");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    let (inc_x, inc_y) = (inc_x as isize, inc_y as isize);
+
+    // First form y := beta * y
+    if beta != one {
+        let mut iy = ky;
+        for _ in 0..n {
+            if beta == zero {
+                *y.offset(iy) = zero;
+            } else {
+                *y.offset(iy) = beta * *y.offset(iy);
+            }
+            iy += inc_y;
+        }
+    }
+    if alpha == zero {
+        return;
+    }
+    // `kk` is the index of the first stored element of column j in `ap`
+    let mut kk = 0_usize;
+    let mut jx = kx;
+    let mut jy = ky;
+    if letter_same(uplo, 'U') {
+        // Form y when ap contains the upper triangle, A(i, j) is in ap(kk + i).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            let mut ix = kx;
+            let mut iy = ky;
+            for i in 0..j {
+                let aij = *ap.add(kk + i);
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij * *x.offset(ix);
+                ix += inc_x;
+                iy += inc_y;
+            }
+            *y.offset(jy) = *y.offset(jy) + temp1 * *ap.add(kk + j) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+            kk += j + 1;
+        }
+    } else {
+        // Form y when ap contains the lower triangle, A(i, j) is in ap(kk + i - j).
+        for j in 0..n {
+            let temp1 = alpha * *x.offset(jx);
+            let mut temp2 = zero;
+            *y.offset(jy) = *y.offset(jy) + temp1 * *ap.add(kk);
+            let mut ix = jx;
+            let mut iy = jy;
+            for i in (j + 1)..n {
+                ix += inc_x;
+                iy += inc_y;
+                let aij = *ap.add(kk + i - j);
+                *y.offset(iy) = *y.offset(iy) + temp1 * aij;
+                temp2 = temp2 + aij * *x.offset(ix);
+            }
+            *y.offset(jy) = *y.offset(jy) + alpha * temp2;
+            jx += inc_x;
+            jy += inc_y;
+            kk += n - j;
+        }
+    }
+}
+
+/// Form x := op(A) * x, where A is an n by n triangular matrix with at most `k` non-zero
+/// off-diagonals (`k = n - 1` for a full triangle).
+///
+/// `elem(i, j)` reads the element A(i, j) of the referenced triangle, with any conjugation already
+/// applied. It is shared by trmv, tbmv and tpmv, which only differ in the storage of A.
+#[inline(always)]
+unsafe fn a_tri_mv<T, F>(
+    upper: bool,
+    no_trans: bool,
+    non_unit: bool,
+    n: usize,
+    k: usize,
+    elem: F,
+    x: *mut T,
+    inc_x: BlasInt,
+) where
+    T: Copy + Zero + PartialEq + Add<Output = T> + Mul<Output = T>,
+    F: Fn(usize, usize) -> T,
+{
+    let kx = vec_start_index(n, inc_x);
+    // the offset of x(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+
+    if no_trans {
+        // Form x := A * x
+        if upper {
+            for j in 0..n {
+                let temp = *x.offset(ix(j));
+                if !temp.is_zero() {
+                    for i in j.saturating_sub(k)..j {
+                        *x.offset(ix(i)) = *x.offset(ix(i)) + temp * elem(i, j);
+                    }
+                    if non_unit {
+                        *x.offset(ix(j)) = *x.offset(ix(j)) * elem(j, j);
+                    }
+                }
+            }
+        } else {
+            for j in (0..n).rev() {
+                let temp = *x.offset(ix(j));
+                if !temp.is_zero() {
+                    for i in ((j + 1)..min(n, j + k + 1)).rev() {
+                        *x.offset(ix(i)) = *x.offset(ix(i)) + temp * elem(i, j);
+                    }
+                    if non_unit {
+                        *x.offset(ix(j)) = *x.offset(ix(j)) * elem(j, j);
+                    }
+                }
+            }
+        }
+    } else {
+        // Form x := A^T * x
+        if upper {
+            for j in (0..n).rev() {
+                let mut temp = *x.offset(ix(j));
+                if non_unit {
+                    temp = temp * elem(j, j);
+                }
+                for i in (j.saturating_sub(k)..j).rev() {
+                    temp = temp + elem(i, j) * *x.offset(ix(i));
+                }
+                *x.offset(ix(j)) = temp;
+            }
+        } else {
+            for j in 0..n {
+                let mut temp = *x.offset(ix(j));
+                if non_unit {
+                    temp = temp * elem(j, j);
+                }
+                for i in (j + 1)..min(n, j + k + 1) {
+                    temp = temp + elem(i, j) * *x.offset(ix(i));
+                }
+                *x.offset(ix(j)) = temp;
+            }
+        }
+    }
+}
+
+/// Form x := inv(op(A)) * x, where A is an n by n triangular matrix with at most `k` non-zero
+/// off-diagonals. `elem` has the same meaning as in `a_tri_mv`, and the routine is shared by
+/// trsv, tbsv and tpsv, and by the diagonal blocks of trsm in level 3.
+#[inline(always)]
+pub(crate) unsafe fn a_tri_sv<T, F>(
+    upper: bool,
+    no_trans: bool,
+    non_unit: bool,
+    n: usize,
+    k: usize,
+    elem: F,
+    x: *mut T,
+    inc_x: BlasInt,
+) where
+    T: Copy + Zero + PartialEq + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    F: Fn(usize, usize) -> T,
+{
+    let kx = vec_start_index(n, inc_x);
+    // the offset of x(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+
+    if no_trans {
+        // Form x := inv(A) * x
+        if upper {
+            for j in (0..n).rev() {
+                if !(*x.offset(ix(j))).is_zero() {
+                    if non_unit {
+                        *x.offset(ix(j)) = *x.offset(ix(j)) / elem(j, j);
+                    }
+                    let temp = *x.offset(ix(j));
+                    for i in (j.saturating_sub(k)..j).rev() {
+                        *x.offset(ix(i)) = *x.offset(ix(i)) - temp * elem(i, j);
+                    }
+                }
+            }
+        } else {
+            for j in 0..n {
+                if !(*x.offset(ix(j))).is_zero() {
+                    if non_unit {
+                        *x.offset(ix(j)) = *x.offset(ix(j)) / elem(j, j);
+                    }
+                    let temp = *x.offset(ix(j));
+                    for i in (j + 1)..min(n, j + k + 1) {
+                        *x.offset(ix(i)) = *x.offset(ix(i)) - temp * elem(i, j);
+                    }
+                }
+            }
+        }
+    } else {
+        // Form x := inv(A^T) * x
+        if upper {
+            for j in 0..n {
+                let mut temp = *x.offset(ix(j));
+                for i in j.saturating_sub(k)..j {
+                    temp = temp - elem(i, j) * *x.offset(ix(i));
+                }
+                if non_unit {
+                    temp = temp / elem(j, j);
+                }
+                *x.offset(ix(j)) = temp;
+            }
+        } else {
+            for j in (0..n).rev() {
+                let mut temp = *x.offset(ix(j));
+                for i in ((j + 1)..min(n, j + k + 1)).rev() {
+                    temp = temp - elem(i, j) * *x.offset(ix(i));
+                }
+                if non_unit {
+                    temp = temp / elem(j, j);
+                }
+                *x.offset(ix(j)) = temp;
+            }
+        }
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_trmv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    a: *const T,
+    lda: BlasInt,
+    x: *mut T,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N') && !letter_same(trans, 'T') && !letter_same(trans, 'C') {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if lda < max(1, n) {
+        info = 6;
+    } else if inc_x == 0 {
+        info = 8;
+    }
+    if info != 0 {
+        xerbla!(false, info, "STRMV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    let elem = |i: usize, j: usize| *a.add(col_major_index(i, j, lda));
+    a_tri_mv(upper, no_trans, non_unit, n, n - 1, elem, x, inc_x);
+}
+
+/// Complex version of trmv. `trans` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemv`.
+#[inline(always)]
+pub unsafe fn cz_trmv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *mut Complex<T>,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N')
+        && !letter_same(trans, 'T')
+        && !letter_same(trans, 'C')
+        && !letter_same(trans, 'R')
+    {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if lda < max(1, n) {
+        info = 6;
+    } else if inc_x == 0 {
+        info = 8;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CTRMV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_tri_mv(upper, no_trans, non_unit, n, n - 1, elem, x, inc_x);
+}
+
+#[inline(always)]
+pub unsafe fn sd_trsv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    a: *const T,
+    lda: BlasInt,
+    x: *mut T,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N') && !letter_same(trans, 'T') && !letter_same(trans, 'C') {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if lda < max(1, n) {
+        info = 6;
+    } else if inc_x == 0 {
+        info = 8;
+    }
+    if info != 0 {
+        xerbla!(false, info, "STRSV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    let elem = |i: usize, j: usize| *a.add(col_major_index(i, j, lda));
+    a_tri_sv(upper, no_trans, non_unit, n, n - 1, elem, x, inc_x);
+}
+
+/// Complex version of trsv. `trans` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemv`.
+#[inline(always)]
+pub unsafe fn cz_trsv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *mut Complex<T>,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N')
+        && !letter_same(trans, 'T')
+        && !letter_same(trans, 'C')
+        && !letter_same(trans, 'R')
+    {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if lda < max(1, n) {
+        info = 6;
+    } else if inc_x == 0 {
+        info = 8;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CTRSV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_tri_sv(upper, no_trans, non_unit, n, n - 1, elem, x, inc_x);
+}
+
+#[inline(always)]
+pub unsafe fn sd_tbmv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    k: BlasInt,
+    a: *const T,
+    lda: BlasInt,
+    x: *mut T,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N') && !letter_same(trans, 'T') && !letter_same(trans, 'C') {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if k < 0 {
+        info = 5;
+    } else if lda < k + 1 {
+        info = 7;
+    } else if inc_x == 0 {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(false, info, "STBMV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    let k = k as usize;
+    // A(i, j) is stored in a(k + i - j, j) for the upper triangle, or in a(i - j, j) for the lower one.
+    let index = |i: usize, j: usize| {
+        if upper {
+            col_major_index(k + i - j, j, lda)
+        } else {
+            col_major_index(i - j, j, lda)
+        }
+    };
+    let elem = |i: usize, j: usize| *a.add(index(i, j));
+    a_tri_mv(upper, no_trans, non_unit, n, k, elem, x, inc_x);
+}
+
+/// Complex version of tbmv. `trans` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemv`.
+#[inline(always)]
+pub unsafe fn cz_tbmv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    k: BlasInt,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *mut Complex<T>,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N')
+        && !letter_same(trans, 'T')
+        && !letter_same(trans, 'C')
+        && !letter_same(trans, 'R')
+    {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if k < 0 {
+        info = 5;
+    } else if lda < k + 1 {
+        info = 7;
+    } else if inc_x == 0 {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CTBMV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    let k = k as usize;
+    // A(i, j) is stored in a(k + i - j, j) for the upper triangle, or in a(i - j, j) for the lower one.
+    let index = |i: usize, j: usize| {
+        if upper {
+            col_major_index(k + i - j, j, lda)
+        } else {
+            col_major_index(i - j, j, lda)
+        }
+    };
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(index(i, j));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_tri_mv(upper, no_trans, non_unit, n, k, elem, x, inc_x);
+}
+
+#[inline(always)]
+pub unsafe fn sd_tbsv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    k: BlasInt,
+    a: *const T,
+    lda: BlasInt,
+    x: *mut T,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N') && !letter_same(trans, 'T') && !letter_same(trans, 'C') {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if k < 0 {
+        info = 5;
+    } else if lda < k + 1 {
+        info = 7;
+    } else if inc_x == 0 {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(false, info, "STBSV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    let k = k as usize;
+    // A(i, j) is stored in a(k + i - j, j) for the upper triangle, or in a(i - j, j) for the lower one.
+    let index = |i: usize, j: usize| {
+        if upper {
+            col_major_index(k + i - j, j, lda)
+        } else {
+            col_major_index(i - j, j, lda)
+        }
+    };
+    let elem = |i: usize, j: usize| *a.add(index(i, j));
+    a_tri_sv(upper, no_trans, non_unit, n, k, elem, x, inc_x);
+}
+
+/// Complex version of tbsv. `trans` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemv`.
+#[inline(always)]
+pub unsafe fn cz_tbsv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    k: BlasInt,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    x: *mut Complex<T>,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N')
+        && !letter_same(trans, 'T')
+        && !letter_same(trans, 'C')
+        && !letter_same(trans, 'R')
+    {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if k < 0 {
+        info = 5;
+    } else if lda < k + 1 {
+        info = 7;
+    } else if inc_x == 0 {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CTBSV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    let k = k as usize;
+    // A(i, j) is stored in a(k + i - j, j) for the upper triangle, or in a(i - j, j) for the lower one.
+    let index = |i: usize, j: usize| {
+        if upper {
+            col_major_index(k + i - j, j, lda)
+        } else {
+            col_major_index(i - j, j, lda)
+        }
+    };
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(index(i, j));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_tri_sv(upper, no_trans, non_unit, n, k, elem, x, inc_x);
+}
+
+#[inline(always)]
+pub unsafe fn sd_tpmv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    ap: *const T,
+    x: *mut T,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N') && !letter_same(trans, 'T') && !letter_same(trans, 'C') {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if inc_x == 0 {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(false, info, "STPMV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    // A(i, j) is stored in ap(i + j * (j + 1) / 2) for the upper triangle,
+    // or in ap(i + j * (2 * n - j - 1) / 2) for the lower one.
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let elem = |i: usize, j: usize| *ap.add(index(i, j));
+    a_tri_mv(upper, no_trans, non_unit, n, n - 1, elem, x, inc_x);
+}
+
+/// Complex version of tpmv. `trans` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemv`.
+#[inline(always)]
+pub unsafe fn cz_tpmv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    ap: *const Complex<T>,
+    x: *mut Complex<T>,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N')
+        && !letter_same(trans, 'T')
+        && !letter_same(trans, 'C')
+        && !letter_same(trans, 'R')
+    {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if inc_x == 0 {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CTPMV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    // A(i, j) is stored in ap(i + j * (j + 1) / 2) for the upper triangle,
+    // or in ap(i + j * (2 * n - j - 1) / 2) for the lower one.
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let elem = |i: usize, j: usize| {
+        let aij = *ap.add(index(i, j));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_tri_mv(upper, no_trans, non_unit, n, n - 1, elem, x, inc_x);
+}
+
+#[inline(always)]
+pub unsafe fn sd_tpsv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    ap: *const T,
+    x: *mut T,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N') && !letter_same(trans, 'T') && !letter_same(trans, 'C') {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if inc_x == 0 {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(false, info, "STPSV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    // A(i, j) is stored in ap(i + j * (j + 1) / 2) for the upper triangle,
+    // or in ap(i + j * (2 * n - j - 1) / 2) for the lower one.
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let elem = |i: usize, j: usize| *ap.add(index(i, j));
+    a_tri_sv(upper, no_trans, non_unit, n, n - 1, elem, x, inc_x);
+}
+
+/// Complex version of tpsv. `trans` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemv`.
+#[inline(always)]
+pub unsafe fn cz_tpsv<T>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: BlasInt,
+    ap: *const Complex<T>,
+    x: *mut Complex<T>,
+    inc_x: BlasInt,
+) where
+    T: Float,
+{
+    // first, check `uplo`, `trans` and `diag`
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if !letter_same(trans, 'N')
+        && !letter_same(trans, 'T')
+        && !letter_same(trans, 'C')
+        && !letter_same(trans, 'R')
+    {
+        info = 2;
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if inc_x == 0 {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CTPSV");
+    }
+
+    // quick return if possible
+    if n == 0 {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    let non_unit = letter_same(diag, 'N');
+    let n = n as usize;
+    // A(i, j) is stored in ap(i + j * (j + 1) / 2) for the upper triangle,
+    // or in ap(i + j * (2 * n - j - 1) / 2) for the lower one.
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let elem = |i: usize, j: usize| {
+        let aij = *ap.add(index(i, j));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_tri_sv(upper, no_trans, non_unit, n, n - 1, elem, x, inc_x);
+}
+
+#[inline(always)]
+pub unsafe fn sd_ger<T>(
+    m: BlasInt,
+    n: BlasInt,
+    alpha: T,
+    x: *const T,
+    inc_x: BlasInt,
+    y: *const T,
+    inc_y: BlasInt,
+    a: *mut T,
+    lda: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if m < 0 {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if inc_y == 0 {
+        info = 7;
+    } else if lda < max(1, m) {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 || alpha == zero {
+        return;
+    }
+
+    let (m, n) = (m as usize, n as usize);
+    let kx = vec_start_index(m, inc_x);
+    let mut jy = vec_start_index(n, inc_y);
+    for j in 0..n {
+        let yj = *y.offset(jy);
+        if yj != zero {
+            let temp = alpha * yj;
+            let mut ix = kx;
+            for i in 0..m {
+                *a.add(col_major_index(i, j, lda)) += *x.offset(ix) * temp;
+                ix += inc_x as isize;
+            }
+        }
+        jy += inc_y as isize;
+    }
+}
+
+/// Complex rank 1 operation A := alpha * op(x) * op(y)^T + A, where op(x) is x or conj(x)
+/// according to `conj_x`, and op(y) is y or conj(y) according to `conj_y`.
+///
+/// GERU uses neither conjugation and GERC conjugates y. A row-major GERC swaps x and y,
+/// so it becomes a col-major update with `conj_x` set instead.
+#[inline(always)]
+pub unsafe fn cz_ger<T>(
+    conj_x: bool,
+    conj_y: bool,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    y: *const Complex<T>,
+    inc_y: BlasInt,
+    a: *mut Complex<T>,
+    lda: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let mut info = 0;
+    if m < 0 {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if inc_y == 0 {
+        info = 7;
+    } else if lda < max(1, m) {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    // quick return if possible
+    if m == 0 || n == 0 || alpha == zero {
+        return;
+    }
+
+    let (m, n) = (m as usize, n as usize);
+    let kx = vec_start_index(m, inc_x);
+    let mut jy = vec_start_index(n, inc_y);
+    for j in 0..n {
+        let yj = if conj_y {
+            (*y.offset(jy)).conj()
+        } else {
+            *y.offset(jy)
+        };
+        if yj != zero {
+            let temp = alpha * yj;
+            let mut ix = kx;
+            for i in 0..m {
+                let xi = if conj_x {
+                    (*x.offset(ix)).conj()
+                } else {
+                    *x.offset(ix)
+                };
+                let aij = a.add(col_major_index(i, j, lda));
+                *aij = *aij + xi * temp;
+                ix += inc_x as isize;
+            }
+        }
+        jy += inc_y as isize;
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_syr<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: T,
+    x: *const T,
+    inc_x: BlasInt,
+    a: *mut T,
+    lda: BlasInt,
+) where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if lda < max(1, n) {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(false, info, "SSYR");
+    }
+
+    // quick return if possible
+    if n == 0 || alpha == zero {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    // the offset of x(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    if letter_same(uplo, 'U') {
+        // Form A when A is stored in upper triangle.
+        for j in 0..n {
+            let xj = *x.offset(ix(j));
+            if xj != zero {
+                let temp = alpha * xj;
+                for i in 0..=j {
+                    *a.add(col_major_index(i, j, lda)) += *x.offset(ix(i)) * temp;
+                }
+            }
+        }
+    } else {
+        // Form A when A is stored in lower triangle.
+        for j in 0..n {
+            let xj = *x.offset(ix(j));
+            if xj != zero {
+                let temp = alpha * xj;
+                for i in j..n {
+                    *a.add(col_major_index(i, j, lda)) += *x.offset(ix(i)) * temp;
+                }
+            }
+        }
+    }
+}
+
+/// Hermitian rank 1 operation A := alpha * x * x^H + A with a real `alpha`. The imaginary parts
+/// of the diagonal are set to zero.
+///
+/// If `conj` is true, the stored triangle holds $\bar{\boldsymbol{A}}$, the same as in `cz_hemv`,
+/// so it is updated with the conjugate of alpha * x * x^H.
+#[inline(always)]
+pub unsafe fn cz_her<T>(
+    uplo: char,
+    conj: bool,
+    n: BlasInt,
+    alpha: T,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    a: *mut Complex<T>,
+    lda: BlasInt,
+) where
+    T: Float + From<i8>,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if lda < max(1, n) {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CHER");
+    }
+
+    // quick return if possible
+    if n == 0 || alpha == zero {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    // the offset of x(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    // add the (i, j) element of alpha * x * x^H, or its conjugate, to A(i, j)
+    let update = |i: usize, j: usize, temp: Complex<T>| {
+        let mut upd = *x.offset(ix(i)) * temp;
+        if conj {
+            upd = upd.conj();
+        }
+        let aij = a.add(col_major_index(i, j, lda));
+        *aij = *aij + upd;
+    };
+    // the diagonal element is real, A(j, j) := real(A(j, j)) + alpha * |x(j)|^2
+    let update_diag = |j: usize| {
+        let ajj = a.add(col_major_index(j, j, lda));
+        *ajj = Complex::new((*ajj).re + alpha * (*x.offset(ix(j))).norm_sqr(), zero);
+    };
+    if letter_same(uplo, 'U') {
+        // Form A when A is stored in upper triangle.
+        for j in 0..n {
+            let temp = (*x.offset(ix(j))).conj() * alpha;
+            for i in 0..j {
+                update(i, j, temp);
+            }
+            update_diag(j);
+        }
+    } else {
+        // Form A when A is stored in lower triangle.
+        for j in 0..n {
+            let temp = (*x.offset(ix(j))).conj() * alpha;
+            update_diag(j);
+            for i in (j + 1)..n {
+                update(i, j, temp);
+            }
+        }
+    }
+}
+
+/// Complex symmetric rank 1 operation A := alpha * x * x^T + A, without any conjugation.
+#[inline(always)]
+pub unsafe fn cz_syr<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    a: *mut Complex<T>,
+    lda: BlasInt,
+) where
+    T: Float + From<i8>,
+{
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if lda < max(1, n) {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CSYR");
+    }
+
+    let alpha = *alpha;
+    // quick return if possible
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    // the offset of x(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    for j in 0..n {
+        let xj = *x.offset(ix(j));
+        if !xj.is_zero() {
+            let temp = alpha * xj;
+            let rows = if letter_same(uplo, 'U') {
+                0..(j + 1)
+            } else {
+                j..n
+            };
+            for i in rows {
+                let aij = a.add(col_major_index(i, j, lda));
+                *aij = *aij + *x.offset(ix(i)) * temp;
+            }
+        }
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_syr2<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: T,
+    x: *const T,
+    inc_x: BlasInt,
+    y: *const T,
+    inc_y: BlasInt,
+    a: *mut T,
+    lda: BlasInt,
+) where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if inc_y == 0 {
+        info = 7;
+    } else if lda < max(1, n) {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(false, info, "SSYR2");
+    }
+
+    // quick return if possible
+    if n == 0 || alpha == zero {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    // the offsets of x(i) and y(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    let iy = |i: usize| ky + i as isize * inc_y as isize;
+    for j in 0..n {
+        let xj = *x.offset(ix(j));
+        let yj = *y.offset(iy(j));
+        if xj != zero || yj != zero {
+            let temp1 = alpha * yj;
+            let temp2 = alpha * xj;
+            let rows = if letter_same(uplo, 'U') {
+                0..(j + 1)
+            } else {
+                j..n
+            };
+            for i in rows {
+                *a.add(col_major_index(i, j, lda)) +=
+                    *x.offset(ix(i)) * temp1 + *y.offset(iy(i)) * temp2;
+            }
+        }
+    }
+}
+
+/// Hermitian rank 2 operation A := alpha * x * y^H + conj(alpha) * y * x^H + A. The imaginary
+/// parts of the diagonal are set to zero. `conj` has the same meaning as in `cz_her`.
+#[inline(always)]
+pub unsafe fn cz_her2<T>(
+    uplo: char,
+    conj: bool,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    y: *const Complex<T>,
+    inc_y: BlasInt,
+    a: *mut Complex<T>,
+    lda: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if inc_y == 0 {
+        info = 7;
+    } else if lda < max(1, n) {
+        info = 9;
+    }
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    // quick return if possible
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+
+    let n = n as usize;
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    // the offsets of x(i) and y(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    let iy = |i: usize| ky + i as isize * inc_y as isize;
+    for j in 0..n {
+        let temp1 = alpha * (*y.offset(iy(j))).conj();
+        let temp2 = (alpha * *x.offset(ix(j))).conj();
+        let rows = if letter_same(uplo, 'U') {
+            0..(j + 1)
+        } else {
+            j..n
+        };
+        for i in rows {
+            let aij = a.add(col_major_index(i, j, lda));
+            let upd = *x.offset(ix(i)) * temp1 + *y.offset(iy(i)) * temp2;
+            if i == j {
+                // the diagonal element is real
+                *aij = Complex::new((*aij).re + upd.re, zero);
+            } else if conj {
+                *aij = *aij + upd.conj();
+            } else {
+                *aij = *aij + upd;
+            }
+        }
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_spr<T>(uplo: char, n: BlasInt, alpha: T, x: *const T, inc_x: BlasInt, ap: *mut T)
+where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    }
+    if info != 0 {
+        xerbla!(false, info, "SSPR");
+    }
+
+    // quick return if possible
+    if n == 0 || alpha == zero {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let n = n as usize;
+    // the packed index of A(i, j), the same as in `sd_tpmv`
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let kx = vec_start_index(n, inc_x);
+    // the offset of x(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    for j in 0..n {
+        let xj = *x.offset(ix(j));
+        if xj != zero {
+            let temp = alpha * xj;
+            let rows = if upper { 0..(j + 1) } else { j..n };
+            for i in rows {
+                *ap.add(index(i, j)) += *x.offset(ix(i)) * temp;
+            }
+        }
+    }
+}
+
+/// Hermitian packed rank 1 operation A := alpha * x * x^H + A with a real `alpha`. `conj` has
+/// the same meaning as in `cz_her`.
+#[inline(always)]
+pub unsafe fn cz_hpr<T>(
+    uplo: char,
+    conj: bool,
+    n: BlasInt,
+    alpha: T,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    ap: *mut Complex<T>,
+) where
+    T: Float + From<i8>,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CHPR");
+    }
+
+    // quick return if possible
+    if n == 0 || alpha == zero {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let n = n as usize;
+    // the packed index of A(i, j), the same as in `cz_tpmv`
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let kx = vec_start_index(n, inc_x);
+    // the offset of x(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    for j in 0..n {
+        let temp = (*x.offset(ix(j))).conj() * alpha;
+        let rows = if upper { 0..(j + 1) } else { j..n };
+        for i in rows {
+            let aij = ap.add(index(i, j));
+            let upd = *x.offset(ix(i)) * temp;
+            if i == j {
+                // the diagonal element is real
+                *aij = Complex::new((*aij).re + upd.re, zero);
+            } else if conj {
+                *aij = *aij + upd.conj();
+            } else {
+                *aij = *aij + upd;
+            }
+        }
+    }
+}
+
+/// Complex symmetric packed rank 1 operation A := alpha * x * x^T + A, the packed counterpart of
+/// `cz_syr`.
+#[inline(always)]
+pub unsafe fn cz_spr<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    ap: *mut Complex<T>,
+) where
+    T: Float + From<i8>,
+{
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CSPR");
+    }
+
+    let alpha = *alpha;
+    // quick return if possible
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let n = n as usize;
+    // the packed index of A(i, j), the same as in `cz_tpmv`
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let kx = vec_start_index(n, inc_x);
+    // the offset of x(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    for j in 0..n {
+        let xj = *x.offset(ix(j));
+        if !xj.is_zero() {
+            let temp = alpha * xj;
+            let rows = if upper { 0..(j + 1) } else { j..n };
+            for i in rows {
+                let aij = ap.add(index(i, j));
+                *aij = *aij + *x.offset(ix(i)) * temp;
+            }
+        }
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_spr2<T>(
+    uplo: char,
+    n: BlasInt,
+    alpha: T,
+    x: *const T,
+    inc_x: BlasInt,
+    y: *const T,
+    inc_y: BlasInt,
+    ap: *mut T,
+) where
+    T: Float + From<i8> + AddAssign,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if inc_y == 0 {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(false, info, "SSPR2");
+    }
+
+    // quick return if possible
+    if n == 0 || alpha == zero {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let n = n as usize;
+    // the packed index of A(i, j), the same as in `sd_tpmv`
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    // the offsets of x(i) and y(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    let iy = |i: usize| ky + i as isize * inc_y as isize;
+    for j in 0..n {
+        let xj = *x.offset(ix(j));
+        let yj = *y.offset(iy(j));
+        if xj != zero || yj != zero {
+            let temp1 = alpha * yj;
+            let temp2 = alpha * xj;
+            let rows = if upper { 0..(j + 1) } else { j..n };
+            for i in rows {
+                *ap.add(index(i, j)) += *x.offset(ix(i)) * temp1 + *y.offset(iy(i)) * temp2;
+            }
+        }
+    }
+}
+
+/// Hermitian packed rank 2 operation A := alpha * x * y^H + conj(alpha) * y * x^H + A. `conj`
+/// has the same meaning as in `cz_her`.
+#[inline(always)]
+pub unsafe fn cz_hpr2<T>(
+    uplo: char,
+    conj: bool,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    x: *const Complex<T>,
+    inc_x: BlasInt,
+    y: *const Complex<T>,
+    inc_y: BlasInt,
+    ap: *mut Complex<T>,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+{
+    let zero: T = From::from(0);
+    let mut info = 0;
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if inc_x == 0 {
+        info = 5;
+    } else if inc_y == 0 {
+        info = 7;
+    }
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    // quick return if possible
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    let n = n as usize;
+    // the packed index of A(i, j), the same as in `cz_tpmv`
+    let index = |i: usize, j: usize| {
+        if upper {
+            i + j * (j + 1) / 2
+        } else {
+            i + j * (2 * n - j - 1) / 2
+        }
+    };
+    let kx = vec_start_index(n, inc_x);
+    let ky = vec_start_index(n, inc_y);
+    // the offsets of x(i) and y(i)
+    let ix = |i: usize| kx + i as isize * inc_x as isize;
+    let iy = |i: usize| ky + i as isize * inc_y as isize;
+    for j in 0..n {
+        let temp1 = alpha * (*y.offset(iy(j))).conj();
+        let temp2 = (alpha * *x.offset(ix(j))).conj();
+        let rows = if upper { 0..(j + 1) } else { j..n };
+        for i in rows {
+            let aij = ap.add(index(i, j));
+            let upd = *x.offset(ix(i)) * temp1 + *y.offset(iy(i)) * temp2;
+            if i == j {
+                // the diagonal element is real
+                *aij = Complex::new((*aij).re + upd.re, zero);
+            } else if conj {
+                *aij = *aij + upd.conj();
+            } else {
+                *aij = *aij + upd;
             }
         }
     }
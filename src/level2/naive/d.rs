@@ -0,0 +1,1398 @@
+use super::core;
+use crate::common::{BlasInt, CBlasDiag, CBlasOrder, CBlasTranspose, CBlasUpLo};
+use crate::dispatch::table;
+
+/// DGEMV perform one of the matrix-vector operations $y=\alpha * op(\boldsymbol{A}) * \vec{x} + \beta * \vec{y}$.
+///
+/// # Description
+/// DGEMV performs one of the matrix-vector operations
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+/// or
+/// $$\vec{y}=\alpha * \boldsymbol{A}^T * \vec{x} + \beta * \vec{y}$$
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed, that is, the $op(\boldsymbol{A})$
+///
+/// `m`(in) - the number of rows.
+///
+/// `n`(in) - the number of cols.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `lda`(in) - if `a` is col-major, `lda` should be the number of rows of the outer matrix;
+/// else, `lda` should be the number of cols of the outer matrix.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dgemv(
+    order: CBlasOrder,
+    trans_a: CBlasTranspose,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: f64,
+    a: *const f64,
+    lda: BlasInt,
+    x: *const f64,
+    inc_x: BlasInt,
+    beta: f64,
+    y: *mut f64,
+    inc_y: BlasInt,
+) {
+    let ta: char;
+    if order == CBlasOrder::ColMajor {
+        match trans_a {
+            CBlasTranspose::NoTrans => {
+                ta = 'N';
+            }
+            CBlasTranspose::Trans => {
+                ta = 'T';
+            }
+            CBlasTranspose::ConjTrans => {
+                ta = 'T';
+            }
+            _ => {
+                xerbla!(
+                    false,
+                    2,
+                    "cblas_dgemv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        }
+        (table().d.gemv)(ta, m, n, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        match trans_a {
+            CBlasTranspose::NoTrans => {
+                ta = 'T';
+            }
+            CBlasTranspose::Trans => {
+                ta = 'N';
+            }
+            CBlasTranspose::ConjTrans => {
+                ta = 'N';
+            }
+            _ => {
+                xerbla!(
+                    false,
+                    2,
+                    "cblas_dgemv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        }
+        (table().d.gemv)(ta, n, m, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dgemv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DGBMV perform one of the matrix-vector operations $y=\alpha * op(\boldsymbol{A}) * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a banded matrix.
+///
+/// # Description
+/// DGBMV performs one of the matrix-vector operations
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+/// or
+/// $$\vec{y}=\alpha * \boldsymbol{A}^T * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an m by n band matrix, with `kl` sub-diagonals and `ku` super-diagonals.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed, that is, the $op(\boldsymbol{A})$
+///
+/// `m`(in) - the number of rows.
+///
+/// `n`(in) - the number of cols.
+///
+/// `kl`(in) - the number of sub-diagonals of matrix `a`.
+///
+/// `ku`(in) - the number of super-diagonals of matrix `a`.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `a`(in) - the band matrix. If `a` is col-major, column j of the matrix is stored in column j of `a`,
+/// with the leading diagonal in row `ku`, that is, $A_{ij}$ is stored in `a[(ku + i - j) + j * lda]`.
+/// If `a` is row-major, row i of the matrix is stored in row i of `a` with the leading diagonal in column `kl`,
+/// that is, $A_{ij}$ is stored in `a[i * lda + (kl + j - i)]`.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `kl + ku + 1`.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dgbmv(
+    order: CBlasOrder,
+    trans_a: CBlasTranspose,
+    m: BlasInt,
+    n: BlasInt,
+    kl: BlasInt,
+    ku: BlasInt,
+    alpha: f64,
+    a: *const f64,
+    lda: BlasInt,
+    x: *const f64,
+    inc_x: BlasInt,
+    beta: f64,
+    y: *mut f64,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    2,
+                    "cblas_dgbmv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_gbmv(
+            ta,
+            m,
+            n,
+            kl,
+            ku,
+            alpha,
+            a,
+            lda,
+            x,
+            inc_x,
+            beta,
+            y,
+            inc_y,
+            false,
+            "cblas_dgbmv",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major band matrix is the col-major band storage of its transpose
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    2,
+                    "cblas_dgbmv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_gbmv(
+            ta,
+            n,
+            m,
+            ku,
+            kl,
+            alpha,
+            a,
+            lda,
+            x,
+            inc_x,
+            beta,
+            y,
+            inc_y,
+            true,
+            "cblas_dgbmv",
+        );
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dgbmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DSYMV perform the matrix-vector operation $y=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a symmetric matrix.
+///
+/// # Description
+/// DSYMV performs the matrix-vector operation
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an n by n symmetric matrix. Only the triangle selected by `uplo` is referenced.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `a`(in) - the symmetric matrix.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dsymv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: f64,
+    a: *const f64,
+    lda: BlasInt,
+    x: *const f64,
+    inc_x: BlasInt,
+    beta: f64,
+    y: *mut f64,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::sd_symv(ul, n, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::sd_symv(ul, n, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dsymv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DSBMV perform the matrix-vector operation $y=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a symmetric banded matrix.
+///
+/// # Description
+/// DSBMV performs the matrix-vector operation
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an n by n symmetric band matrix, with `k` super-diagonals.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the band matrix is supplied in `a`.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `k`(in) - the number of super-diagonals (or sub-diagonals) of matrix `a`.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `a`(in) - the band matrix. If `a` is col-major and `uplo` is `Upper`, $A_{ij}$ is stored in
+/// `a[(k + i - j) + j * lda]`; if `uplo` is `Lower`, $A_{ij}$ is stored in `a[(i - j) + j * lda]`.
+/// If `a` is row-major and `uplo` is `Upper`, $A_{ij}$ is stored in `a[i * lda + (j - i)]`;
+/// if `uplo` is `Lower`, $A_{ij}$ is stored in `a[i * lda + (k + j - i)]`.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `k + 1`.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dsbmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: f64,
+    a: *const f64,
+    lda: BlasInt,
+    x: *const f64,
+    inc_x: BlasInt,
+    beta: f64,
+    y: *mut f64,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::sd_sbmv(ul, n, k, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper band of a row-major matrix is the lower band of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::sd_sbmv(ul, n, k, alpha, a, lda, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dsbmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DSPMV perform the matrix-vector operation $y=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$,
+/// where $\boldsymbol{A}$ is a symmetric matrix in packed form.
+///
+/// # Description
+/// DSPMV performs the matrix-vector operation
+/// $$\vec{y}=\alpha * \boldsymbol{A} * \vec{x} + \beta * \vec{y}$$
+///
+/// where $\boldsymbol{A}$ is an n by n symmetric matrix, supplied in packed form.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the matrix is supplied in `ap`.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `ap`(in) - the packed matrix, with at least n * (n + 1) / 2 elements. The selected triangle is packed
+/// column by column if `ap` is col-major, or row by row if `ap` is row-major.
+/// For example, a col-major upper triangle is stored as $A_{00}, A_{01}, A_{11}, A_{02}, A_{12}, A_{22}, ...$
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `beta`(in) - constant scaling factor $\beta$.
+///
+/// `y`(in, out) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dspmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: f64,
+    ap: *const f64,
+    x: *const f64,
+    inc_x: BlasInt,
+    beta: f64,
+    y: *mut f64,
+    inc_y: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::sd_spmv(ul, n, alpha, ap, x, inc_x, beta, y, inc_y);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle packed by rows is the lower triangle packed by columns
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::sd_spmv(ul, n, alpha, ap, x, inc_x, beta, y, inc_y);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dspmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DTRMV perform one of the matrix-vector operations $\vec{x}=op(\boldsymbol{A}) * \vec{x}$,
+/// where $\boldsymbol{A}$ is a triangular matrix.
+///
+/// # Description
+/// DTRMV performs one of the matrix-vector operations
+/// $$\vec{x}=\boldsymbol{A} * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^T * \vec{x}$$
+///
+/// where $\boldsymbol{A}$ is an n by n unit, or non-unit, upper or lower triangular matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether matrix `a` is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `a`(in) - the triangular matrix. Only the triangle selected by `uplo` is referenced.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+/// `x`(in, out) - vector x. On exit, it is overwritten with the transformed vector.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dtrmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    a: *const f64,
+    lda: BlasInt,
+    x: *mut f64,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtrmv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_trmv(ul, ta, dg, n, a, lda, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtrmv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_trmv(ul, ta, dg, n, a, lda, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dtrmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DTRSV solve one of the systems of equations $op(\boldsymbol{A}) * \vec{x} = \vec{b}$,
+/// where $\boldsymbol{A}$ is a triangular matrix.
+///
+/// # Description
+/// DTRSV solves one of the systems of equations
+/// $$\boldsymbol{A} * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^T * \vec{x} = \vec{b}$$
+///
+/// where $\vec{b}$ and $\vec{x}$ are n element vectors and $\boldsymbol{A}$ is an n by n unit, or non-unit,
+/// upper or lower triangular matrix.
+///
+/// No test for singularity or near-singularity is included in this routine.
+/// Such tests must be performed before calling this routine.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether matrix `a` is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `a`(in) - the triangular matrix. Only the triangle selected by `uplo` is referenced.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+/// `x`(in, out) - On entry, the right-hand side vector b. On exit, it is overwritten with the solution vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dtrsv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    a: *const f64,
+    lda: BlasInt,
+    x: *mut f64,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtrsv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_trsv(ul, ta, dg, n, a, lda, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtrsv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_trsv(ul, ta, dg, n, a, lda, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dtrsv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DTBMV perform one of the matrix-vector operations $\vec{x}=op(\boldsymbol{A}) * \vec{x}$,
+/// where $\boldsymbol{A}$ is a triangular band matrix.
+///
+/// # Description
+/// DTBMV performs one of the matrix-vector operations
+/// $$\vec{x}=\boldsymbol{A} * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^T * \vec{x}$$
+///
+/// where $\boldsymbol{A}$ is an n by n unit, or non-unit, upper or lower triangular band matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether the matrix would be transposed, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether the matrix is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `k`(in) - the number of super-diagonals (or sub-diagonals) of the matrix.
+///
+/// `a`(in) - the band matrix. If `a` is col-major and `uplo` is `Upper`, $A_{ij}$ is stored in
+/// `a[(k + i - j) + j * lda]`; if `uplo` is `Lower`, $A_{ij}$ is stored in `a[(i - j) + j * lda]`.
+/// If `a` is row-major and `uplo` is `Upper`, $A_{ij}$ is stored in `a[i * lda + (j - i)]`;
+/// if `uplo` is `Lower`, $A_{ij}$ is stored in `a[i * lda + (k + j - i)]`.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `k + 1`.
+///
+/// `x`(in, out) - vector x. On exit, it is overwritten with the transformed vector.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dtbmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    k: BlasInt,
+    a: *const f64,
+    lda: BlasInt,
+    x: *mut f64,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtbmv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_tbmv(ul, ta, dg, n, k, a, lda, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtbmv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_tbmv(ul, ta, dg, n, k, a, lda, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dtbmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DTBSV solve one of the systems of equations $op(\boldsymbol{A}) * \vec{x} = \vec{b}$,
+/// where $\boldsymbol{A}$ is a triangular band matrix.
+///
+/// # Description
+/// DTBSV solves one of the systems of equations
+/// $$\boldsymbol{A} * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^T * \vec{x} = \vec{b}$$
+///
+/// where $\vec{b}$ and $\vec{x}$ are n element vectors and $\boldsymbol{A}$ is an n by n unit, or non-unit,
+/// upper or lower triangular band matrix.
+///
+/// No test for singularity or near-singularity is included in this routine.
+/// Such tests must be performed before calling this routine.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether the matrix would be transposed, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether the matrix is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `k`(in) - the number of super-diagonals (or sub-diagonals) of the matrix.
+///
+/// `a`(in) - the band matrix. If `a` is col-major and `uplo` is `Upper`, $A_{ij}$ is stored in
+/// `a[(k + i - j) + j * lda]`; if `uplo` is `Lower`, $A_{ij}$ is stored in `a[(i - j) + j * lda]`.
+/// If `a` is row-major and `uplo` is `Upper`, $A_{ij}$ is stored in `a[i * lda + (j - i)]`;
+/// if `uplo` is `Lower`, $A_{ij}$ is stored in `a[i * lda + (k + j - i)]`.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `k + 1`.
+///
+/// `x`(in, out) - On entry, the right-hand side vector b. On exit, it is overwritten with the solution vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dtbsv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    k: BlasInt,
+    a: *const f64,
+    lda: BlasInt,
+    x: *mut f64,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtbsv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_tbsv(ul, ta, dg, n, k, a, lda, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtbsv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_tbsv(ul, ta, dg, n, k, a, lda, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dtbsv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DTPMV perform one of the matrix-vector operations $\vec{x}=op(\boldsymbol{A}) * \vec{x}$,
+/// where $\boldsymbol{A}$ is a triangular packed matrix.
+///
+/// # Description
+/// DTPMV performs one of the matrix-vector operations
+/// $$\vec{x}=\boldsymbol{A} * \vec{x}$$
+/// or
+/// $$\vec{x}=\boldsymbol{A}^T * \vec{x}$$
+///
+/// where $\boldsymbol{A}$ is an n by n unit, or non-unit, upper or lower triangular packed matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `ap` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether the matrix would be transposed, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether the matrix is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `ap`(in) - the packed matrix, with at least n * (n + 1) / 2 elements. The selected triangle is packed
+/// column by column if `ap` is col-major, or row by row if `ap` is row-major.
+///
+/// `x`(in, out) - vector x. On exit, it is overwritten with the transformed vector.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dtpmv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    ap: *const f64,
+    x: *mut f64,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtpmv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_tpmv(ul, ta, dg, n, ap, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtpmv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_tpmv(ul, ta, dg, n, ap, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dtpmv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DTPSV solve one of the systems of equations $op(\boldsymbol{A}) * \vec{x} = \vec{b}$,
+/// where $\boldsymbol{A}$ is a triangular packed matrix.
+///
+/// # Description
+/// DTPSV solves one of the systems of equations
+/// $$\boldsymbol{A} * \vec{x} = \vec{b}$$
+/// or
+/// $$\boldsymbol{A}^T * \vec{x} = \vec{b}$$
+///
+/// where $\vec{b}$ and $\vec{x}$ are n element vectors and $\boldsymbol{A}$ is an n by n unit, or non-unit,
+/// upper or lower triangular packed matrix.
+///
+/// No test for singularity or near-singularity is included in this routine.
+/// Such tests must be performed before calling this routine.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether matrix `ap` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - whether the matrix would be transposed, that is, the $op(\boldsymbol{A})$
+///
+/// `diag`(in) - whether the matrix is unit triangular. If it is, the diagonal elements are not referenced
+/// and are assumed to be one.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `ap`(in) - the packed matrix, with at least n * (n + 1) / 2 elements. The selected triangle is packed
+/// column by column if `ap` is col-major, or row by row if `ap` is row-major.
+///
+/// `x`(in, out) - On entry, the right-hand side vector b. On exit, it is overwritten with the solution vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dtpsv(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    n: BlasInt,
+    ap: *const f64,
+    x: *mut f64,
+    inc_x: BlasInt,
+) {
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtpsv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_tpsv(ul, ta, dg, n, ap, x, inc_x);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, so both `uplo` and `trans_a` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let ta = match trans_a {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_dtpsv",
+                    "Illegal TransA setting, {:?}\n",
+                    trans_a
+                );
+            }
+        };
+        core::sd_tpsv(ul, ta, dg, n, ap, x, inc_x);
+    } else {
+        xerbla!(
+            false,
+            2,
+            "cblas_dtpsv",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DGER perform the rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^T + \boldsymbol{A}$.
+///
+/// # Description
+/// DGER performs the rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^T + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an m element vector, $\vec{y}$ is an n element vector and $\boldsymbol{A}$ is an m by n matrix.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `m`(in) - the number of rows.
+///
+/// `n`(in) - the number of cols.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `y`(in) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+/// `a`(in, out) - matrix a. On exit, it is overwritten by the updated matrix.
+///
+/// `lda`(in) - if `a` is col-major, `lda` should be the number of rows of the outer matrix;
+/// else, `lda` should be the number of cols of the outer matrix.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dger(
+    order: CBlasOrder,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: f64,
+    x: *const f64,
+    inc_x: BlasInt,
+    y: *const f64,
+    inc_y: BlasInt,
+    a: *mut f64,
+    lda: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        core::sd_ger(m, n, alpha, x, inc_x, y, inc_y, a, lda, false, "cblas_dger");
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major A is a col-major A^T, and A^T = alpha * y * x^T + A^T
+        core::sd_ger(n, m, alpha, y, inc_y, x, inc_x, a, lda, true, "cblas_dger");
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_dger",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DSYR perform the symmetric rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^T + \boldsymbol{A}$.
+///
+/// # Description
+/// DSYR performs the symmetric rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^T + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an n element vector and $\boldsymbol{A}$ is an n by n symmetric matrix.
+/// Only the triangle selected by `uplo` is referenced and updated.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `a`(in, out) - the symmetric matrix. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dsyr(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: f64,
+    x: *const f64,
+    inc_x: BlasInt,
+    a: *mut f64,
+    lda: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::sd_syr(ul, n, alpha, x, inc_x, a, lda);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::sd_syr(ul, n, alpha, x, inc_x, a, lda);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_dsyr",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DSYR2 perform the symmetric rank 2 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^T + \alpha * \vec{y} * \vec{x}^T + \boldsymbol{A}$.
+///
+/// # Description
+/// DSYR2 performs the symmetric rank 2 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^T + \alpha * \vec{y} * \vec{x}^T + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ and $\vec{y}$ are n element vectors and $\boldsymbol{A}$ is an n by n symmetric matrix.
+/// Only the triangle selected by `uplo` is referenced and updated.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `a` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `n`(in) - the order of matrix `a`.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `y`(in) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+/// `a`(in, out) - the symmetric matrix. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `lda`(in) - the leading dimension of `a`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dsyr2(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: f64,
+    x: *const f64,
+    inc_x: BlasInt,
+    y: *const f64,
+    inc_y: BlasInt,
+    a: *mut f64,
+    lda: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::sd_syr2(ul, n, alpha, x, inc_x, y, inc_y, a, lda);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::sd_syr2(ul, n, alpha, x, inc_x, y, inc_y, a, lda);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_dsyr2",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DSPR perform the symmetric rank 1 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^T + \boldsymbol{A}$.
+///
+/// # Description
+/// DSPR performs the symmetric rank 1 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{x}^T + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ is an n element vector and $\boldsymbol{A}$ is an n by n symmetric matrix, supplied in packed form.
+/// Only the triangle selected by `uplo` is stored and updated.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the matrix is supplied in `ap`.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `ap`(in, out) - the packed symmetric matrix, with at least n * (n + 1) / 2 elements, stored in the same
+/// way as [`cblas_dspmv`](crate::level2::cblas_dspmv). On exit, it is overwritten by the updated matrix.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dspr(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: f64,
+    x: *const f64,
+    inc_x: BlasInt,
+    ap: *mut f64,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::sd_spr(ul, n, alpha, x, inc_x, ap);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::sd_spr(ul, n, alpha, x, inc_x, ap);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_dspr",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// DSPR2 perform the symmetric rank 2 operation $\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^T + \alpha * \vec{y} * \vec{x}^T + \boldsymbol{A}$.
+///
+/// # Description
+/// DSPR2 performs the symmetric rank 2 operation
+/// $$\boldsymbol{A}=\alpha * \vec{x} * \vec{y}^T + \alpha * \vec{y} * \vec{x}^T + \boldsymbol{A}$$
+///
+/// where $\vec{x}$ and $\vec{y}$ are n element vectors and $\boldsymbol{A}$ is an n by n symmetric matrix, supplied in packed form.
+/// Only the triangle selected by `uplo` is stored and updated.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrix `ap` is row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangular part of the matrix is supplied in `ap`.
+///
+/// `n`(in) - the order of the matrix.
+///
+/// `alpha`(in) - constant scaling factor $\alpha$.
+///
+/// `x`(in) - vector x.
+///
+/// `inc_x`(in) - increment of vector x.
+///
+/// `y`(in) - vector y.
+///
+/// `inc_y`(in) - increment of vector y.
+///
+/// `ap`(in, out) - the packed symmetric matrix, with at least n * (n + 1) / 2 elements, stored in the same
+/// way as [`cblas_dspmv`](crate::level2::cblas_dspmv). On exit, it is overwritten by the updated matrix.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_dspr2(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    n: BlasInt,
+    alpha: f64,
+    x: *const f64,
+    inc_x: BlasInt,
+    y: *const f64,
+    inc_y: BlasInt,
+    ap: *mut f64,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::sd_spr2(ul, n, alpha, x, inc_x, y, inc_y, ap);
+    } else if order == CBlasOrder::RowMajor {
+        // the upper triangle of a row-major matrix is the lower triangle of a col-major one
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::sd_spr2(ul, n, alpha, x, inc_x, y, inc_y, ap);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_dspr2",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
@@ -1,4 +1,10 @@
-mod core;
+mod c;
+pub(crate) mod core;
+mod d;
 mod s;
+mod z;
 
+pub use c::*;
+pub use d::*;
 pub use s::*;
+pub use z::*;
@@ -0,0 +1,323 @@
+//! AVX2 and FMA kernels of gemv. They have the same arguments as the cores, take the case of unit
+//! increments and leave the strided and negative increments to the cores.
+//!
+//! Both kernels go through A by blocks of `GEMV_MB` rows, so the block of y, or of x in the
+//! transposed case, stays in the L1 cache while the cols of A stream through. Four cols of A are
+//! taken at a time, so y is read and written once for every four cols of $\boldsymbol{A}$, and x is
+//! loaded once for four dot products of $\boldsymbol{A}^T$.
+
+use super::naive::core::{self, GEMV_MB};
+use crate::common::BlasInt;
+use crate::level1::x86_64::{hsum_pd, hsum_ps};
+use std::arch::x86_64::*;
+use std::cmp::min;
+
+pub(crate) unsafe fn sgemv(
+    trans: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: f32,
+    a: *const f32,
+    lda: BlasInt,
+    x: *const f32,
+    inc_x: BlasInt,
+    beta: f32,
+    y: *mut f32,
+    inc_y: BlasInt,
+) {
+    core::sd_gemv_with(
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        inc_x,
+        beta,
+        y,
+        inc_y,
+        sgemv_n_kernel,
+        sgemv_t_kernel,
+    );
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sgemv_n_kernel(
+    m: usize,
+    n: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    y: *mut f32,
+) {
+    for i0 in (0..m).step_by(GEMV_MB) {
+        let mb = min(GEMV_MB, m - i0);
+        let y = y.add(i0);
+        let mut j = 0;
+        while j + 4 <= n {
+            let col = [0, 1, 2, 3].map(|c| a.add(i0 + (j + c) * lda));
+            let t = [0, 1, 2, 3].map(|c| alpha * *x.add(j + c));
+            let tv = t.map(|t| _mm256_set1_ps(t));
+            let mut i = 0;
+            while i + 8 <= mb {
+                let mut yv = _mm256_loadu_ps(y.add(i));
+                for (col, tv) in col.iter().zip(&tv) {
+                    yv = _mm256_fmadd_ps(_mm256_loadu_ps(col.add(i)), *tv, yv);
+                }
+                _mm256_storeu_ps(y.add(i), yv);
+                i += 8;
+            }
+            for i in i..mb {
+                let mut yi = *y.add(i);
+                for (col, t) in col.iter().zip(&t) {
+                    yi += *col.add(i) * t;
+                }
+                *y.add(i) = yi;
+            }
+            j += 4;
+        }
+        for j in j..n {
+            let col = a.add(i0 + j * lda);
+            let t = alpha * *x.add(j);
+            let tv = _mm256_set1_ps(t);
+            let mut i = 0;
+            while i + 8 <= mb {
+                let yv = _mm256_loadu_ps(y.add(i));
+                _mm256_storeu_ps(
+                    y.add(i),
+                    _mm256_fmadd_ps(_mm256_loadu_ps(col.add(i)), tv, yv),
+                );
+                i += 8;
+            }
+            for i in i..mb {
+                *y.add(i) += *col.add(i) * t;
+            }
+        }
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sgemv_t_kernel(
+    m: usize,
+    n: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    y: *mut f32,
+) {
+    for i0 in (0..m).step_by(GEMV_MB) {
+        let mb = min(GEMV_MB, m - i0);
+        let x = x.add(i0);
+        let mut j = 0;
+        while j + 4 <= n {
+            let col = [0, 1, 2, 3].map(|c| a.add(i0 + (j + c) * lda));
+            // two independent accumulators for each col hide the latency of fma
+            let mut acc = [[_mm256_setzero_ps(); 2]; 4];
+            let mut i = 0;
+            while i + 16 <= mb {
+                let xv = [_mm256_loadu_ps(x.add(i)), _mm256_loadu_ps(x.add(i + 8))];
+                for (col, acc) in col.iter().zip(acc.iter_mut()) {
+                    acc[0] = _mm256_fmadd_ps(_mm256_loadu_ps(col.add(i)), xv[0], acc[0]);
+                    acc[1] = _mm256_fmadd_ps(_mm256_loadu_ps(col.add(i + 8)), xv[1], acc[1]);
+                }
+                i += 16;
+            }
+            if i + 8 <= mb {
+                let xv = _mm256_loadu_ps(x.add(i));
+                for (col, acc) in col.iter().zip(acc.iter_mut()) {
+                    acc[0] = _mm256_fmadd_ps(_mm256_loadu_ps(col.add(i)), xv, acc[0]);
+                }
+                i += 8;
+            }
+            for (c, (col, acc)) in col.iter().zip(&acc).enumerate() {
+                let mut dot = hsum_ps(_mm256_add_ps(acc[0], acc[1]));
+                for i in i..mb {
+                    dot += *col.add(i) * *x.add(i);
+                }
+                *y.add(j + c) += alpha * dot;
+            }
+            j += 4;
+        }
+        for j in j..n {
+            let col = a.add(i0 + j * lda);
+            let mut acc = [_mm256_setzero_ps(); 2];
+            let mut i = 0;
+            while i + 16 <= mb {
+                acc[0] = _mm256_fmadd_ps(
+                    _mm256_loadu_ps(col.add(i)),
+                    _mm256_loadu_ps(x.add(i)),
+                    acc[0],
+                );
+                acc[1] = _mm256_fmadd_ps(
+                    _mm256_loadu_ps(col.add(i + 8)),
+                    _mm256_loadu_ps(x.add(i + 8)),
+                    acc[1],
+                );
+                i += 16;
+            }
+            let mut dot = hsum_ps(_mm256_add_ps(acc[0], acc[1]));
+            for i in i..mb {
+                dot += *col.add(i) * *x.add(i);
+            }
+            *y.add(j) += alpha * dot;
+        }
+    }
+}
+
+pub(crate) unsafe fn dgemv(
+    trans: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: f64,
+    a: *const f64,
+    lda: BlasInt,
+    x: *const f64,
+    inc_x: BlasInt,
+    beta: f64,
+    y: *mut f64,
+    inc_y: BlasInt,
+) {
+    core::sd_gemv_with(
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        inc_x,
+        beta,
+        y,
+        inc_y,
+        dgemv_n_kernel,
+        dgemv_t_kernel,
+    );
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dgemv_n_kernel(
+    m: usize,
+    n: usize,
+    alpha: f64,
+    a: *const f64,
+    lda: usize,
+    x: *const f64,
+    y: *mut f64,
+) {
+    for i0 in (0..m).step_by(GEMV_MB) {
+        let mb = min(GEMV_MB, m - i0);
+        let y = y.add(i0);
+        let mut j = 0;
+        while j + 4 <= n {
+            let col = [0, 1, 2, 3].map(|c| a.add(i0 + (j + c) * lda));
+            let t = [0, 1, 2, 3].map(|c| alpha * *x.add(j + c));
+            let tv = t.map(|t| _mm256_set1_pd(t));
+            let mut i = 0;
+            while i + 4 <= mb {
+                let mut yv = _mm256_loadu_pd(y.add(i));
+                for (col, tv) in col.iter().zip(&tv) {
+                    yv = _mm256_fmadd_pd(_mm256_loadu_pd(col.add(i)), *tv, yv);
+                }
+                _mm256_storeu_pd(y.add(i), yv);
+                i += 4;
+            }
+            for i in i..mb {
+                let mut yi = *y.add(i);
+                for (col, t) in col.iter().zip(&t) {
+                    yi += *col.add(i) * t;
+                }
+                *y.add(i) = yi;
+            }
+            j += 4;
+        }
+        for j in j..n {
+            let col = a.add(i0 + j * lda);
+            let t = alpha * *x.add(j);
+            let tv = _mm256_set1_pd(t);
+            let mut i = 0;
+            while i + 4 <= mb {
+                let yv = _mm256_loadu_pd(y.add(i));
+                _mm256_storeu_pd(
+                    y.add(i),
+                    _mm256_fmadd_pd(_mm256_loadu_pd(col.add(i)), tv, yv),
+                );
+                i += 4;
+            }
+            for i in i..mb {
+                *y.add(i) += *col.add(i) * t;
+            }
+        }
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dgemv_t_kernel(
+    m: usize,
+    n: usize,
+    alpha: f64,
+    a: *const f64,
+    lda: usize,
+    x: *const f64,
+    y: *mut f64,
+) {
+    for i0 in (0..m).step_by(GEMV_MB) {
+        let mb = min(GEMV_MB, m - i0);
+        let x = x.add(i0);
+        let mut j = 0;
+        while j + 4 <= n {
+            let col = [0, 1, 2, 3].map(|c| a.add(i0 + (j + c) * lda));
+            // two independent accumulators for each col hide the latency of fma
+            let mut acc = [[_mm256_setzero_pd(); 2]; 4];
+            let mut i = 0;
+            while i + 8 <= mb {
+                let xv = [_mm256_loadu_pd(x.add(i)), _mm256_loadu_pd(x.add(i + 4))];
+                for (col, acc) in col.iter().zip(acc.iter_mut()) {
+                    acc[0] = _mm256_fmadd_pd(_mm256_loadu_pd(col.add(i)), xv[0], acc[0]);
+                    acc[1] = _mm256_fmadd_pd(_mm256_loadu_pd(col.add(i + 4)), xv[1], acc[1]);
+                }
+                i += 8;
+            }
+            if i + 4 <= mb {
+                let xv = _mm256_loadu_pd(x.add(i));
+                for (col, acc) in col.iter().zip(acc.iter_mut()) {
+                    acc[0] = _mm256_fmadd_pd(_mm256_loadu_pd(col.add(i)), xv, acc[0]);
+                }
+                i += 4;
+            }
+            for (c, (col, acc)) in col.iter().zip(&acc).enumerate() {
+                let mut dot = hsum_pd(_mm256_add_pd(acc[0], acc[1]));
+                for i in i..mb {
+                    dot += *col.add(i) * *x.add(i);
+                }
+                *y.add(j + c) += alpha * dot;
+            }
+            j += 4;
+        }
+        for j in j..n {
+            let col = a.add(i0 + j * lda);
+            let mut acc = [_mm256_setzero_pd(); 2];
+            let mut i = 0;
+            while i + 8 <= mb {
+                acc[0] = _mm256_fmadd_pd(
+                    _mm256_loadu_pd(col.add(i)),
+                    _mm256_loadu_pd(x.add(i)),
+                    acc[0],
+                );
+                acc[1] = _mm256_fmadd_pd(
+                    _mm256_loadu_pd(col.add(i + 4)),
+                    _mm256_loadu_pd(x.add(i + 4)),
+                    acc[1],
+                );
+                i += 8;
+            }
+            let mut dot = hsum_pd(_mm256_add_pd(acc[0], acc[1]));
+            for i in i..mb {
+                dot += *col.add(i) * *x.add(i);
+            }
+            *y.add(j) += alpha * dot;
+        }
+    }
+}
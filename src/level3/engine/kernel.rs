@@ -0,0 +1,133 @@
+use crate::common::{Complex32, Complex64};
+use crate::dispatch::table;
+use num_traits::{One, Zero};
+use std::ops::{Add, Mul};
+
+/// Form C := C + alpha * A * B for an `mr` by `nr` tile of C, where A is a packed micro-panel of
+/// `mr` rows, B is a packed micro-panel of `nr` cols and both of them have `k` slices. The
+/// arguments are `k`, `alpha`, `a`, `b`, `c` and `ldc`.
+pub(crate) type MicroKernel<T> = unsafe fn(usize, T, *const T, *const T, *mut T, usize);
+
+/// A micro-kernel with its register tile `mr` by `nr` and its cache block sizes `mc`, `kc` and
+/// `nc`. `mc` is a multiple of `mr` and `nc` is a multiple of `nr`. `isa` is the instruction set
+/// the micro-kernel is written in, such as `avx2`.
+#[derive(Clone, Copy)]
+pub(crate) struct Kernel<T> {
+    pub isa: &'static str,
+    pub mr: usize,
+    pub nr: usize,
+    pub mc: usize,
+    pub kc: usize,
+    pub nc: usize,
+    pub micro: MicroKernel<T>,
+}
+
+/// The element types which could be multiplied by the engine.
+pub(crate) trait GemmScalar:
+    Copy + Zero + One + PartialEq + Add<Output = Self> + Mul<Output = Self>
+{
+    /// The kernel of this type in the chosen kernel set.
+    fn kernel() -> Kernel<Self>;
+}
+
+/// The portable micro-kernel, which keeps the `MR` by `NR` tile in a local array and leaves the
+/// vectorization to the compiler.
+pub(crate) unsafe fn micro_generic<T, const MR: usize, const NR: usize>(
+    k: usize,
+    alpha: T,
+    a: *const T,
+    b: *const T,
+    c: *mut T,
+    ldc: usize,
+) where
+    T: GemmScalar,
+{
+    let mut ab = [[T::zero(); MR]; NR];
+    for p in 0..k {
+        let ap = std::slice::from_raw_parts(a.add(p * MR), MR);
+        let bp = std::slice::from_raw_parts(b.add(p * NR), NR);
+        for (abj, &bpj) in ab.iter_mut().zip(bp) {
+            for (abij, &api) in abj.iter_mut().zip(ap) {
+                *abij = *abij + api * bpj;
+            }
+        }
+    }
+    for (j, abj) in ab.iter().enumerate() {
+        for (i, &abij) in abj.iter().enumerate() {
+            let cij = c.add(i + j * ldc);
+            *cij = *cij + alpha * abij;
+        }
+    }
+}
+
+pub(crate) fn sgemm_generic() -> Kernel<f32> {
+    Kernel {
+        isa: "generic",
+        mr: 8,
+        nr: 4,
+        mc: 128,
+        kc: 256,
+        nc: 2048,
+        micro: micro_generic::<f32, 8, 4>,
+    }
+}
+
+pub(crate) fn dgemm_generic() -> Kernel<f64> {
+    Kernel {
+        isa: "generic",
+        mr: 4,
+        nr: 4,
+        mc: 128,
+        kc: 256,
+        nc: 2048,
+        micro: micro_generic::<f64, 4, 4>,
+    }
+}
+
+pub(crate) fn cgemm_generic() -> Kernel<Complex32> {
+    Kernel {
+        isa: "generic",
+        mr: 4,
+        nr: 2,
+        mc: 128,
+        kc: 256,
+        nc: 2048,
+        micro: micro_generic::<Complex32, 4, 2>,
+    }
+}
+
+pub(crate) fn zgemm_generic() -> Kernel<Complex64> {
+    Kernel {
+        isa: "generic",
+        mr: 4,
+        nr: 2,
+        mc: 128,
+        kc: 128,
+        nc: 2048,
+        micro: micro_generic::<Complex64, 4, 2>,
+    }
+}
+
+impl GemmScalar for f32 {
+    fn kernel() -> Kernel<f32> {
+        table().s.gemm
+    }
+}
+
+impl GemmScalar for f64 {
+    fn kernel() -> Kernel<f64> {
+        table().d.gemm
+    }
+}
+
+impl GemmScalar for Complex32 {
+    fn kernel() -> Kernel<Complex32> {
+        table().c.gemm
+    }
+}
+
+impl GemmScalar for Complex64 {
+    fn kernel() -> Kernel<Complex64> {
+        table().z.gemm
+    }
+}
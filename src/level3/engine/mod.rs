@@ -0,0 +1,211 @@
+//! The cache-blocked and packed gemm engine of level 3, in the style of Goto and BLIS.
+//!
+//! $\boldsymbol{C} := \boldsymbol{C} + \alpha * \boldsymbol{A} * \boldsymbol{B}$ is computed by
+//! five nested loops around a micro-kernel:
+//! - the cols of C and B are split into blocks of `nc` cols,
+//! - the inner dimension is split into blocks of `kc`, and the `kc` by `nc` block of B is packed
+//!   into micro-panels of `nr` cols, which stay in the L3 cache,
+//! - the rows of C and A are split into blocks of `mc` rows, and the `mc` by `kc` block of A is
+//!   packed into micro-panels of `mr` rows, which stay in the L2 cache,
+//! - the micro-panels of B and A are walked by the macro-kernel, and each pair of them updates an
+//!   `mr` by `nr` tile of C held in registers by the micro-kernel.
+//!
+//! A and B are read by closures, so every level 3 routine can feed the engine with its own
+//! storage, such as transposed, conjugated, symmetric, packed or band matrices. The closures are
+//! only called while packing, which is cheap compared with the multiplication itself.
+
+mod kernel;
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+pub(crate) use kernel::{cgemm_generic, dgemm_generic, sgemm_generic, zgemm_generic};
+pub(crate) use kernel::{GemmScalar, Kernel};
+#[cfg(target_arch = "x86_64")]
+pub(crate) use x86_64::*;
+
+use std::cmp::min;
+
+/// Form C := C + alpha * A * B, where A is an m by k matrix read by `a(i, l)`, B is a k by n
+/// matrix read by `b(l, j)` and C is an m by n col-major matrix.
+#[inline]
+pub(crate) unsafe fn gemm<T, FA, FB>(
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: FA,
+    b: FB,
+    c: *mut T,
+    ldc: usize,
+) where
+    T: GemmScalar,
+    FA: Fn(usize, usize) -> T,
+    FB: Fn(usize, usize) -> T,
+{
+    drive(None, m, n, k, alpha, a, b, c, ldc);
+}
+
+/// The same as `gemm`, but C is an n by n matrix and only its `upper` or lower triangle is
+/// referenced and updated.
+#[inline]
+pub(crate) unsafe fn gemm_tri<T, FA, FB>(
+    upper: bool,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: FA,
+    b: FB,
+    c: *mut T,
+    ldc: usize,
+) where
+    T: GemmScalar,
+    FA: Fn(usize, usize) -> T,
+    FB: Fn(usize, usize) -> T,
+{
+    drive(Some(upper), n, n, k, alpha, a, b, c, ldc);
+}
+
+/// Form C := beta * C, where C is an m by n col-major matrix, or only the `upper` or lower
+/// triangle of it if `tri` is `Some(upper)`. C is not read when beta is zero.
+pub(crate) unsafe fn scale<T>(tri: Option<bool>, m: usize, n: usize, beta: T, c: *mut T, ldc: usize)
+where
+    T: GemmScalar,
+{
+    if beta.is_one() {
+        return;
+    }
+    for j in 0..n {
+        let rows = match tri {
+            None => 0..m,
+            Some(true) => 0..min(m, j + 1),
+            Some(false) => min(m, j)..m,
+        };
+        for i in rows {
+            let cij = c.add(i + j * ldc);
+            *cij = if beta.is_zero() {
+                T::zero()
+            } else {
+                beta * *cij
+            };
+        }
+    }
+}
+
+/// Whether C(i, j) is referenced, `tri` has the same meaning as in `scale`.
+#[inline(always)]
+fn in_tri(tri: Option<bool>, i: usize, j: usize) -> bool {
+    match tri {
+        None => true,
+        Some(true) => i <= j,
+        Some(false) => i >= j,
+    }
+}
+
+/// The five loops of the engine. `tri` has the same meaning as in `scale`.
+#[inline(always)]
+unsafe fn drive<T, FA, FB>(
+    tri: Option<bool>,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: FA,
+    b: FB,
+    c: *mut T,
+    ldc: usize,
+) where
+    T: GemmScalar,
+    FA: Fn(usize, usize) -> T,
+    FB: Fn(usize, usize) -> T,
+{
+    if m == 0 || n == 0 || k == 0 || alpha.is_zero() {
+        return;
+    }
+    let ker = T::kernel();
+    let (mr, nr) = (ker.mr, ker.nr);
+    // the packed buffers never need to be larger than the matrices rounded up to whole
+    // micro-panels
+    let mc = min(ker.mc, m.next_multiple_of(mr));
+    let kc = min(ker.kc, k);
+    let nc = min(ker.nc, n.next_multiple_of(nr));
+    let mut a_pack = vec![T::zero(); mc * kc];
+    let mut b_pack = vec![T::zero(); kc * nc];
+    let mut tile = vec![T::zero(); mr * nr];
+
+    for jc in (0..n).step_by(nc) {
+        let nb = min(nc, n - jc);
+        // the rows of C which have referenced elements in the cols jc..jc + nb
+        let (row_lo, row_hi) = match tri {
+            None => (0, m),
+            Some(true) => (0, min(m, jc + nb)),
+            Some(false) => (jc, m),
+        };
+        for pc in (0..k).step_by(kc) {
+            let kb = min(kc, k - pc);
+            pack(nr, nb, kb, |j, p| b(pc + p, jc + j), &mut b_pack);
+            for ic in (row_lo..row_hi).step_by(mc) {
+                let mb = min(mc, row_hi - ic);
+                pack(mr, mb, kb, |i, p| a(ic + i, pc + p), &mut a_pack);
+                // the macro-kernel
+                for jr in (0..nb).step_by(nr) {
+                    let nb_r = min(nr, nb - jr);
+                    let bp = b_pack.as_ptr().add(jr * kb);
+                    for ir in (0..mb).step_by(mr) {
+                        let mb_r = min(mr, mb - ir);
+                        let ap = a_pack.as_ptr().add(ir * kb);
+                        let (i0, j0) = (ic + ir, jc + jr);
+                        let (i1, j1) = (i0 + mb_r - 1, j0 + nb_r - 1);
+                        // whether the tile has no referenced element, and whether all of its
+                        // elements are referenced
+                        let (skip, whole) = match tri {
+                            None => (false, true),
+                            Some(true) => (i0 > j1, i1 <= j0),
+                            Some(false) => (i1 < j0, i0 >= j1),
+                        };
+                        if skip {
+                            continue;
+                        }
+                        let cp = c.add(i0 + j0 * ldc);
+                        if whole && mb_r == mr && nb_r == nr {
+                            (ker.micro)(kb, alpha, ap, bp, cp, ldc);
+                        } else {
+                            // the tile is formed aside, and only its referenced part is added
+                            for t in tile.iter_mut() {
+                                *t = T::zero();
+                            }
+                            (ker.micro)(kb, alpha, ap, bp, tile.as_mut_ptr(), mr);
+                            for j in 0..nb_r {
+                                for i in 0..mb_r {
+                                    if in_tri(tri, i0 + i, j0 + j) {
+                                        let cij = cp.add(i + j * ldc);
+                                        *cij = *cij + tile[i + j * mr];
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Pack the first `len` rows (or cols) of a block into micro-panels of `r` rows (or cols), each
+/// of which stores its `kb` slices of `r` elements one after another. `x(i, p)` reads the i-th
+/// row (or col) of the p-th slice, and the last micro-panel is padded with zeros.
+#[inline(always)]
+fn pack<T, F>(r: usize, len: usize, kb: usize, x: F, buf: &mut [T])
+where
+    T: GemmScalar,
+    F: Fn(usize, usize) -> T,
+{
+    for (panel, start) in (0..len).step_by(r).enumerate() {
+        let rows = min(r, len - start);
+        let dst = &mut buf[panel * r * kb..(panel + 1) * r * kb];
+        for (p, slice) in dst.chunks_exact_mut(r).enumerate() {
+            for (i, v) in slice.iter_mut().enumerate() {
+                *v = if i < rows { x(start + i, p) } else { T::zero() };
+            }
+        }
+    }
+}
@@ -0,0 +1,435 @@
+//! SSE2, AVX2 with FMA and AVX-512 micro-kernels. Each column of the tile is held in two vector
+//! registers, the micro-panel of A is loaded once per slice and every element of the micro-panel
+//! of B is broadcast against it.
+//!
+//! The complex kernels keep A interleaved as it is packed, and broadcast the real and imaginary
+//! parts of each element of B into two sets of accumulators, $A b_r$ and $A b_i$. The two sets are
+//! only combined into $A b$ by addsub when the tile is stored, so the loop over the slices is the
+//! same fmadd as the real kernels.
+
+use super::kernel::Kernel;
+use crate::common::{Complex32, Complex64};
+use std::arch::x86_64::*;
+
+pub(crate) fn sgemm_sse2() -> Kernel<f32> {
+    Kernel {
+        isa: "sse2",
+        mr: 8,
+        nr: 6,
+        mc: 128,
+        kc: 256,
+        nc: 3072,
+        micro: sgemm_8x6,
+    }
+}
+
+pub(crate) fn dgemm_sse2() -> Kernel<f64> {
+    Kernel {
+        isa: "sse2",
+        mr: 4,
+        nr: 6,
+        mc: 128,
+        kc: 256,
+        nc: 3072,
+        micro: dgemm_4x6,
+    }
+}
+
+pub(crate) fn sgemm_avx2() -> Kernel<f32> {
+    Kernel {
+        isa: "avx2",
+        mr: 16,
+        nr: 6,
+        mc: 192,
+        kc: 256,
+        nc: 3072,
+        micro: sgemm_16x6,
+    }
+}
+
+pub(crate) fn dgemm_avx2() -> Kernel<f64> {
+    Kernel {
+        isa: "avx2",
+        mr: 8,
+        nr: 6,
+        mc: 192,
+        kc: 256,
+        nc: 3072,
+        micro: dgemm_8x6,
+    }
+}
+
+pub(crate) fn sgemm_avx512() -> Kernel<f32> {
+    Kernel {
+        isa: "avx512",
+        mr: 32,
+        nr: 12,
+        mc: 192,
+        kc: 256,
+        nc: 3072,
+        micro: sgemm_32x12,
+    }
+}
+
+pub(crate) fn dgemm_avx512() -> Kernel<f64> {
+    Kernel {
+        isa: "avx512",
+        mr: 16,
+        nr: 12,
+        mc: 192,
+        kc: 256,
+        nc: 3072,
+        micro: dgemm_16x12,
+    }
+}
+
+pub(crate) fn cgemm_avx2() -> Kernel<Complex32> {
+    Kernel {
+        isa: "avx2",
+        mr: 8,
+        nr: 3,
+        mc: 96,
+        kc: 256,
+        nc: 3072,
+        micro: cgemm_8x3,
+    }
+}
+
+pub(crate) fn zgemm_avx2() -> Kernel<Complex64> {
+    Kernel {
+        isa: "avx2",
+        mr: 4,
+        nr: 3,
+        mc: 96,
+        kc: 128,
+        nc: 3072,
+        micro: zgemm_4x3,
+    }
+}
+
+pub(crate) fn cgemm_avx512() -> Kernel<Complex32> {
+    Kernel {
+        isa: "avx512",
+        mr: 16,
+        nr: 6,
+        mc: 96,
+        kc: 256,
+        nc: 3072,
+        micro: cgemm_16x6,
+    }
+}
+
+pub(crate) fn zgemm_avx512() -> Kernel<Complex64> {
+    Kernel {
+        isa: "avx512",
+        mr: 8,
+        nr: 6,
+        mc: 96,
+        kc: 128,
+        nc: 3072,
+        micro: zgemm_8x6,
+    }
+}
+
+// SSE2 is a part of x86_64, so the SSE2 kernels need no `target_feature`.
+unsafe fn sgemm_8x6(k: usize, alpha: f32, a: *const f32, b: *const f32, c: *mut f32, ldc: usize) {
+    let mut ab = [[_mm_setzero_ps(); 2]; 6];
+    let (mut a, mut b) = (a, b);
+    for _ in 0..k {
+        let a0 = _mm_loadu_ps(a);
+        let a1 = _mm_loadu_ps(a.add(4));
+        for (j, abj) in ab.iter_mut().enumerate() {
+            let bj = _mm_set1_ps(*b.add(j));
+            abj[0] = _mm_add_ps(abj[0], _mm_mul_ps(a0, bj));
+            abj[1] = _mm_add_ps(abj[1], _mm_mul_ps(a1, bj));
+        }
+        a = a.add(8);
+        b = b.add(6);
+    }
+    let alpha = _mm_set1_ps(alpha);
+    for (j, abj) in ab.iter().enumerate() {
+        let cj = c.add(j * ldc);
+        _mm_storeu_ps(cj, _mm_add_ps(_mm_loadu_ps(cj), _mm_mul_ps(alpha, abj[0])));
+        let cj = cj.add(4);
+        _mm_storeu_ps(cj, _mm_add_ps(_mm_loadu_ps(cj), _mm_mul_ps(alpha, abj[1])));
+    }
+}
+
+unsafe fn dgemm_4x6(k: usize, alpha: f64, a: *const f64, b: *const f64, c: *mut f64, ldc: usize) {
+    let mut ab = [[_mm_setzero_pd(); 2]; 6];
+    let (mut a, mut b) = (a, b);
+    for _ in 0..k {
+        let a0 = _mm_loadu_pd(a);
+        let a1 = _mm_loadu_pd(a.add(2));
+        for (j, abj) in ab.iter_mut().enumerate() {
+            let bj = _mm_set1_pd(*b.add(j));
+            abj[0] = _mm_add_pd(abj[0], _mm_mul_pd(a0, bj));
+            abj[1] = _mm_add_pd(abj[1], _mm_mul_pd(a1, bj));
+        }
+        a = a.add(4);
+        b = b.add(6);
+    }
+    let alpha = _mm_set1_pd(alpha);
+    for (j, abj) in ab.iter().enumerate() {
+        let cj = c.add(j * ldc);
+        _mm_storeu_pd(cj, _mm_add_pd(_mm_loadu_pd(cj), _mm_mul_pd(alpha, abj[0])));
+        let cj = cj.add(2);
+        _mm_storeu_pd(cj, _mm_add_pd(_mm_loadu_pd(cj), _mm_mul_pd(alpha, abj[1])));
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sgemm_16x6(k: usize, alpha: f32, a: *const f32, b: *const f32, c: *mut f32, ldc: usize) {
+    let mut ab = [[_mm256_setzero_ps(); 2]; 6];
+    let (mut a, mut b) = (a, b);
+    for _ in 0..k {
+        let a0 = _mm256_loadu_ps(a);
+        let a1 = _mm256_loadu_ps(a.add(8));
+        for (j, abj) in ab.iter_mut().enumerate() {
+            let bj = _mm256_broadcast_ss(&*b.add(j));
+            abj[0] = _mm256_fmadd_ps(a0, bj, abj[0]);
+            abj[1] = _mm256_fmadd_ps(a1, bj, abj[1]);
+        }
+        a = a.add(16);
+        b = b.add(6);
+    }
+    let alpha = _mm256_set1_ps(alpha);
+    for (j, abj) in ab.iter().enumerate() {
+        let cj = c.add(j * ldc);
+        _mm256_storeu_ps(cj, _mm256_fmadd_ps(alpha, abj[0], _mm256_loadu_ps(cj)));
+        let cj = cj.add(8);
+        _mm256_storeu_ps(cj, _mm256_fmadd_ps(alpha, abj[1], _mm256_loadu_ps(cj)));
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dgemm_8x6(k: usize, alpha: f64, a: *const f64, b: *const f64, c: *mut f64, ldc: usize) {
+    let mut ab = [[_mm256_setzero_pd(); 2]; 6];
+    let (mut a, mut b) = (a, b);
+    for _ in 0..k {
+        let a0 = _mm256_loadu_pd(a);
+        let a1 = _mm256_loadu_pd(a.add(4));
+        for (j, abj) in ab.iter_mut().enumerate() {
+            let bj = _mm256_broadcast_sd(&*b.add(j));
+            abj[0] = _mm256_fmadd_pd(a0, bj, abj[0]);
+            abj[1] = _mm256_fmadd_pd(a1, bj, abj[1]);
+        }
+        a = a.add(8);
+        b = b.add(6);
+    }
+    let alpha = _mm256_set1_pd(alpha);
+    for (j, abj) in ab.iter().enumerate() {
+        let cj = c.add(j * ldc);
+        _mm256_storeu_pd(cj, _mm256_fmadd_pd(alpha, abj[0], _mm256_loadu_pd(cj)));
+        let cj = cj.add(4);
+        _mm256_storeu_pd(cj, _mm256_fmadd_pd(alpha, abj[1], _mm256_loadu_pd(cj)));
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn sgemm_32x12(k: usize, alpha: f32, a: *const f32, b: *const f32, c: *mut f32, ldc: usize) {
+    let mut ab = [[_mm512_setzero_ps(); 2]; 12];
+    let (mut a, mut b) = (a, b);
+    for _ in 0..k {
+        let a0 = _mm512_loadu_ps(a);
+        let a1 = _mm512_loadu_ps(a.add(16));
+        for (j, abj) in ab.iter_mut().enumerate() {
+            let bj = _mm512_set1_ps(*b.add(j));
+            abj[0] = _mm512_fmadd_ps(a0, bj, abj[0]);
+            abj[1] = _mm512_fmadd_ps(a1, bj, abj[1]);
+        }
+        a = a.add(32);
+        b = b.add(12);
+    }
+    let alpha = _mm512_set1_ps(alpha);
+    for (j, abj) in ab.iter().enumerate() {
+        let cj = c.add(j * ldc);
+        _mm512_storeu_ps(cj, _mm512_fmadd_ps(alpha, abj[0], _mm512_loadu_ps(cj)));
+        let cj = cj.add(16);
+        _mm512_storeu_ps(cj, _mm512_fmadd_ps(alpha, abj[1], _mm512_loadu_ps(cj)));
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn dgemm_16x12(k: usize, alpha: f64, a: *const f64, b: *const f64, c: *mut f64, ldc: usize) {
+    let mut ab = [[_mm512_setzero_pd(); 2]; 12];
+    let (mut a, mut b) = (a, b);
+    for _ in 0..k {
+        let a0 = _mm512_loadu_pd(a);
+        let a1 = _mm512_loadu_pd(a.add(8));
+        for (j, abj) in ab.iter_mut().enumerate() {
+            let bj = _mm512_set1_pd(*b.add(j));
+            abj[0] = _mm512_fmadd_pd(a0, bj, abj[0]);
+            abj[1] = _mm512_fmadd_pd(a1, bj, abj[1]);
+        }
+        a = a.add(16);
+        b = b.add(12);
+    }
+    let alpha = _mm512_set1_pd(alpha);
+    for (j, abj) in ab.iter().enumerate() {
+        let cj = c.add(j * ldc);
+        _mm512_storeu_pd(cj, _mm512_fmadd_pd(alpha, abj[0], _mm512_loadu_pd(cj)));
+        let cj = cj.add(8);
+        _mm512_storeu_pd(cj, _mm512_fmadd_pd(alpha, abj[1], _mm512_loadu_pd(cj)));
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn cgemm_8x3(
+    k: usize,
+    alpha: Complex32,
+    a: *const Complex32,
+    b: *const Complex32,
+    c: *mut Complex32,
+    ldc: usize,
+) {
+    let mut ab_re = [[_mm256_setzero_ps(); 2]; 3];
+    let mut ab_im = [[_mm256_setzero_ps(); 2]; 3];
+    let (mut a, mut b) = (a as *const f32, b as *const f32);
+    for _ in 0..k {
+        let a0 = _mm256_loadu_ps(a);
+        let a1 = _mm256_loadu_ps(a.add(8));
+        for (j, (re, im)) in ab_re.iter_mut().zip(ab_im.iter_mut()).enumerate() {
+            let br = _mm256_broadcast_ss(&*b.add(2 * j));
+            let bi = _mm256_broadcast_ss(&*b.add(2 * j + 1));
+            re[0] = _mm256_fmadd_ps(a0, br, re[0]);
+            re[1] = _mm256_fmadd_ps(a1, br, re[1]);
+            im[0] = _mm256_fmadd_ps(a0, bi, im[0]);
+            im[1] = _mm256_fmadd_ps(a1, bi, im[1]);
+        }
+        a = a.add(16);
+        b = b.add(6);
+    }
+    let (alpha_re, alpha_im) = (_mm256_set1_ps(alpha.re), _mm256_set1_ps(alpha.im));
+    for (j, (re, im)) in ab_re.iter().zip(&ab_im).enumerate() {
+        let cj = c.add(j * ldc) as *mut f32;
+        for (u, (&re, &im)) in re.iter().zip(im).enumerate() {
+            // swapping the real and imaginary parts of a_r b_i, a_i b_i gives a_i b_i, a_r b_i
+            let ab = _mm256_addsub_ps(re, _mm256_permute_ps(im, 0b1011_0001));
+            let swapped = _mm256_permute_ps(ab, 0b1011_0001);
+            let ab = _mm256_fmaddsub_ps(ab, alpha_re, _mm256_mul_ps(swapped, alpha_im));
+            let cu = cj.add(8 * u);
+            _mm256_storeu_ps(cu, _mm256_add_ps(_mm256_loadu_ps(cu), ab));
+        }
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn zgemm_4x3(
+    k: usize,
+    alpha: Complex64,
+    a: *const Complex64,
+    b: *const Complex64,
+    c: *mut Complex64,
+    ldc: usize,
+) {
+    let mut ab_re = [[_mm256_setzero_pd(); 2]; 3];
+    let mut ab_im = [[_mm256_setzero_pd(); 2]; 3];
+    let (mut a, mut b) = (a as *const f64, b as *const f64);
+    for _ in 0..k {
+        let a0 = _mm256_loadu_pd(a);
+        let a1 = _mm256_loadu_pd(a.add(4));
+        for (j, (re, im)) in ab_re.iter_mut().zip(ab_im.iter_mut()).enumerate() {
+            let br = _mm256_broadcast_sd(&*b.add(2 * j));
+            let bi = _mm256_broadcast_sd(&*b.add(2 * j + 1));
+            re[0] = _mm256_fmadd_pd(a0, br, re[0]);
+            re[1] = _mm256_fmadd_pd(a1, br, re[1]);
+            im[0] = _mm256_fmadd_pd(a0, bi, im[0]);
+            im[1] = _mm256_fmadd_pd(a1, bi, im[1]);
+        }
+        a = a.add(8);
+        b = b.add(6);
+    }
+    let (alpha_re, alpha_im) = (_mm256_set1_pd(alpha.re), _mm256_set1_pd(alpha.im));
+    for (j, (re, im)) in ab_re.iter().zip(&ab_im).enumerate() {
+        let cj = c.add(j * ldc) as *mut f64;
+        for (u, (&re, &im)) in re.iter().zip(im).enumerate() {
+            let ab = _mm256_addsub_pd(re, _mm256_permute_pd(im, 0b0101));
+            let swapped = _mm256_permute_pd(ab, 0b0101);
+            let ab = _mm256_fmaddsub_pd(ab, alpha_re, _mm256_mul_pd(swapped, alpha_im));
+            let cu = cj.add(4 * u);
+            _mm256_storeu_pd(cu, _mm256_add_pd(_mm256_loadu_pd(cu), ab));
+        }
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn cgemm_16x6(
+    k: usize,
+    alpha: Complex32,
+    a: *const Complex32,
+    b: *const Complex32,
+    c: *mut Complex32,
+    ldc: usize,
+) {
+    let mut ab_re = [[_mm512_setzero_ps(); 2]; 6];
+    let mut ab_im = [[_mm512_setzero_ps(); 2]; 6];
+    let (mut a, mut b) = (a as *const f32, b as *const f32);
+    for _ in 0..k {
+        let a0 = _mm512_loadu_ps(a);
+        let a1 = _mm512_loadu_ps(a.add(16));
+        for (j, (re, im)) in ab_re.iter_mut().zip(ab_im.iter_mut()).enumerate() {
+            let br = _mm512_set1_ps(*b.add(2 * j));
+            let bi = _mm512_set1_ps(*b.add(2 * j + 1));
+            re[0] = _mm512_fmadd_ps(a0, br, re[0]);
+            re[1] = _mm512_fmadd_ps(a1, br, re[1]);
+            im[0] = _mm512_fmadd_ps(a0, bi, im[0]);
+            im[1] = _mm512_fmadd_ps(a1, bi, im[1]);
+        }
+        a = a.add(32);
+        b = b.add(12);
+    }
+    // AVX-512 has no addsub, so it is a fmaddsub by one
+    let one = _mm512_set1_ps(1.);
+    let (alpha_re, alpha_im) = (_mm512_set1_ps(alpha.re), _mm512_set1_ps(alpha.im));
+    for (j, (re, im)) in ab_re.iter().zip(&ab_im).enumerate() {
+        let cj = c.add(j * ldc) as *mut f32;
+        for (u, (&re, &im)) in re.iter().zip(im).enumerate() {
+            let ab = _mm512_fmaddsub_ps(re, one, _mm512_permute_ps(im, 0b1011_0001));
+            let swapped = _mm512_permute_ps(ab, 0b1011_0001);
+            let ab = _mm512_fmaddsub_ps(ab, alpha_re, _mm512_mul_ps(swapped, alpha_im));
+            let cu = cj.add(16 * u);
+            _mm512_storeu_ps(cu, _mm512_add_ps(_mm512_loadu_ps(cu), ab));
+        }
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn zgemm_8x6(
+    k: usize,
+    alpha: Complex64,
+    a: *const Complex64,
+    b: *const Complex64,
+    c: *mut Complex64,
+    ldc: usize,
+) {
+    let mut ab_re = [[_mm512_setzero_pd(); 2]; 6];
+    let mut ab_im = [[_mm512_setzero_pd(); 2]; 6];
+    let (mut a, mut b) = (a as *const f64, b as *const f64);
+    for _ in 0..k {
+        let a0 = _mm512_loadu_pd(a);
+        let a1 = _mm512_loadu_pd(a.add(8));
+        for (j, (re, im)) in ab_re.iter_mut().zip(ab_im.iter_mut()).enumerate() {
+            let br = _mm512_set1_pd(*b.add(2 * j));
+            let bi = _mm512_set1_pd(*b.add(2 * j + 1));
+            re[0] = _mm512_fmadd_pd(a0, br, re[0]);
+            re[1] = _mm512_fmadd_pd(a1, br, re[1]);
+            im[0] = _mm512_fmadd_pd(a0, bi, im[0]);
+            im[1] = _mm512_fmadd_pd(a1, bi, im[1]);
+        }
+        a = a.add(16);
+        b = b.add(12);
+    }
+    let one = _mm512_set1_pd(1.);
+    let (alpha_re, alpha_im) = (_mm512_set1_pd(alpha.re), _mm512_set1_pd(alpha.im));
+    for (j, (re, im)) in ab_re.iter().zip(&ab_im).enumerate() {
+        let cj = c.add(j * ldc) as *mut f64;
+        for (u, (&re, &im)) in re.iter().zip(im).enumerate() {
+            let ab = _mm512_fmaddsub_pd(re, one, _mm512_permute_pd(im, 0b0101_0101));
+            let swapped = _mm512_permute_pd(ab, 0b0101_0101);
+            let ab = _mm512_fmaddsub_pd(ab, alpha_re, _mm512_mul_pd(swapped, alpha_im));
+            let cu = cj.add(8 * u);
+            _mm512_storeu_pd(cu, _mm512_add_pd(_mm512_loadu_pd(cu), ab));
+        }
+    }
+}
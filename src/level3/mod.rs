@@ -0,0 +1,122 @@
+//! The level 3 functions of cblas
+//!
+//! This is a list of functions:
+//! s-functions:
+//! - [x] SGEMM - matrix matrix multiply
+//!
+//! - [x] SGEMMT - matrix matrix multiply updating one triangle(**not included in blas**)
+//!
+//! - [x] SGEMM_BATCH - groups of matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] SGEMM_BATCH_STRIDED - strided batch of matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] SSYMM - symmetric matrix matrix multiply
+//!
+//! - [x] SSYRK - symmetric rank-k update to a matrix
+//!
+//! - [x] SSYR2K - symmetric rank-2k update to a matrix
+//!
+//! - [x] STRMM - triangular matrix matrix multiply
+//!
+//! - [x] STRSM - solving triangular matrix with multiple right hand sides
+//!
+//! - [x] STRMM_OOP - out-of-place triangular matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] SSPMM - packed symmetric matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] SGBMM - band matrix matrix multiply(**not included in blas**)
+//!
+//! d-functions:
+//! - [x] DGEMM - matrix matrix multiply
+//!
+//! - [x] DGEMMT - matrix matrix multiply updating one triangle(**not included in blas**)
+//!
+//! - [x] DGEMM_BATCH - groups of matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] DGEMM_BATCH_STRIDED - strided batch of matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] DSYMM - symmetric matrix matrix multiply
+//!
+//! - [x] DSYRK - symmetric rank-k update to a matrix
+//!
+//! - [x] DSYR2K - symmetric rank-2k update to a matrix
+//!
+//! - [x] DTRMM - triangular matrix matrix multiply
+//!
+//! - [x] DTRSM - solving triangular matrix with multiple right hand sides
+//!
+//! - [x] DTRMM_OOP - out-of-place triangular matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] DSPMM - packed symmetric matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] DGBMM - band matrix matrix multiply(**not included in blas**)
+//!
+//! c-functions:
+//! - [x] CGEMM - matrix matrix multiply
+//!
+//! - [x] CGEMM3M - matrix matrix multiply with the 3M algorithm(**not included in blas**)
+//!
+//! - [x] CGEMMT - matrix matrix multiply updating one triangle(**not included in blas**)
+//!
+//! - [x] CGEMM_BATCH - groups of matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] CGEMM_BATCH_STRIDED - strided batch of matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] CSYMM - symmetric matrix matrix multiply
+//!
+//! - [x] CHEMM - hermitian matrix matrix multiply
+//!
+//! - [x] CSYRK - symmetric rank-k update to a matrix
+//!
+//! - [x] CHERK - hermitian rank-k update to a matrix
+//!
+//! - [x] CSYR2K - symmetric rank-2k update to a matrix
+//!
+//! - [x] CHER2K - hermitian rank-2k update to a matrix
+//!
+//! - [x] CTRMM - triangular matrix matrix multiply
+//!
+//! - [x] CTRSM - solving triangular matrix with multiple right hand sides
+//!
+//! - [x] CTRMM_OOP - out-of-place triangular matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] CSPMM - packed complex symmetric matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] CGBMM - band matrix matrix multiply(**not included in blas**)
+//!
+//! z-functions:
+//! - [x] ZGEMM - matrix matrix multiply
+//!
+//! - [x] ZGEMM3M - matrix matrix multiply with the 3M algorithm(**not included in blas**)
+//!
+//! - [x] ZGEMMT - matrix matrix multiply updating one triangle(**not included in blas**)
+//!
+//! - [x] ZGEMM_BATCH - groups of matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] ZGEMM_BATCH_STRIDED - strided batch of matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] ZSYMM - symmetric matrix matrix multiply
+//!
+//! - [x] ZHEMM - hermitian matrix matrix multiply
+//!
+//! - [x] ZSYRK - symmetric rank-k update to a matrix
+//!
+//! - [x] ZHERK - hermitian rank-k update to a matrix
+//!
+//! - [x] ZSYR2K - symmetric rank-2k update to a matrix
+//!
+//! - [x] ZHER2K - hermitian rank-2k update to a matrix
+//!
+//! - [x] ZTRMM - triangular matrix matrix multiply
+//!
+//! - [x] ZTRSM - solving triangular matrix with multiple right hand sides
+//!
+//! - [x] ZTRMM_OOP - out-of-place triangular matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] ZSPMM - packed complex symmetric matrix matrix multiply(**not included in blas**)
+//!
+//! - [x] ZGBMM - band matrix matrix multiply(**not included in blas**)
+//!
+pub(crate) mod engine;
+mod naive;
+pub use naive::*;
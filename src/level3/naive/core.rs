@@ -0,0 +1,2282 @@
+use crate::common::{BlasInt, Complex};
+use crate::level2::naive::core::a_tri_sv;
+use crate::level3::engine::{self, GemmScalar};
+use crate::utils::{col_major_index, letter_same};
+use num_traits::{Float, One, Zero};
+use std::cmp::{max, min};
+use std::ops::{Div, Sub};
+
+#[inline(always)]
+pub unsafe fn sd_gemm<T>(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    let info = sd_gemm_check(trans_a, trans_b, m, n, k, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+
+    let nota = letter_same(trans_a, 'N');
+    let notb = letter_same(trans_b, 'N');
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let a_op = |i: usize, l: usize| {
+        if nota {
+            *a.add(col_major_index(i, l, lda))
+        } else {
+            *a.add(col_major_index(l, i, lda))
+        }
+    };
+    let b_op = |l: usize, j: usize| {
+        if notb {
+            *b.add(col_major_index(l, j, ldb))
+        } else {
+            *b.add(col_major_index(j, l, ldb))
+        }
+    };
+    // First form C := beta * C, and then C += alpha * op(A) * op(B) by the engine.
+    engine::scale(None, m, n, beta, c, ldc as usize);
+    engine::gemm(m, n, k, alpha, a_op, b_op, c, ldc as usize);
+}
+
+/// Complex version of gemm. Besides 'N', 'T' and 'C', `trans_a` and `trans_b` also accept 'R',
+/// which means the conjugate without transpose, the same as `cz_gemv` in level 2.
+#[inline(always)]
+pub unsafe fn cz_gemm<T>(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    let info = cz_gemm_check(trans_a, trans_b, m, n, k, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let a_op = cz_op_elem(trans_a, a, lda);
+    let b_op = cz_op_elem(trans_b, b, ldb);
+    // First form C := beta * C, and then C += alpha * op(A) * op(B) by the engine.
+    engine::scale(None, m, n, beta, c, ldc as usize);
+    engine::gemm(m, n, k, alpha, a_op, b_op, c, ldc as usize);
+}
+
+/// gemm with the 3M algorithm. op(A) and op(B) are split into their real and imaginary parts, and
+/// the product is formed by three real matrix multiplications instead of four:
+///
+/// $P_1 = A_r B_r$, $P_2 = A_i B_i$, $P_3 = (A_r + A_i)(B_r + B_i)$,
+/// $op(\boldsymbol{A}) * op(\boldsymbol{B}) = P_1 - P_2 + i (P_3 - P_1 - P_2)$.
+///
+/// The arguments are the same as `cz_gemm`.
+#[inline(always)]
+pub unsafe fn cz_gemm3m<T>(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+    Complex<T>: GemmScalar,
+{
+    let zero: Complex<T> = Complex::new(From::from(0), From::from(0));
+    let one: Complex<T> = Complex::new(From::from(1), From::from(0));
+    let info = cz_gemm_check(trans_a, trans_b, m, n, k, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let c_elem = |i: usize, j: usize| c.add(col_major_index(i, j, ldc));
+    if alpha == zero || k == 0 {
+        // C := beta * C. C is not read when beta is zero.
+        for j in 0..n {
+            for i in 0..m {
+                let cij = c_elem(i, j);
+                *cij = if beta == zero { zero } else { beta * *cij };
+            }
+        }
+        return;
+    }
+
+    // The three real products are formed block by block of C, and op(A) and op(B) are split into
+    // their real and imaginary parts while the engine packs them, so the extra memory is bounded
+    // by the block sizes of the real kernel instead of growing with m, n and k.
+    let a_op = cz_op_elem(trans_a, a, lda);
+    let b_op = cz_op_elem(trans_b, b, ldb);
+    let ker = T::kernel();
+    let (mc, nc) = (min(m, ker.mc), min(n, ker.nc));
+    let real_zero: T = From::from(0);
+    let real_one: T = From::from(1);
+    let mut p1 = vec![real_zero; mc * nc];
+    let mut p2 = vec![real_zero; mc * nc];
+    let mut p3 = vec![real_zero; mc * nc];
+    for jc in (0..n).step_by(nc) {
+        let nb = min(nc, n - jc);
+        for ic in (0..m).step_by(mc) {
+            let mb = min(mc, m - ic);
+            for p in [&mut p1, &mut p2, &mut p3] {
+                p.iter_mut().for_each(|pij| *pij = real_zero);
+            }
+            let a_blk = |i: usize, l: usize| a_op(ic + i, l);
+            let b_blk = |l: usize, j: usize| b_op(l, jc + j);
+            // P1, P2 and P3 of the block
+            engine::gemm(
+                mb,
+                nb,
+                k,
+                real_one,
+                |i, l| a_blk(i, l).re,
+                |l, j| b_blk(l, j).re,
+                p1.as_mut_ptr(),
+                mb,
+            );
+            engine::gemm(
+                mb,
+                nb,
+                k,
+                real_one,
+                |i, l| a_blk(i, l).im,
+                |l, j| b_blk(l, j).im,
+                p2.as_mut_ptr(),
+                mb,
+            );
+            engine::gemm(
+                mb,
+                nb,
+                k,
+                real_one,
+                |i, l| {
+                    let ail = a_blk(i, l);
+                    ail.re + ail.im
+                },
+                |l, j| {
+                    let blj = b_blk(l, j);
+                    blj.re + blj.im
+                },
+                p3.as_mut_ptr(),
+                mb,
+            );
+            for j in 0..nb {
+                for i in 0..mb {
+                    let idx = i + j * mb;
+                    let prod = Complex::new(p1[idx] - p2[idx], p3[idx] - p1[idx] - p2[idx]);
+                    let cij = c_elem(ic + i, jc + j);
+                    *cij = if beta == zero {
+                        alpha * prod
+                    } else {
+                        alpha * prod + beta * *cij
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// Check the arguments of the real gemm routines, and return the number of the first illegal
+/// argument, or 0 if all of them are legal.
+#[inline(always)]
+fn sd_gemm_check(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    lda: BlasInt,
+    ldb: BlasInt,
+    ldc: BlasInt,
+) -> BlasInt {
+    // `nota` and `notb` are true if A and B are not transposed. 'C' is the same as 'T' for real
+    // matrices.
+    let nota = letter_same(trans_a, 'N');
+    let notb = letter_same(trans_b, 'N');
+    let nrowa = if nota { m } else { k };
+    let nrowb = if notb { k } else { n };
+    if !nota && !letter_same(trans_a, 'C') && !letter_same(trans_a, 'T') {
+        1
+    } else if !notb && !letter_same(trans_b, 'C') && !letter_same(trans_b, 'T') {
+        2
+    } else if m < 0 {
+        3
+    } else if n < 0 {
+        4
+    } else if k < 0 {
+        5
+    } else if lda < max(1, nrowa) {
+        8
+    } else if ldb < max(1, nrowb) {
+        10
+    } else if ldc < max(1, m) {
+        13
+    } else {
+        0
+    }
+}
+
+/// Check the arguments of the complex gemm routines, and return the number of the first illegal
+/// argument, or 0 if all of them are legal.
+#[inline(always)]
+fn cz_gemm_check(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    lda: BlasInt,
+    ldb: BlasInt,
+    ldc: BlasInt,
+) -> BlasInt {
+    let legal = |trans: char| {
+        letter_same(trans, 'N')
+            || letter_same(trans, 'T')
+            || letter_same(trans, 'C')
+            || letter_same(trans, 'R')
+    };
+    // A and B are not transposed when they are 'N' or 'R'
+    let nota = letter_same(trans_a, 'N') || letter_same(trans_a, 'R');
+    let notb = letter_same(trans_b, 'N') || letter_same(trans_b, 'R');
+    let nrowa = if nota { m } else { k };
+    let nrowb = if notb { k } else { n };
+    if !legal(trans_a) {
+        1
+    } else if !legal(trans_b) {
+        2
+    } else if m < 0 {
+        3
+    } else if n < 0 {
+        4
+    } else if k < 0 {
+        5
+    } else if lda < max(1, nrowa) {
+        8
+    } else if ldb < max(1, nrowb) {
+        10
+    } else if ldc < max(1, m) {
+        13
+    } else {
+        0
+    }
+}
+
+/// Return a closure reading op(X)(i, j) of a col-major matrix `x`, where op is selected by `trans`
+/// from 'N', 'T', 'C' and 'R'.
+#[inline(always)]
+unsafe fn cz_op_elem<T>(
+    trans: char,
+    x: *const Complex<T>,
+    ldx: BlasInt,
+) -> impl Fn(usize, usize) -> Complex<T>
+where
+    T: Float,
+{
+    let no_trans = letter_same(trans, 'N') || letter_same(trans, 'R');
+    let conj = letter_same(trans, 'C') || letter_same(trans, 'R');
+    move |i: usize, j: usize| {
+        let xij = if no_trans {
+            *x.add(col_major_index(i, j, ldx))
+        } else {
+            *x.add(col_major_index(j, i, ldx))
+        };
+        if conj {
+            xij.conj()
+        } else {
+            xij
+        }
+    }
+}
+
+/// Form C := alpha * A * B + beta * C if `left`, or C := alpha * B * A + beta * C otherwise,
+/// where A is a symmetric or hermitian matrix and C is an m by n matrix.
+///
+/// `elem(i, j)` reads the element A(i, j) of the whole matrix, and it is shared by symm and hemm,
+/// which only differ in how the unreferenced triangle is recovered from the referenced one.
+#[inline(always)]
+unsafe fn a_symm<T, F>(
+    left: bool,
+    m: usize,
+    n: usize,
+    alpha: T,
+    elem: F,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+) where
+    T: GemmScalar,
+    F: Fn(usize, usize) -> T,
+{
+    let b_elem = |i: usize, j: usize| *b.add(col_major_index(i, j, ldb));
+    // First form C := beta * C, and then C += alpha * A * B or C += alpha * B * A by the engine.
+    engine::scale(None, m, n, beta, c, ldc as usize);
+    if left {
+        engine::gemm(m, n, m, alpha, elem, b_elem, c, ldc as usize);
+    } else {
+        engine::gemm(m, n, n, alpha, b_elem, elem, c, ldc as usize);
+    }
+}
+
+/// Check the arguments of symm and hemm, and return the number of the first illegal argument, or
+/// 0 if all of them are legal.
+#[inline(always)]
+fn a_symm_check(
+    side: char,
+    uplo: char,
+    m: BlasInt,
+    n: BlasInt,
+    lda: BlasInt,
+    ldb: BlasInt,
+    ldc: BlasInt,
+) -> BlasInt {
+    let nrowa = if letter_same(side, 'L') { m } else { n };
+    if !letter_same(side, 'L') && !letter_same(side, 'R') {
+        1
+    } else if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        2
+    } else if m < 0 {
+        3
+    } else if n < 0 {
+        4
+    } else if lda < max(1, nrowa) {
+        7
+    } else if ldb < max(1, m) {
+        9
+    } else if ldc < max(1, m) {
+        12
+    } else {
+        0
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_symm<T>(
+    side: char,
+    uplo: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    let info = a_symm_check(side, uplo, m, n, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    // only the `uplo` triangle of A is referenced
+    let elem = |i: usize, j: usize| {
+        if i == j || (i < j) == upper {
+            *a.add(col_major_index(i, j, lda))
+        } else {
+            *a.add(col_major_index(j, i, lda))
+        }
+    };
+    let left = letter_same(side, 'L');
+    a_symm(
+        left, m as usize, n as usize, alpha, elem, b, ldb, beta, c, ldc,
+    );
+}
+
+/// Complex symmetric matrix matrix multiply, where $\boldsymbol{A}=\boldsymbol{A}^T$ without any
+/// conjugation.
+#[inline(always)]
+pub unsafe fn cz_symm<T>(
+    side: char,
+    uplo: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let info = a_symm_check(side, uplo, m, n, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    // only the `uplo` triangle of A is referenced
+    let elem = |i: usize, j: usize| {
+        if i == j || (i < j) == upper {
+            *a.add(col_major_index(i, j, lda))
+        } else {
+            *a.add(col_major_index(j, i, lda))
+        }
+    };
+    let left = letter_same(side, 'L');
+    a_symm(
+        left, m as usize, n as usize, alpha, elem, b, ldb, beta, c, ldc,
+    );
+}
+
+/// Hermitian matrix matrix multiply. Only the `uplo` triangle of `a` is referenced and the
+/// imaginary parts of the diagonal are assumed to be zero.
+#[inline(always)]
+pub unsafe fn cz_hemm<T>(
+    side: char,
+    uplo: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let zero: T = From::from(0);
+    let info = a_symm_check(side, uplo, m, n, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return;
+    }
+
+    let upper = letter_same(uplo, 'U');
+    // only the `uplo` triangle of A is referenced, and the other one is its conjugate
+    let elem = |i: usize, j: usize| {
+        if i == j {
+            Complex::new((*a.add(col_major_index(j, j, lda))).re, zero)
+        } else if (i < j) == upper {
+            *a.add(col_major_index(i, j, lda))
+        } else {
+            (*a.add(col_major_index(j, i, lda))).conj()
+        }
+    };
+    let left = letter_same(side, 'L');
+    a_symm(
+        left, m as usize, n as usize, alpha, elem, b, ldb, beta, c, ldc,
+    );
+}
+
+/// Form C := alpha * A * B + beta * C, where A is an n by k matrix read by `a(i, l)`, B is a k by
+/// n matrix read by `b(l, j)`, and only the `upper` or lower triangle of the n by n matrix C is
+/// referenced and updated. C is not read when beta is zero.
+///
+/// The routine is shared by gemmt, syrk, herk, syr2k and her2k, and the rank-2k updates call it
+/// twice, the second time with beta one.
+#[inline(always)]
+unsafe fn a_rank_k<T, FA, FB>(
+    upper: bool,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: FA,
+    b: FB,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+) where
+    T: GemmScalar,
+    FA: Fn(usize, usize) -> T,
+    FB: Fn(usize, usize) -> T,
+{
+    engine::scale(Some(upper), n, n, beta, c, ldc as usize);
+    engine::gemm_tri(upper, n, k, alpha, a, b, c, ldc as usize);
+}
+
+/// Set the imaginary parts of the diagonal of the n by n matrix C to zero, as herk and her2k
+/// require.
+#[inline(always)]
+unsafe fn a_real_diag<T>(n: usize, c: *mut Complex<T>, ldc: BlasInt)
+where
+    T: Float,
+{
+    for j in 0..n {
+        (*c.add(col_major_index(j, j, ldc))).im = T::zero();
+    }
+}
+
+/// Check the arguments of syrk, herk, syr2k and her2k, and return the number of the first illegal
+/// argument, or 0 if all of them are legal. `ldb` is only checked when `rank_2k` is true, and
+/// `trans_legal` tells if `trans` is one of the legal values of the routine.
+#[inline(always)]
+fn a_rank_k_check(
+    rank_2k: bool,
+    uplo: char,
+    trans: char,
+    trans_legal: bool,
+    n: BlasInt,
+    k: BlasInt,
+    lda: BlasInt,
+    ldb: BlasInt,
+    ldc: BlasInt,
+) -> BlasInt {
+    let nrowa = if letter_same(trans, 'N') { n } else { k };
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        1
+    } else if !trans_legal {
+        2
+    } else if n < 0 {
+        3
+    } else if k < 0 {
+        4
+    } else if lda < max(1, nrowa) {
+        7
+    } else if rank_2k && ldb < max(1, nrowa) {
+        9
+    } else if ldc < max(1, n) {
+        if rank_2k {
+            12
+        } else {
+            10
+        }
+    } else {
+        0
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_syrk<T>(
+    uplo: char,
+    trans: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // 'C' is the same as 'T' for real matrices
+    let no_trans = letter_same(trans, 'N');
+    let trans_legal = no_trans || letter_same(trans, 'T') || letter_same(trans, 'C');
+    let info = a_rank_k_check(false, uplo, trans, trans_legal, n, k, lda, 1, ldc);
+    if info != 0 {
+        xerbla!(false, info + 1, rout);
+    }
+
+    // quick return if possible
+    if n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+
+    // op(A) is A if `no_trans`, or A^T otherwise, and C := alpha * op(A) * op(A)^T + beta * C
+    let a_op = |i: usize, l: usize| {
+        if no_trans {
+            *a.add(col_major_index(i, l, lda))
+        } else {
+            *a.add(col_major_index(l, i, lda))
+        }
+    };
+    let upper = letter_same(uplo, 'U');
+    a_rank_k(
+        upper,
+        n as usize,
+        k as usize,
+        alpha,
+        a_op,
+        |l: usize, j: usize| a_op(j, l),
+        beta,
+        c,
+        ldc,
+    );
+}
+
+/// Complex symmetric rank-k update, C := alpha * op(A) * op(A)^T + beta * C without any
+/// conjugation. `trans` is either 'N' or 'T'.
+#[inline(always)]
+pub unsafe fn cz_syrk<T>(
+    uplo: char,
+    trans: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let trans_legal = letter_same(trans, 'N') || letter_same(trans, 'T');
+    let info = a_rank_k_check(false, uplo, trans, trans_legal, n, k, lda, 1, ldc);
+    if info != 0 {
+        xerbla!(false, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return;
+    }
+
+    let a_op = cz_op_elem(trans, a, lda);
+    let upper = letter_same(uplo, 'U');
+    a_rank_k(
+        upper,
+        n as usize,
+        k as usize,
+        alpha,
+        &a_op,
+        |l: usize, j: usize| a_op(j, l),
+        beta,
+        c,
+        ldc,
+    );
+}
+
+/// Hermitian rank-k update, C := alpha * op(A) * op(A)^H + beta * C with real `alpha` and `beta`.
+/// `trans` is either 'N' or 'C', and the imaginary parts of the diagonal are set to zero.
+#[inline(always)]
+pub unsafe fn cz_herk<T>(
+    uplo: char,
+    trans: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: T,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    beta: T,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    let trans_legal = letter_same(trans, 'N') || letter_same(trans, 'C');
+    let info = a_rank_k_check(false, uplo, trans, trans_legal, n, k, lda, 1, ldc);
+    if info != 0 {
+        xerbla!(false, info + 1, rout);
+    }
+
+    // quick return if possible
+    if n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+
+    let a_op = cz_op_elem(trans, a, lda);
+    let upper = letter_same(uplo, 'U');
+    a_rank_k(
+        upper,
+        n as usize,
+        k as usize,
+        Complex::new(alpha, zero),
+        &a_op,
+        |l: usize, j: usize| a_op(j, l).conj(),
+        Complex::new(beta, zero),
+        c,
+        ldc,
+    );
+    a_real_diag(n as usize, c, ldc);
+}
+
+#[inline(always)]
+pub unsafe fn sd_syr2k<T>(
+    uplo: char,
+    trans: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // 'C' is the same as 'T' for real matrices
+    let no_trans = letter_same(trans, 'N');
+    let trans_legal = no_trans || letter_same(trans, 'T') || letter_same(trans, 'C');
+    let info = a_rank_k_check(true, uplo, trans, trans_legal, n, k, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(false, info + 1, rout);
+    }
+
+    // quick return if possible
+    if n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+
+    // C := alpha * op(A) * op(B)^T + alpha * op(B) * op(A)^T + beta * C
+    let op = |x: *const T, ldx: BlasInt| {
+        move |i: usize, l: usize| {
+            if no_trans {
+                *x.add(col_major_index(i, l, ldx))
+            } else {
+                *x.add(col_major_index(l, i, ldx))
+            }
+        }
+    };
+    let (a_op, b_op) = (op(a, lda), op(b, ldb));
+    let upper = letter_same(uplo, 'U');
+    let (n, k) = (n as usize, k as usize);
+    a_rank_k(
+        upper,
+        n,
+        k,
+        alpha,
+        a_op,
+        |l: usize, j: usize| b_op(j, l),
+        beta,
+        c,
+        ldc,
+    );
+    a_rank_k(
+        upper,
+        n,
+        k,
+        alpha,
+        b_op,
+        |l: usize, j: usize| a_op(j, l),
+        one,
+        c,
+        ldc,
+    );
+}
+
+/// Complex symmetric rank-2k update, C := alpha * op(A) * op(B)^T + alpha * op(B) * op(A)^T +
+/// beta * C without any conjugation. `trans` is either 'N' or 'T'.
+#[inline(always)]
+pub unsafe fn cz_syr2k<T>(
+    uplo: char,
+    trans: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let trans_legal = letter_same(trans, 'N') || letter_same(trans, 'T');
+    let info = a_rank_k_check(true, uplo, trans, trans_legal, n, k, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(false, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return;
+    }
+
+    let a_op = cz_op_elem(trans, a, lda);
+    let b_op = cz_op_elem(trans, b, ldb);
+    let upper = letter_same(uplo, 'U');
+    let (n, k) = (n as usize, k as usize);
+    a_rank_k(
+        upper,
+        n,
+        k,
+        alpha,
+        &a_op,
+        |l: usize, j: usize| b_op(j, l),
+        beta,
+        c,
+        ldc,
+    );
+    a_rank_k(
+        upper,
+        n,
+        k,
+        alpha,
+        &b_op,
+        |l: usize, j: usize| a_op(j, l),
+        Complex::one(),
+        c,
+        ldc,
+    );
+}
+
+/// Hermitian rank-2k update, C := alpha * op(A) * op(B)^H + conj(alpha) * op(B) * op(A)^H +
+/// beta * C with a real `beta`. `trans` is either 'N' or 'C', and the imaginary parts of the
+/// diagonal are set to zero.
+#[inline(always)]
+pub unsafe fn cz_her2k<T>(
+    uplo: char,
+    trans: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    let trans_legal = letter_same(trans, 'N') || letter_same(trans, 'C');
+    let info = a_rank_k_check(true, uplo, trans, trans_legal, n, k, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(false, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    // quick return if possible
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta == one) {
+        return;
+    }
+
+    let a_op = cz_op_elem(trans, a, lda);
+    let b_op = cz_op_elem(trans, b, ldb);
+    let upper = letter_same(uplo, 'U');
+    let (n, k) = (n as usize, k as usize);
+    let b_h = |l: usize, j: usize| b_op(j, l).conj();
+    let a_h = |l: usize, j: usize| a_op(j, l).conj();
+    let beta = Complex::new(beta, zero);
+    a_rank_k(upper, n, k, alpha, &a_op, b_h, beta, c, ldc);
+    a_rank_k(
+        upper,
+        n,
+        k,
+        alpha.conj(),
+        &b_op,
+        a_h,
+        Complex::one(),
+        c,
+        ldc,
+    );
+    a_real_diag(n, c, ldc);
+}
+
+/// The order of the diagonal blocks of trmm and trsm. The blocks off the diagonal are handed to
+/// the engine, and the diagonal blocks of trsm are solved by the level 2 routine.
+const TRI_BLOCK: usize = 64;
+
+/// Form B := alpha * op(A) * B if `left`, or B := alpha * B * op(A) otherwise, where A is a
+/// triangular matrix and B is an m by n matrix. If `solve` is true, B is overwritten by the
+/// solution X of op(A) * X = alpha * B or X * op(A) = alpha * B instead.
+///
+/// `elem` has the same meaning as in `a_tri_sv` of level 2. The product is formed out of place
+/// from a copy of B by `a_trmm_oop`. The solution is formed block by block: the solved blocks of
+/// X are first subtracted from the next block of B by the engine, and then the diagonal block of
+/// op(A) is solved for each column of B on the left, or for each row of B on the right, where
+/// each row of X is a vector for op(A)^T, so the level 2 routine is applied with `no_trans`
+/// flipped.
+#[inline(always)]
+unsafe fn a_trmm<T, F>(
+    solve: bool,
+    left: bool,
+    upper: bool,
+    no_trans: bool,
+    non_unit: bool,
+    m: usize,
+    n: usize,
+    alpha: T,
+    elem: F,
+    b: *mut T,
+    ldb: BlasInt,
+) where
+    T: GemmScalar + Sub<Output = T> + Div<Output = T>,
+    F: Fn(usize, usize) -> T,
+{
+    if !solve {
+        let mut work = Vec::with_capacity(m * n);
+        for j in 0..n {
+            for i in 0..m {
+                work.push(*b.add(col_major_index(i, j, ldb)));
+            }
+        }
+        a_trmm_oop(
+            left,
+            upper,
+            no_trans,
+            non_unit,
+            m,
+            n,
+            alpha,
+            elem,
+            work.as_ptr(),
+            m as BlasInt,
+            T::zero(),
+            b,
+            ldb,
+        );
+        return;
+    }
+
+    // First form B := alpha * B.
+    engine::scale(None, m, n, alpha, b, ldb as usize);
+    if alpha.is_zero() {
+        return;
+    }
+    let minus_one = T::zero() - T::one();
+    let op_a = |i: usize, j: usize| if no_trans { elem(i, j) } else { elem(j, i) };
+    let b_elem = |i: usize, j: usize| *b.add(col_major_index(i, j, ldb));
+    // whether op(A) is upper triangular
+    let op_upper = upper == no_trans;
+    let order = if left { m } else { n };
+    let blocks = (0..order)
+        .step_by(TRI_BLOCK)
+        .map(|start| (start, min(order, start + TRI_BLOCK)));
+    // op(A) * X = B is solved from the bottom if op(A) is upper triangular, and X * op(A) = B is
+    // solved from the left if op(A) is upper triangular
+    let blocks: Vec<(usize, usize)> = if op_upper == left {
+        blocks.rev().collect()
+    } else {
+        blocks.collect()
+    };
+    for (start, end) in blocks {
+        let len = end - start;
+        let diag_elem = |i: usize, j: usize| elem(start + i, start + j);
+        if left {
+            // B(start..end, :) -= op(A)(start..end, solved) * X(solved, :)
+            let (s0, s1) = if op_upper { (end, m) } else { (0, start) };
+            engine::gemm(
+                len,
+                n,
+                s1 - s0,
+                minus_one,
+                |i: usize, l: usize| op_a(start + i, s0 + l),
+                |l: usize, j: usize| b_elem(s0 + l, j),
+                b.add(start),
+                ldb as usize,
+            );
+            for j in 0..n {
+                let x = b.add(col_major_index(start, j, ldb));
+                a_tri_sv(upper, no_trans, non_unit, len, len - 1, diag_elem, x, 1);
+            }
+        } else {
+            // B(:, start..end) -= X(:, solved) * op(A)(solved, start..end)
+            let (s0, s1) = if op_upper { (0, start) } else { (end, n) };
+            engine::gemm(
+                m,
+                len,
+                s1 - s0,
+                minus_one,
+                |i: usize, l: usize| b_elem(i, s0 + l),
+                |l: usize, j: usize| op_a(s0 + l, start + j),
+                b.add(col_major_index(0, start, ldb)),
+                ldb as usize,
+            );
+            for i in 0..m {
+                let x = b.add(col_major_index(i, start, ldb));
+                a_tri_sv(upper, !no_trans, non_unit, len, len - 1, diag_elem, x, ldb);
+            }
+        }
+    }
+}
+
+/// Check the arguments of trmm and trsm, and return the number of the first illegal argument, or
+/// 0 if all of them are legal. `trans_legal` tells if `trans_a` is one of the legal values of the
+/// routine.
+#[inline(always)]
+fn a_trmm_check(
+    side: char,
+    uplo: char,
+    trans_legal: bool,
+    diag: char,
+    m: BlasInt,
+    n: BlasInt,
+    lda: BlasInt,
+    ldb: BlasInt,
+) -> BlasInt {
+    let nrowa = if letter_same(side, 'L') { m } else { n };
+    if !letter_same(side, 'L') && !letter_same(side, 'R') {
+        1
+    } else if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        2
+    } else if !trans_legal {
+        3
+    } else if !letter_same(diag, 'U') && !letter_same(diag, 'N') {
+        4
+    } else if m < 0 {
+        5
+    } else if n < 0 {
+        6
+    } else if lda < max(1, nrowa) {
+        9
+    } else if ldb < max(1, m) {
+        11
+    } else {
+        0
+    }
+}
+
+#[inline(always)]
+pub unsafe fn sd_trmm<T>(
+    side: char,
+    uplo: char,
+    trans_a: char,
+    diag: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    b: *mut T,
+    ldb: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    // 'C' is the same as 'T' for real matrices
+    let no_trans = letter_same(trans_a, 'N');
+    let trans_legal = no_trans || letter_same(trans_a, 'T') || letter_same(trans_a, 'C');
+    let info = a_trmm_check(side, uplo, trans_legal, diag, m, n, lda, ldb);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 {
+        return;
+    }
+
+    let elem = |i: usize, j: usize| *a.add(col_major_index(i, j, lda));
+    a_trmm(
+        false,
+        letter_same(side, 'L'),
+        letter_same(uplo, 'U'),
+        no_trans,
+        letter_same(diag, 'N'),
+        m as usize,
+        n as usize,
+        alpha,
+        elem,
+        b,
+        ldb,
+    );
+}
+
+/// Complex version of trmm. `trans_a` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemm`.
+#[inline(always)]
+pub unsafe fn cz_trmm<T>(
+    side: char,
+    uplo: char,
+    trans_a: char,
+    diag: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *mut Complex<T>,
+    ldb: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let no_trans = letter_same(trans_a, 'N') || letter_same(trans_a, 'R');
+    let conj = letter_same(trans_a, 'C') || letter_same(trans_a, 'R');
+    let trans_legal = no_trans || letter_same(trans_a, 'T') || letter_same(trans_a, 'C');
+    let info = a_trmm_check(side, uplo, trans_legal, diag, m, n, lda, ldb);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 {
+        return;
+    }
+
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_trmm(
+        false,
+        letter_same(side, 'L'),
+        letter_same(uplo, 'U'),
+        no_trans,
+        letter_same(diag, 'N'),
+        m as usize,
+        n as usize,
+        *alpha,
+        elem,
+        b,
+        ldb,
+    );
+}
+
+#[inline(always)]
+pub unsafe fn sd_trsm<T>(
+    side: char,
+    uplo: char,
+    trans_a: char,
+    diag: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    b: *mut T,
+    ldb: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    // 'C' is the same as 'T' for real matrices
+    let no_trans = letter_same(trans_a, 'N');
+    let trans_legal = no_trans || letter_same(trans_a, 'T') || letter_same(trans_a, 'C');
+    let info = a_trmm_check(side, uplo, trans_legal, diag, m, n, lda, ldb);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 {
+        return;
+    }
+
+    let elem = |i: usize, j: usize| *a.add(col_major_index(i, j, lda));
+    a_trmm(
+        true,
+        letter_same(side, 'L'),
+        letter_same(uplo, 'U'),
+        no_trans,
+        letter_same(diag, 'N'),
+        m as usize,
+        n as usize,
+        alpha,
+        elem,
+        b,
+        ldb,
+    );
+}
+
+/// Complex version of trsm. `trans_a` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemm`.
+#[inline(always)]
+pub unsafe fn cz_trsm<T>(
+    side: char,
+    uplo: char,
+    trans_a: char,
+    diag: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *mut Complex<T>,
+    ldb: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let no_trans = letter_same(trans_a, 'N') || letter_same(trans_a, 'R');
+    let conj = letter_same(trans_a, 'C') || letter_same(trans_a, 'R');
+    let trans_legal = no_trans || letter_same(trans_a, 'T') || letter_same(trans_a, 'C');
+    let info = a_trmm_check(side, uplo, trans_legal, diag, m, n, lda, ldb);
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 {
+        return;
+    }
+
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_trmm(
+        true,
+        letter_same(side, 'L'),
+        letter_same(uplo, 'U'),
+        no_trans,
+        letter_same(diag, 'N'),
+        m as usize,
+        n as usize,
+        *alpha,
+        elem,
+        b,
+        ldb,
+    );
+}
+
+/// Check the arguments of gemmt, and return the number of the first illegal argument, or 0 if
+/// all of them are legal. `nota` and `notb` tell if A and B are not transposed.
+#[inline(always)]
+fn a_gemmt_check(
+    uplo: char,
+    trans_a_legal: bool,
+    trans_b_legal: bool,
+    nota: bool,
+    notb: bool,
+    n: BlasInt,
+    k: BlasInt,
+    lda: BlasInt,
+    ldb: BlasInt,
+    ldc: BlasInt,
+) -> BlasInt {
+    let nrowa = if nota { n } else { k };
+    let nrowb = if notb { k } else { n };
+    if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        1
+    } else if !trans_a_legal {
+        2
+    } else if !trans_b_legal {
+        3
+    } else if n < 0 {
+        4
+    } else if k < 0 {
+        5
+    } else if lda < max(1, nrowa) {
+        8
+    } else if ldb < max(1, nrowb) {
+        10
+    } else if ldc < max(1, n) {
+        13
+    } else {
+        0
+    }
+}
+
+/// gemm that only computes the `uplo` triangle of the n by n matrix C,
+/// C := alpha * op(A) * op(B) + beta * C.
+#[inline(always)]
+pub unsafe fn sd_gemmt<T>(
+    uplo: char,
+    trans_a: char,
+    trans_b: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // 'C' is the same as 'T' for real matrices
+    let nota = letter_same(trans_a, 'N');
+    let notb = letter_same(trans_b, 'N');
+    let info = a_gemmt_check(
+        uplo,
+        nota || letter_same(trans_a, 'T') || letter_same(trans_a, 'C'),
+        notb || letter_same(trans_b, 'T') || letter_same(trans_b, 'C'),
+        nota,
+        notb,
+        n,
+        k,
+        lda,
+        ldb,
+        ldc,
+    );
+    if info != 0 {
+        xerbla!(false, info + 1, rout);
+    }
+
+    // quick return if possible
+    if n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+
+    let a_op = |i: usize, l: usize| {
+        if nota {
+            *a.add(col_major_index(i, l, lda))
+        } else {
+            *a.add(col_major_index(l, i, lda))
+        }
+    };
+    let b_op = |l: usize, j: usize| {
+        if notb {
+            *b.add(col_major_index(l, j, ldb))
+        } else {
+            *b.add(col_major_index(j, l, ldb))
+        }
+    };
+    let upper = letter_same(uplo, 'U');
+    a_rank_k(
+        upper, n as usize, k as usize, alpha, a_op, b_op, beta, c, ldc,
+    );
+}
+
+/// Complex version of gemmt. `trans_a` and `trans_b` accept 'N', 'T', 'C' and 'R', the same as
+/// `cz_gemm`.
+#[inline(always)]
+pub unsafe fn cz_gemmt<T>(
+    uplo: char,
+    trans_a: char,
+    trans_b: char,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let legal = |trans: char| {
+        letter_same(trans, 'N')
+            || letter_same(trans, 'T')
+            || letter_same(trans, 'C')
+            || letter_same(trans, 'R')
+    };
+    let info = a_gemmt_check(
+        uplo,
+        legal(trans_a),
+        legal(trans_b),
+        letter_same(trans_a, 'N') || letter_same(trans_a, 'R'),
+        letter_same(trans_b, 'N') || letter_same(trans_b, 'R'),
+        n,
+        k,
+        lda,
+        ldb,
+        ldc,
+    );
+    if info != 0 {
+        xerbla!(false, info + 1, rout);
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return;
+    }
+
+    let a_op = cz_op_elem(trans_a, a, lda);
+    let b_op = cz_op_elem(trans_b, b, ldb);
+    let upper = letter_same(uplo, 'U');
+    a_rank_k(
+        upper, n as usize, k as usize, alpha, a_op, b_op, beta, c, ldc,
+    );
+}
+
+/// Run gemm on `group_size` matrices sharing the same arguments, the i-th product reads
+/// `*a_array.add(i)` and `*b_array.add(i)` and updates `*c_array.add(i)`. The arguments of every
+/// product are checked by `sd_gemm` itself.
+#[inline(always)]
+pub unsafe fn sd_gemm_group<T>(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: T,
+    a_array: *const *const T,
+    lda: BlasInt,
+    b_array: *const *const T,
+    ldb: BlasInt,
+    beta: T,
+    c_array: *const *mut T,
+    ldc: BlasInt,
+    group_size: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    if group_size < 0 {
+        xerbla!(row_major, 16, rout);
+    }
+    for i in 0..group_size as usize {
+        sd_gemm(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            *a_array.add(i),
+            lda,
+            *b_array.add(i),
+            ldb,
+            beta,
+            *c_array.add(i),
+            ldc,
+            row_major,
+            rout,
+        );
+    }
+}
+
+/// Complex version of `sd_gemm_group`.
+#[inline(always)]
+pub unsafe fn cz_gemm_group<T>(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a_array: *const *const Complex<T>,
+    lda: BlasInt,
+    b_array: *const *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c_array: *const *mut Complex<T>,
+    ldc: BlasInt,
+    group_size: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    if group_size < 0 {
+        xerbla!(row_major, 16, rout);
+    }
+    for i in 0..group_size as usize {
+        cz_gemm(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            *a_array.add(i),
+            lda,
+            *b_array.add(i),
+            ldb,
+            beta,
+            *c_array.add(i),
+            ldc,
+            row_major,
+            rout,
+        );
+    }
+}
+
+/// Run gemm on `batch_size` matrices sharing the same arguments, the i-th product reads
+/// `a.offset(i * stride_a)` and `b.offset(i * stride_b)` and updates `c.offset(i * stride_c)`.
+#[inline(always)]
+pub unsafe fn sd_gemm_batch_strided<T>(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    stride_a: BlasInt,
+    b: *const T,
+    ldb: BlasInt,
+    stride_b: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+    stride_c: BlasInt,
+    batch_size: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    // the arguments shared by the products are checked once, and numbered as in the parameter
+    // list of this routine, where a stride follows each leading dimension
+    let info = match sd_gemm_check(trans_a, trans_b, m, n, k, lda, ldb, ldc) {
+        10 => 11,
+        13 => 15,
+        info => info,
+    };
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    } else if batch_size < 0 {
+        xerbla!(row_major, 18, rout);
+    }
+    for i in 0..batch_size as isize {
+        sd_gemm(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            a.offset(i * stride_a as isize),
+            lda,
+            b.offset(i * stride_b as isize),
+            ldb,
+            beta,
+            c.offset(i * stride_c as isize),
+            ldc,
+            row_major,
+            rout,
+        );
+    }
+}
+
+/// Complex version of `sd_gemm_batch_strided`.
+#[inline(always)]
+pub unsafe fn cz_gemm_batch_strided<T>(
+    trans_a: char,
+    trans_b: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    stride_a: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    stride_b: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+    stride_c: BlasInt,
+    batch_size: BlasInt,
+    row_major: bool,
+    rout: &str,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let info = match cz_gemm_check(trans_a, trans_b, m, n, k, lda, ldb, ldc) {
+        10 => 11,
+        13 => 15,
+        info => info,
+    };
+    if info != 0 {
+        xerbla!(row_major, info + 1, rout);
+    } else if batch_size < 0 {
+        xerbla!(row_major, 18, rout);
+    }
+    for i in 0..batch_size as isize {
+        cz_gemm(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            a.offset(i * stride_a as isize),
+            lda,
+            b.offset(i * stride_b as isize),
+            ldb,
+            beta,
+            c.offset(i * stride_c as isize),
+            ldc,
+            row_major,
+            rout,
+        );
+    }
+}
+
+/// Form C := alpha * op(A) * B + beta * C if `left`, or C := alpha * B * op(A) + beta * C
+/// otherwise, where A is a triangular matrix and B and C are m by n matrices. B is not modified.
+///
+/// `elem` has the same meaning as in `a_trmm`. C is formed by blocks of `TRI_BLOCK` rows on the
+/// left, or cols on the right, and each of them is handed to the engine with only the part of
+/// op(A) which could be non-zero.
+#[inline(always)]
+unsafe fn a_trmm_oop<T, F>(
+    left: bool,
+    upper: bool,
+    no_trans: bool,
+    non_unit: bool,
+    m: usize,
+    n: usize,
+    alpha: T,
+    elem: F,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+) where
+    T: GemmScalar,
+    F: Fn(usize, usize) -> T,
+{
+    // First form C := beta * C.
+    engine::scale(None, m, n, beta, c, ldc as usize);
+    // whether op(A) is upper triangular
+    let op_upper = upper == no_trans;
+    // op(A)(i, j) of the whole matrix, which is zero outside the triangle
+    let op_a = |i: usize, j: usize| {
+        if i == j {
+            if non_unit {
+                elem(i, i)
+            } else {
+                T::one()
+            }
+        } else if (i < j) == op_upper {
+            if no_trans {
+                elem(i, j)
+            } else {
+                elem(j, i)
+            }
+        } else {
+            T::zero()
+        }
+    };
+    let b_elem = |i: usize, j: usize| *b.add(col_major_index(i, j, ldb));
+    if left {
+        for start in (0..m).step_by(TRI_BLOCK) {
+            let end = min(m, start + TRI_BLOCK);
+            // the cols of op(A)(start..end, :) which could be non-zero
+            let (l0, l1) = if op_upper { (start, m) } else { (0, end) };
+            engine::gemm(
+                end - start,
+                n,
+                l1 - l0,
+                alpha,
+                |i: usize, l: usize| op_a(start + i, l0 + l),
+                |l: usize, j: usize| b_elem(l0 + l, j),
+                c.add(start),
+                ldc as usize,
+            );
+        }
+    } else {
+        for start in (0..n).step_by(TRI_BLOCK) {
+            let end = min(n, start + TRI_BLOCK);
+            // the rows of op(A)(:, start..end) which could be non-zero
+            let (l0, l1) = if op_upper { (0, end) } else { (start, n) };
+            engine::gemm(
+                m,
+                end - start,
+                l1 - l0,
+                alpha,
+                |i: usize, l: usize| b_elem(i, l0 + l),
+                |l: usize, j: usize| op_a(l0 + l, start + j),
+                c.add(col_major_index(0, start, ldc)),
+                ldc as usize,
+            );
+        }
+    }
+}
+
+/// Out-of-place trmm, C := alpha * op(A) * B + beta * C or C := alpha * B * op(A) + beta * C,
+/// which is the trmm3 of BLIS.
+#[inline(always)]
+pub unsafe fn sd_trmm_oop<T>(
+    side: char,
+    uplo: char,
+    trans_a: char,
+    diag: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // 'C' is the same as 'T' for real matrices
+    let no_trans = letter_same(trans_a, 'N');
+    let trans_legal = no_trans || letter_same(trans_a, 'T') || letter_same(trans_a, 'C');
+    let mut info = a_trmm_check(side, uplo, trans_legal, diag, m, n, lda, ldb);
+    if info == 0 && ldc < max(1, m) {
+        info = 14;
+    }
+    if info != 0 {
+        xerbla!(false, info, "STRMM_OOP");
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let elem = |i: usize, j: usize| *a.add(col_major_index(i, j, lda));
+    a_trmm_oop(
+        letter_same(side, 'L'),
+        letter_same(uplo, 'U'),
+        no_trans,
+        letter_same(diag, 'N'),
+        m as usize,
+        n as usize,
+        alpha,
+        elem,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    );
+}
+
+/// Complex version of trmm_oop. `trans_a` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemm`.
+#[inline(always)]
+pub unsafe fn cz_trmm_oop<T>(
+    side: char,
+    uplo: char,
+    trans_a: char,
+    diag: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let no_trans = letter_same(trans_a, 'N') || letter_same(trans_a, 'R');
+    let conj = letter_same(trans_a, 'C') || letter_same(trans_a, 'R');
+    let trans_legal = no_trans || letter_same(trans_a, 'T') || letter_same(trans_a, 'C');
+    let mut info = a_trmm_check(side, uplo, trans_legal, diag, m, n, lda, ldb);
+    if info == 0 && ldc < max(1, m) {
+        info = 14;
+    }
+    if info != 0 {
+        xerbla!(false, info, "CTRMM_OOP");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return;
+    }
+
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(i, j, lda));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    a_trmm_oop(
+        letter_same(side, 'L'),
+        letter_same(uplo, 'U'),
+        no_trans,
+        letter_same(diag, 'N'),
+        m as usize,
+        n as usize,
+        alpha,
+        elem,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    );
+}
+
+/// Check the arguments of spmm, and return the number of the first illegal argument, or 0 if all
+/// of them are legal.
+#[inline(always)]
+fn a_spmm_check(
+    side: char,
+    uplo: char,
+    m: BlasInt,
+    n: BlasInt,
+    ldb: BlasInt,
+    ldc: BlasInt,
+) -> BlasInt {
+    if !letter_same(side, 'L') && !letter_same(side, 'R') {
+        1
+    } else if !letter_same(uplo, 'U') && !letter_same(uplo, 'L') {
+        2
+    } else if m < 0 {
+        3
+    } else if n < 0 {
+        4
+    } else if ldb < max(1, m) {
+        8
+    } else if ldc < max(1, m) {
+        11
+    } else {
+        0
+    }
+}
+
+/// Return a closure reading the element A(i, j) of the whole symmetric matrix A of order
+/// `order`, whose `uplo` triangle is packed column by column in `ap`, the same as spmv of level 2.
+#[inline(always)]
+unsafe fn a_packed_sym_elem<T>(
+    upper: bool,
+    order: usize,
+    ap: *const T,
+) -> impl Fn(usize, usize) -> T
+where
+    T: Copy,
+{
+    move |i: usize, j: usize| {
+        // only the `uplo` triangle is stored, so read A(j, i) for the other one
+        let (i, j) = if i == j || (i < j) == upper {
+            (i, j)
+        } else {
+            (j, i)
+        };
+        if upper {
+            *ap.add(i + j * (j + 1) / 2)
+        } else {
+            *ap.add(i + j * (2 * order - j - 1) / 2)
+        }
+    }
+}
+
+/// Symmetric matrix matrix multiply with A supplied in packed form.
+#[inline(always)]
+pub unsafe fn sd_spmm<T>(
+    side: char,
+    uplo: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: T,
+    ap: *const T,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    let info = a_spmm_check(side, uplo, m, n, ldb, ldc);
+    if info != 0 {
+        xerbla!(false, info, "SSPMM");
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha == zero && beta == one) {
+        return;
+    }
+
+    let left = letter_same(side, 'L');
+    let order = if left { m } else { n } as usize;
+    let elem = a_packed_sym_elem(letter_same(uplo, 'U'), order, ap);
+    a_symm(
+        left, m as usize, n as usize, alpha, elem, b, ldb, beta, c, ldc,
+    );
+}
+
+/// Complex symmetric matrix matrix multiply with A supplied in packed form, where
+/// $\boldsymbol{A}=\boldsymbol{A}^T$ without any conjugation.
+#[inline(always)]
+pub unsafe fn cz_spmm<T>(
+    side: char,
+    uplo: char,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex<T>,
+    ap: *const Complex<T>,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let info = a_spmm_check(side, uplo, m, n, ldb, ldc);
+    if info != 0 {
+        xerbla!(false, info, "CSPMM");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return;
+    }
+
+    let left = letter_same(side, 'L');
+    let order = if left { m } else { n } as usize;
+    let elem = a_packed_sym_elem(letter_same(uplo, 'U'), order, ap);
+    a_symm(
+        left, m as usize, n as usize, alpha, elem, b, ldb, beta, c, ldc,
+    );
+}
+
+/// Form C := alpha * op(A) * B + beta * C if `left`, or C := alpha * B * op(A) + beta * C
+/// otherwise, where op(A) is a band matrix with `sub` sub-diagonals and `sup` super-diagonals, and
+/// C is an m by n matrix. `k` is the number of cols of op(A) on the left, and the number of rows
+/// of op(A) on the right.
+///
+/// `op_a(i, j)` reads the element op(A)(i, j), with any transpose and conjugation already applied,
+/// and it is zero outside the band. The inner dimension is split into blocks as wide as the band,
+/// and each of them is handed to the engine with only the rows (or cols) of op(A) it touches.
+#[inline(always)]
+unsafe fn a_gbmm<T, F>(
+    left: bool,
+    m: usize,
+    n: usize,
+    k: usize,
+    sub: usize,
+    sup: usize,
+    alpha: T,
+    op_a: F,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+) where
+    T: GemmScalar,
+    F: Fn(usize, usize) -> T,
+{
+    // First form C := beta * C.
+    engine::scale(None, m, n, beta, c, ldc as usize);
+    let b_elem = |i: usize, j: usize| *b.add(col_major_index(i, j, ldb));
+    let width = sub + sup + 1;
+    for p0 in (0..k).step_by(width) {
+        let p1 = min(k, p0 + width);
+        if left {
+            // the cols p0..p1 of op(A) are non-zero in the rows from p0 - sup to p1 + sub
+            let (r0, r1) = (p0.saturating_sub(sup), min(m, p1 + sub));
+            if r0 >= r1 {
+                break;
+            }
+            engine::gemm(
+                r1 - r0,
+                n,
+                p1 - p0,
+                alpha,
+                |i: usize, l: usize| op_a(r0 + i, p0 + l),
+                |l: usize, j: usize| b_elem(p0 + l, j),
+                c.add(r0),
+                ldc as usize,
+            );
+        } else {
+            // the rows p0..p1 of op(A) are non-zero in the cols from p0 - sub to p1 + sup
+            let (c0, c1) = (p0.saturating_sub(sub), min(n, p1 + sup));
+            if c0 >= c1 {
+                break;
+            }
+            engine::gemm(
+                m,
+                c1 - c0,
+                p1 - p0,
+                alpha,
+                |i: usize, l: usize| b_elem(i, p0 + l),
+                |l: usize, j: usize| op_a(p0 + l, c0 + j),
+                c.add(col_major_index(0, c0, ldc)),
+                ldc as usize,
+            );
+        }
+    }
+}
+
+/// Check the arguments of gbmm, and return the number of the first illegal argument, or 0 if all
+/// of them are legal. `trans_legal` tells if `trans_a` is one of the legal values of the routine.
+#[inline(always)]
+fn a_gbmm_check(
+    side: char,
+    trans_legal: bool,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    kl: BlasInt,
+    ku: BlasInt,
+    lda: BlasInt,
+    ldb: BlasInt,
+    ldc: BlasInt,
+) -> BlasInt {
+    let nrowb = if letter_same(side, 'L') { k } else { m };
+    if !letter_same(side, 'L') && !letter_same(side, 'R') {
+        1
+    } else if !trans_legal {
+        2
+    } else if m < 0 {
+        3
+    } else if n < 0 {
+        4
+    } else if k < 0 {
+        5
+    } else if kl < 0 {
+        6
+    } else if ku < 0 {
+        7
+    } else if lda < kl + ku + 1 {
+        10
+    } else if ldb < max(1, nrowb) {
+        12
+    } else if ldc < max(1, m) {
+        15
+    } else {
+        0
+    }
+}
+
+/// The number of rows and cols of the band matrix A of gbmm.
+#[inline(always)]
+fn a_gbmm_shape(left: bool, no_trans: bool, m: usize, n: usize, k: usize) -> (usize, usize) {
+    // op(A) is m by k on the left, and k by n on the right
+    let (rows, cols) = if left { (m, k) } else { (k, n) };
+    if no_trans {
+        (rows, cols)
+    } else {
+        (cols, rows)
+    }
+}
+
+/// General band matrix matrix multiply. A is stored in the band storage of gbmv, which has `kl`
+/// sub-diagonals and `ku` super-diagonals.
+#[inline(always)]
+pub unsafe fn sd_gbmm<T>(
+    side: char,
+    trans_a: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    kl: BlasInt,
+    ku: BlasInt,
+    alpha: T,
+    a: *const T,
+    lda: BlasInt,
+    b: *const T,
+    ldb: BlasInt,
+    beta: T,
+    c: *mut T,
+    ldc: BlasInt,
+) where
+    T: Float + From<i8> + GemmScalar,
+{
+    let zero: T = From::from(0);
+    let one: T = From::from(1);
+    // 'C' is the same as 'T' for real matrices
+    let no_trans = letter_same(trans_a, 'N');
+    let trans_legal = no_trans || letter_same(trans_a, 'T') || letter_same(trans_a, 'C');
+    let info = a_gbmm_check(side, trans_legal, m, n, k, kl, ku, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(false, info, "SGBMM");
+    }
+
+    // quick return if possible
+    if m == 0 || n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+
+    let left = letter_same(side, 'L');
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (kl, ku) = (kl as usize, ku as usize);
+    // A(i, j) is stored in a(ku + i - j, j)
+    let elem = |i: usize, j: usize| *a.add(col_major_index(ku + i - j, j, lda));
+    let (rows, cols) = a_gbmm_shape(left, no_trans, m, n, k);
+    let in_band = |i: usize, j: usize| i < rows && j < cols && i + ku >= j && i <= j + kl;
+    let op_a = |i: usize, j: usize| {
+        let (i, j) = if no_trans { (i, j) } else { (j, i) };
+        if in_band(i, j) {
+            elem(i, j)
+        } else {
+            zero
+        }
+    };
+    let (sub, sup) = if no_trans { (kl, ku) } else { (ku, kl) };
+    a_gbmm(left, m, n, k, sub, sup, alpha, op_a, b, ldb, beta, c, ldc);
+}
+
+/// Complex version of gbmm. `trans_a` accepts 'N', 'T', 'C' and 'R', the same as `cz_gemm`.
+#[inline(always)]
+pub unsafe fn cz_gbmm<T>(
+    side: char,
+    trans_a: char,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    kl: BlasInt,
+    ku: BlasInt,
+    alpha: *const Complex<T>,
+    a: *const Complex<T>,
+    lda: BlasInt,
+    b: *const Complex<T>,
+    ldb: BlasInt,
+    beta: *const Complex<T>,
+    c: *mut Complex<T>,
+    ldc: BlasInt,
+) where
+    T: Float + From<i8>,
+    Complex<T>: GemmScalar,
+{
+    let no_trans = letter_same(trans_a, 'N') || letter_same(trans_a, 'R');
+    let conj = letter_same(trans_a, 'C') || letter_same(trans_a, 'R');
+    let trans_legal = no_trans || letter_same(trans_a, 'T') || letter_same(trans_a, 'C');
+    let info = a_gbmm_check(side, trans_legal, m, n, k, kl, ku, lda, ldb, ldc);
+    if info != 0 {
+        xerbla!(false, info, "CGBMM");
+    }
+
+    let alpha = *alpha;
+    let beta = *beta;
+    // quick return if possible
+    if m == 0 || n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return;
+    }
+
+    let left = letter_same(side, 'L');
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (kl, ku) = (kl as usize, ku as usize);
+    // A(i, j) is stored in a(ku + i - j, j)
+    let elem = |i: usize, j: usize| {
+        let aij = *a.add(col_major_index(ku + i - j, j, lda));
+        if conj {
+            aij.conj()
+        } else {
+            aij
+        }
+    };
+    let (rows, cols) = a_gbmm_shape(left, no_trans, m, n, k);
+    let in_band = |i: usize, j: usize| i < rows && j < cols && i + ku >= j && i <= j + kl;
+    let op_a = |i: usize, j: usize| {
+        let (i, j) = if no_trans { (i, j) } else { (j, i) };
+        if in_band(i, j) {
+            elem(i, j)
+        } else {
+            Complex::zero()
+        }
+    };
+    let (sub, sup) = if no_trans { (kl, ku) } else { (ku, kl) };
+    a_gbmm(left, m, n, k, sub, sup, alpha, op_a, b, ldb, beta, c, ldc);
+}
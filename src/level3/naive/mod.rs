@@ -0,0 +1,10 @@
+mod c;
+mod core;
+mod d;
+mod s;
+mod z;
+
+pub use c::*;
+pub use d::*;
+pub use s::*;
+pub use z::*;
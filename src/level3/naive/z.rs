@@ -0,0 +1,1891 @@
+use super::core;
+use crate::common::{
+    BlasInt, CBlasDiag, CBlasOrder, CBlasSide, CBlasTranspose, CBlasUpLo, Complex64,
+};
+
+/// ZGEMM perform one of the matrix-matrix operations $\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$.
+///
+/// # Description
+/// ZGEMM performs one of the matrix-matrix operations
+/// $$\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$$
+///
+/// where $op(\boldsymbol{X})$ is one of $\boldsymbol{X}$, $\boldsymbol{X}^T$, $\boldsymbol{X}^H$ or $\bar{\boldsymbol{X}}$,
+/// $op(\boldsymbol{A})$ is an m by k matrix, $op(\boldsymbol{B})$ is a k by n matrix and $\boldsymbol{C}$ is an m by n matrix.
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, `b` and `c` are row-major or col-major.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `trans_b`(in) - whether matrix `b` would be transposed or conjugated, that is, the $op(\boldsymbol{B})$
+///
+/// `m`(in) - the number of rows of $op(\boldsymbol{A})$ and $\boldsymbol{C}$.
+///
+/// `n`(in) - the number of cols of $op(\boldsymbol{B})$ and $\boldsymbol{C}$.
+///
+/// `k`(in) - the number of cols of $op(\boldsymbol{A})$ and rows of $op(\boldsymbol{B})$.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - matrix a.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - matrix c. On exit, it is overwritten by the m by n matrix $\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$.
+///
+/// `ldc`(in) - the leading dimension of `c`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zgemm(
+    order: CBlasOrder,
+    trans_a: CBlasTranspose,
+    trans_b: CBlasTranspose,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    let ta = match trans_a {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    let tb = match trans_b {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    if order == CBlasOrder::ColMajor {
+        core::cz_gemm(
+            ta,
+            tb,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            false,
+            "cblas_zgemm",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C is a col-major C^T = op(B)^T * op(A)^T, so A and B are swapped, and
+        // the transposes are kept since a row-major X is a col-major X^T.
+        core::cz_gemm(
+            tb,
+            ta,
+            n,
+            m,
+            k,
+            alpha,
+            b,
+            ldb,
+            a,
+            lda,
+            beta,
+            c,
+            ldc,
+            true,
+            "cblas_zgemm",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zgemm",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZGEMM3M perform the same operation as [`cblas_zgemm`], with the 3M algorithm.
+///
+/// # Description
+/// ZGEMM3M performs one of the matrix-matrix operations
+/// $$\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$$
+///
+/// The product $op(\boldsymbol{A}) * op(\boldsymbol{B})$ is formed by three real matrix multiplications
+/// instead of four,
+/// $$\boldsymbol{P_1} = \boldsymbol{A_r} * \boldsymbol{B_r},\ \boldsymbol{P_2} = \boldsymbol{A_i} * \boldsymbol{B_i},\ \boldsymbol{P_3} = (\boldsymbol{A_r} + \boldsymbol{A_i}) * (\boldsymbol{B_r} + \boldsymbol{B_i})$$
+/// $$op(\boldsymbol{A}) * op(\boldsymbol{B}) = \boldsymbol{P_1} - \boldsymbol{P_2} + i (\boldsymbol{P_3} - \boldsymbol{P_1} - \boldsymbol{P_2})$$
+///
+/// which saves about 25% of the floating point operations, at the cost of slightly less accurate
+/// imaginary parts. The real products are formed block by block of $\boldsymbol{C}$, so besides
+/// the packed panels of the gemm engine, the extra memory is three real blocks of at most 192 by
+/// 3072 elements, whatever the sizes of the matrices are. The arguments are the same as
+/// [`cblas_zgemm`].
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zgemm3m(
+    order: CBlasOrder,
+    trans_a: CBlasTranspose,
+    trans_b: CBlasTranspose,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    let ta = match trans_a {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    let tb = match trans_b {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    if order == CBlasOrder::ColMajor {
+        core::cz_gemm3m(
+            ta,
+            tb,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            false,
+            "cblas_zgemm3m",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // the same as `cblas_zgemm`
+        core::cz_gemm3m(
+            tb,
+            ta,
+            n,
+            m,
+            k,
+            alpha,
+            b,
+            ldb,
+            a,
+            lda,
+            beta,
+            c,
+            ldc,
+            true,
+            "cblas_zgemm3m",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zgemm3m",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZGEMMT perform the matrix-matrix operation $\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$
+/// on one triangle of $\boldsymbol{C}$ (**not included in blas**).
+///
+/// # Description
+/// ZGEMMT performs the matrix-matrix operation
+/// $$\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$$
+///
+/// where $op(\boldsymbol{X})$ is one of $\boldsymbol{X}$, $\boldsymbol{X}^T$, $\boldsymbol{X}^H$ or $\bar{\boldsymbol{X}}$, $op(\boldsymbol{A})$ is an n by k matrix,
+/// $op(\boldsymbol{B})$ is a k by n matrix and $\boldsymbol{C}$ is an n by n matrix.
+/// Only the upper or lower triangular part of $\boldsymbol{C}$ is computed and updated, and the
+/// other strictly triangular part is not referenced. When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, `b` and `c` are row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or lower triangular part of `c` is updated.
+///
+/// `trans_a`(in) - whether matrix `a` would be transposed or conjugated, that is, the $op(\boldsymbol{A})$
+///
+/// `trans_b`(in) - whether matrix `b` would be transposed or conjugated, that is, the $op(\boldsymbol{B})$
+///
+/// `n`(in) - the order of matrix $\boldsymbol{C}$, the number of rows of $op(\boldsymbol{A})$ and cols of $op(\boldsymbol{B})$.
+///
+/// `k`(in) - the number of cols of $op(\boldsymbol{A})$ and rows of $op(\boldsymbol{B})$.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - matrix a.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - matrix c. On exit, the `uplo` triangular part of it is overwritten by the one of
+/// $\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$.
+///
+/// `ldc`(in) - the leading dimension of `c`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zgemmt(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    trans_b: CBlasTranspose,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    let ta = match trans_a {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    let tb = match trans_b {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_gemmt(
+            ul,
+            ta,
+            tb,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            "cblas_zgemmt",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C is a col-major C^T = op(B)^T * op(A)^T with the other triangle, so A and
+        // B are swapped and `uplo` is flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_gemmt(
+            ul,
+            tb,
+            ta,
+            n,
+            k,
+            alpha,
+            b,
+            ldb,
+            a,
+            lda,
+            beta,
+            c,
+            ldc,
+            "cblas_zgemmt",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zgemmt",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZGEMM_BATCH perform groups of the matrix-matrix operations $\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$
+/// (**not included in blas**).
+///
+/// # Description
+/// ZGEMM_BATCH performs `group_count` groups of the matrix-matrix operations
+/// $$\boldsymbol{C_i}=\alpha * op(\boldsymbol{A_i}) * op(\boldsymbol{B_i}) + \beta * \boldsymbol{C_i}$$
+///
+/// where $op(\boldsymbol{X})$ is one of $\boldsymbol{X}$, $\boldsymbol{X}^T$, $\boldsymbol{X}^H$ or $\bar{\boldsymbol{X}}$. All the operations of
+/// the g-th group share `trans_a_array[g]`, `trans_b_array[g]`, `m_array[g]`, `n_array[g]`,
+/// `k_array[g]`, `alpha_array[g]`, `lda_array[g]`, `ldb_array[g]`, `beta_array[g]` and
+/// `ldc_array[g]`, and the group has `group_size[g]` operations. The matrices are taken in order
+/// from `a_array`, `b_array` and `c_array`, so the first group uses the first `group_size[0]`
+/// pointers of them, the second group uses the next `group_size[1]` ones, and so on.
+/// Each operation is the same as [`cblas_zgemm`].
+///
+/// # Arguments
+/// `order`(in) - indicate if all matrices are row-major or col-major.
+///
+/// `trans_a_array`(in) - array of size `group_count`, the $op(\boldsymbol{A})$ of each group.
+///
+/// `trans_b_array`(in) - array of size `group_count`, the $op(\boldsymbol{B})$ of each group.
+///
+/// `m_array`(in) - array of size `group_count`, the number of rows of $op(\boldsymbol{A})$ and $\boldsymbol{C}$ of each group.
+///
+/// `n_array`(in) - array of size `group_count`, the number of cols of $op(\boldsymbol{B})$ and $\boldsymbol{C}$ of each group.
+///
+/// `k_array`(in) - array of size `group_count`, the number of cols of $op(\boldsymbol{A})$ and rows of $op(\boldsymbol{B})$ of each group.
+///
+/// `alpha_array`(in) - array of size `group_count`, the constant scaling factor $\alpha$ of each group.
+///
+/// `a_array`(in) - array of pointers to the matrices a, its size is the sum of `group_size`.
+///
+/// `lda_array`(in) - array of size `group_count`, the leading dimension of a of each group.
+///
+/// `b_array`(in) - array of pointers to the matrices b, its size is the sum of `group_size`.
+///
+/// `ldb_array`(in) - array of size `group_count`, the leading dimension of b of each group.
+///
+/// `beta_array`(in) - array of size `group_count`, the constant scaling factor $\beta$ of each group.
+///
+/// `c_array`(in, out) - array of pointers to the matrices c, its size is the sum of `group_size`.
+///
+/// `ldc_array`(in) - array of size `group_count`, the leading dimension of c of each group.
+///
+/// `group_count`(in) - the number of groups.
+///
+/// `group_size`(in) - array of size `group_count`, the number of operations in each group.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zgemm_batch(
+    order: CBlasOrder,
+    trans_a_array: *const CBlasTranspose,
+    trans_b_array: *const CBlasTranspose,
+    m_array: *const BlasInt,
+    n_array: *const BlasInt,
+    k_array: *const BlasInt,
+    alpha_array: *const Complex64,
+    a_array: *const *const Complex64,
+    lda_array: *const BlasInt,
+    b_array: *const *const Complex64,
+    ldb_array: *const BlasInt,
+    beta_array: *const Complex64,
+    c_array: *const *mut Complex64,
+    ldc_array: *const BlasInt,
+    group_count: BlasInt,
+    group_size: *const BlasInt,
+) {
+    if order != CBlasOrder::ColMajor && order != CBlasOrder::RowMajor {
+        xerbla!(
+            false,
+            1,
+            "cblas_zgemm_batch",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+    if group_count < 0 {
+        xerbla!(
+            false,
+            15,
+            "cblas_zgemm_batch",
+            "Illegal group_count setting, {}\n",
+            group_count
+        );
+    }
+    // the index of the first matrix of the current group in `a_array`, `b_array` and `c_array`
+    let mut first = 0;
+    for g in 0..group_count as usize {
+        let ta = match *trans_a_array.add(g) {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        let tb = match *trans_b_array.add(g) {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            CBlasTranspose::ConjTrans => 'C',
+            CBlasTranspose::ConjNoTrans => 'R',
+        };
+        let (m, n, k) = (*m_array.add(g), *n_array.add(g), *k_array.add(g));
+        let (lda, ldb, ldc) = (*lda_array.add(g), *ldb_array.add(g), *ldc_array.add(g));
+        let size = *group_size.add(g);
+        let (a, b, c) = (a_array.add(first), b_array.add(first), c_array.add(first));
+        if order == CBlasOrder::ColMajor {
+            core::cz_gemm_group(
+                ta,
+                tb,
+                m,
+                n,
+                k,
+                alpha_array.add(g),
+                a,
+                lda,
+                b,
+                ldb,
+                beta_array.add(g),
+                c,
+                ldc,
+                size,
+                false,
+                "cblas_zgemm_batch",
+            );
+        } else {
+            // the same swap as `cblas_zgemm`
+            core::cz_gemm_group(
+                tb,
+                ta,
+                n,
+                m,
+                k,
+                alpha_array.add(g),
+                b,
+                ldb,
+                a,
+                lda,
+                beta_array.add(g),
+                c,
+                ldc,
+                size,
+                true,
+                "cblas_zgemm_batch",
+            );
+        }
+        first += size as usize;
+    }
+}
+
+/// ZGEMM_BATCH_STRIDED perform a batch of the matrix-matrix operations $\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * op(\boldsymbol{B}) + \beta * \boldsymbol{C}$
+/// (**not included in blas**).
+///
+/// # Description
+/// ZGEMM_BATCH_STRIDED performs `batch_size` matrix-matrix operations
+/// $$\boldsymbol{C_i}=\alpha * op(\boldsymbol{A_i}) * op(\boldsymbol{B_i}) + \beta * \boldsymbol{C_i}$$
+///
+/// which share all the arguments of [`cblas_zgemm`], where $\boldsymbol{A_i}$ starts at `a + i * stride_a`,
+/// $\boldsymbol{B_i}$ starts at `b + i * stride_b` and $\boldsymbol{C_i}$ starts at `c + i * stride_c`.
+///
+/// # Arguments
+/// `order`(in) - indicate if all matrices are row-major or col-major.
+///
+/// `trans_a`(in) - the $op(\boldsymbol{A})$ of all operations.
+///
+/// `trans_b`(in) - the $op(\boldsymbol{B})$ of all operations.
+///
+/// `m`(in) - the number of rows of $op(\boldsymbol{A})$ and $\boldsymbol{C}$.
+///
+/// `n`(in) - the number of cols of $op(\boldsymbol{B})$ and $\boldsymbol{C}$.
+///
+/// `k`(in) - the number of cols of $op(\boldsymbol{A})$ and rows of $op(\boldsymbol{B})$.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the first matrix a.
+///
+/// `lda`(in) - the leading dimension of a.
+///
+/// `stride_a`(in) - the distance in elements between two consecutive matrices a.
+///
+/// `b`(in) - the first matrix b.
+///
+/// `ldb`(in) - the leading dimension of b.
+///
+/// `stride_b`(in) - the distance in elements between two consecutive matrices b.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - the first matrix c.
+///
+/// `ldc`(in) - the leading dimension of c.
+///
+/// `stride_c`(in) - the distance in elements between two consecutive matrices c.
+///
+/// `batch_size`(in) - the number of operations.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zgemm_batch_strided(
+    order: CBlasOrder,
+    trans_a: CBlasTranspose,
+    trans_b: CBlasTranspose,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    stride_a: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    stride_b: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+    stride_c: BlasInt,
+    batch_size: BlasInt,
+) {
+    let ta = match trans_a {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    let tb = match trans_b {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    if order == CBlasOrder::ColMajor {
+        core::cz_gemm_batch_strided(
+            ta,
+            tb,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            stride_a,
+            b,
+            ldb,
+            stride_b,
+            beta,
+            c,
+            ldc,
+            stride_c,
+            batch_size,
+            false,
+            "cblas_zgemm_batch_strided",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // the same swap as `cblas_zgemm`
+        core::cz_gemm_batch_strided(
+            tb,
+            ta,
+            n,
+            m,
+            k,
+            alpha,
+            b,
+            ldb,
+            stride_b,
+            a,
+            lda,
+            stride_a,
+            beta,
+            c,
+            ldc,
+            stride_c,
+            batch_size,
+            true,
+            "cblas_zgemm_batch_strided",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zgemm_batch_strided",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZSYMM perform one of the matrix-matrix operations $\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B} + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{B} * \boldsymbol{A} + \beta * \boldsymbol{C}$, where $\boldsymbol{A}$ is a complex symmetric matrix.
+///
+/// # Description
+/// ZSYMM performs one of the matrix-matrix operations
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B} + \beta * \boldsymbol{C}$$
+/// or
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{B} * \boldsymbol{A} + \beta * \boldsymbol{C}$$
+///
+/// where $\boldsymbol{A}$ is a complex symmetric matrix, and $\boldsymbol{B}$ and $\boldsymbol{C}$ are m by n matrices.
+/// Only the triangle of $\boldsymbol{A}$ selected by `uplo` is referenced. $\boldsymbol{A}=\boldsymbol{A}^T$ without any conjugation.
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, `b` and `c` are row-major or col-major.
+///
+/// `side`(in) - whether $\boldsymbol{A}$ appears on the left or on the right of $\boldsymbol{B}$.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `m`(in) - the number of rows of `b` and `c`.
+///
+/// `n`(in) - the number of cols of `b` and `c`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the complex symmetric matrix, m by m if `side` is left, or n by n otherwise.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - matrix c. On exit, it is overwritten by the updated matrix.
+///
+/// `ldc`(in) - the leading dimension of `c`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zsymm(
+    order: CBlasOrder,
+    side: CBlasSide,
+    uplo: CBlasUpLo,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let sd = match side {
+            CBlasSide::Left => 'L',
+            CBlasSide::Right => 'R',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_symm(
+            sd,
+            ul,
+            m,
+            n,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            false,
+            "cblas_zsymm",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C = A * B is a col-major C^T = B^T * A^T, where A^T is stored as the
+        // other triangle, so `side` and `uplo` are flipped and m and n are swapped.
+        let sd = match side {
+            CBlasSide::Left => 'R',
+            CBlasSide::Right => 'L',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_symm(
+            sd,
+            ul,
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            true,
+            "cblas_zsymm",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zsymm",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZHEMM perform one of the matrix-matrix operations $\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B} + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{B} * \boldsymbol{A} + \beta * \boldsymbol{C}$, where $\boldsymbol{A}$ is a hermitian matrix.
+///
+/// # Description
+/// ZHEMM performs one of the matrix-matrix operations
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B} + \beta * \boldsymbol{C}$$
+/// or
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{B} * \boldsymbol{A} + \beta * \boldsymbol{C}$$
+///
+/// where $\boldsymbol{A}$ is a hermitian matrix, and $\boldsymbol{B}$ and $\boldsymbol{C}$ are m by n matrices.
+/// Only the triangle of $\boldsymbol{A}$ selected by `uplo` is referenced. The imaginary parts of the diagonal elements are assumed to be zero.
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, `b` and `c` are row-major or col-major.
+///
+/// `side`(in) - whether $\boldsymbol{A}$ appears on the left or on the right of $\boldsymbol{B}$.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `a` is referenced.
+///
+/// `m`(in) - the number of rows of `b` and `c`.
+///
+/// `n`(in) - the number of cols of `b` and `c`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the hermitian matrix, m by m if `side` is left, or n by n otherwise.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - matrix c. On exit, it is overwritten by the updated matrix.
+///
+/// `ldc`(in) - the leading dimension of `c`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zhemm(
+    order: CBlasOrder,
+    side: CBlasSide,
+    uplo: CBlasUpLo,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let sd = match side {
+            CBlasSide::Left => 'L',
+            CBlasSide::Right => 'R',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_hemm(
+            sd,
+            ul,
+            m,
+            n,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            false,
+            "cblas_zhemm",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C = A * B is a col-major C^T = B^T * A^T, where A^T is stored as the
+        // other triangle, so `side` and `uplo` are flipped and m and n are swapped.
+        let sd = match side {
+            CBlasSide::Left => 'R',
+            CBlasSide::Right => 'L',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_hemm(
+            sd,
+            ul,
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            true,
+            "cblas_zhemm",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zhemm",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZSYRK perform one of the complex symmetric rank-k operations $\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{A}^T + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{A}^T * \boldsymbol{A} + \beta * \boldsymbol{C}$.
+///
+/// # Description
+/// ZSYRK performs one of the complex symmetric rank-k operations
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{A}^T + \beta * \boldsymbol{C}$$
+/// or
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A}^T * \boldsymbol{A} + \beta * \boldsymbol{C}$$
+///
+/// where $\boldsymbol{C}$ is an n by n complex symmetric matrix, and $\boldsymbol{A}$ is an n by k matrix in the first case
+/// and k by n matrix in the second case. Only the triangle of $\boldsymbol{C}$ selected by `uplo` is referenced and updated. $\boldsymbol{C}=\boldsymbol{C}^T$ without any conjugation.
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, and `c` are row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `c` is referenced.
+///
+/// `trans`(in) - `NoTrans` for the first operation, or `Trans` for the second one.
+///
+/// `n`(in) - the order of matrix `c`.
+///
+/// `k`(in) - the number of cols of `a` in the first operation, or the number of rows in the second one.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - matrix a.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - the complex symmetric matrix c. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `ldc`(in) - the leading dimension of `c`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zsyrk(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans: CBlasTranspose,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let tr = match trans {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_zsyrk",
+                    "Illegal Trans setting, {:?}\n",
+                    trans
+                );
+            }
+        };
+        core::cz_syrk(ul, tr, n, k, alpha, a, lda, beta, c, ldc, "cblas_zsyrk");
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C is a col-major C^T with the other triangle, and a row-major A is a
+        // col-major A^T, so both `uplo` and `trans` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let tr = match trans {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_zsyrk",
+                    "Illegal Trans setting, {:?}\n",
+                    trans
+                );
+            }
+        };
+        core::cz_syrk(ul, tr, n, k, alpha, a, lda, beta, c, ldc, "cblas_zsyrk");
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zsyrk",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZHERK perform one of the hermitian rank-k operations $\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{A}^H + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{A}^H * \boldsymbol{A} + \beta * \boldsymbol{C}$.
+///
+/// # Description
+/// ZHERK performs one of the hermitian rank-k operations
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{A}^H + \beta * \boldsymbol{C}$$
+/// or
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A}^H * \boldsymbol{A} + \beta * \boldsymbol{C}$$
+///
+/// where $\boldsymbol{C}$ is an n by n hermitian matrix, and $\boldsymbol{A}$ is an n by k matrix in the first case
+/// and k by n matrix in the second case. Only the triangle of $\boldsymbol{C}$ selected by `uplo` is referenced and updated. The imaginary parts of the diagonal elements are set to zero.
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, and `c` are row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `c` is referenced.
+///
+/// `trans`(in) - `NoTrans` for the first operation, or `ConjTrans` for the second one.
+///
+/// `n`(in) - the order of matrix `c`.
+///
+/// `k`(in) - the number of cols of `a` in the first operation, or the number of rows in the second one.
+///
+/// `alpha`(in) - real constant scaling factor $\alpha$.
+///
+/// `a`(in) - matrix a.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `beta`(in) - real constant scaling factor $\beta$.
+///
+/// `c`(in, out) - the hermitian matrix c. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `ldc`(in) - the leading dimension of `c`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zherk(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans: CBlasTranspose,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: f64,
+    a: *const Complex64,
+    lda: BlasInt,
+    beta: f64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let tr = match trans {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::ConjTrans => 'C',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_zherk",
+                    "Illegal Trans setting, {:?}\n",
+                    trans
+                );
+            }
+        };
+        core::cz_herk(ul, tr, n, k, alpha, a, lda, beta, c, ldc, "cblas_zherk");
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C is a col-major C^T = conj(C) with the other triangle, and a row-major A
+        // is a col-major A^T, so both `uplo` and `trans` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let tr = match trans {
+            CBlasTranspose::NoTrans => 'C',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_zherk",
+                    "Illegal Trans setting, {:?}\n",
+                    trans
+                );
+            }
+        };
+        core::cz_herk(ul, tr, n, k, alpha, a, lda, beta, c, ldc, "cblas_zherk");
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zherk",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZSYR2K perform one of the complex symmetric rank-2k operations $\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B}^T + \alpha * \boldsymbol{B} * \boldsymbol{A}^T + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{A}^T * \boldsymbol{B} + \alpha * \boldsymbol{B}^T * \boldsymbol{A} + \beta * \boldsymbol{C}$.
+///
+/// # Description
+/// ZSYR2K performs one of the complex symmetric rank-2k operations
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B}^T + \alpha * \boldsymbol{B} * \boldsymbol{A}^T + \beta * \boldsymbol{C}$$
+/// or
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A}^T * \boldsymbol{B} + \alpha * \boldsymbol{B}^T * \boldsymbol{A} + \beta * \boldsymbol{C}$$
+///
+/// where $\boldsymbol{C}$ is an n by n complex symmetric matrix, and $\boldsymbol{A}$ and $\boldsymbol{B}$ are n by k matrices in the first case
+/// and k by n matrices in the second case. Only the triangle of $\boldsymbol{C}$ selected by `uplo` is referenced and updated. $\boldsymbol{C}=\boldsymbol{C}^T$ without any conjugation.
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, `b` and `c` are row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `c` is referenced.
+///
+/// `trans`(in) - `NoTrans` for the first operation, or `Trans` for the second one.
+///
+/// `n`(in) - the order of matrix `c`.
+///
+/// `k`(in) - the number of cols of `a` and `b` in the first operation, or the number of rows in the second one.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - matrix a.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - the complex symmetric matrix c. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `ldc`(in) - the leading dimension of `c`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zsyr2k(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans: CBlasTranspose,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let tr = match trans {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::Trans => 'T',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_zsyr2k",
+                    "Illegal Trans setting, {:?}\n",
+                    trans
+                );
+            }
+        };
+        core::cz_syr2k(
+            ul,
+            tr,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            "cblas_zsyr2k",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C is a col-major C^T with the other triangle, and a row-major A is a
+        // col-major A^T, so both `uplo` and `trans` are flipped
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let tr = match trans {
+            CBlasTranspose::NoTrans => 'T',
+            CBlasTranspose::Trans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_zsyr2k",
+                    "Illegal Trans setting, {:?}\n",
+                    trans
+                );
+            }
+        };
+        core::cz_syr2k(
+            ul,
+            tr,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            "cblas_zsyr2k",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zsyr2k",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZHER2K perform one of the hermitian rank-2k operations $\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B}^H + \bar{\alpha} * \boldsymbol{B} * \boldsymbol{A}^H + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{A}^H * \boldsymbol{B} + \bar{\alpha} * \boldsymbol{B}^H * \boldsymbol{A} + \beta * \boldsymbol{C}$.
+///
+/// # Description
+/// ZHER2K performs one of the hermitian rank-2k operations
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B}^H + \bar{\alpha} * \boldsymbol{B} * \boldsymbol{A}^H + \beta * \boldsymbol{C}$$
+/// or
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{A}^H * \boldsymbol{B} + \bar{\alpha} * \boldsymbol{B}^H * \boldsymbol{A} + \beta * \boldsymbol{C}$$
+///
+/// where $\boldsymbol{C}$ is an n by n hermitian matrix, and $\boldsymbol{A}$ and $\boldsymbol{B}$ are n by k matrices in the first case
+/// and k by n matrices in the second case. Only the triangle of $\boldsymbol{C}$ selected by `uplo` is referenced and updated. The imaginary parts of the diagonal elements are set to zero.
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, `b` and `c` are row-major or col-major.
+///
+/// `uplo`(in) - whether the upper or the lower triangle of matrix `c` is referenced.
+///
+/// `trans`(in) - `NoTrans` for the first operation, or `ConjTrans` for the second one.
+///
+/// `n`(in) - the order of matrix `c`.
+///
+/// `k`(in) - the number of cols of `a` and `b` in the first operation, or the number of rows in the second one.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - matrix a.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - real constant scaling factor $\beta$.
+///
+/// `c`(in, out) - the hermitian matrix c. On exit, the selected triangle is overwritten by the updated matrix.
+///
+/// `ldc`(in) - the leading dimension of `c`, at least `max(1, n)`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zher2k(
+    order: CBlasOrder,
+    uplo: CBlasUpLo,
+    trans: CBlasTranspose,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: f64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        let tr = match trans {
+            CBlasTranspose::NoTrans => 'N',
+            CBlasTranspose::ConjTrans => 'C',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_zher2k",
+                    "Illegal Trans setting, {:?}\n",
+                    trans
+                );
+            }
+        };
+        core::cz_her2k(
+            ul,
+            tr,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            "cblas_zher2k",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C is a col-major C^T = conj(C) with the other triangle, and a row-major A
+        // is a col-major A^T, so both `uplo` and `trans` are flipped and alpha is conjugated
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        let tr = match trans {
+            CBlasTranspose::NoTrans => 'C',
+            CBlasTranspose::ConjTrans => 'N',
+            _ => {
+                xerbla!(
+                    false,
+                    3,
+                    "cblas_zher2k",
+                    "Illegal Trans setting, {:?}\n",
+                    trans
+                );
+            }
+        };
+        let alpha = (*alpha).conj();
+        core::cz_her2k(
+            ul,
+            tr,
+            n,
+            k,
+            &alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            "cblas_zher2k",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zher2k",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZTRMM perform one of the matrix-matrix operations $\boldsymbol{B}=\alpha * op(\boldsymbol{A}) * \boldsymbol{B}$
+/// or $\boldsymbol{B}=\alpha * \boldsymbol{B} * op(\boldsymbol{A})$, where $\boldsymbol{A}$ is a triangular matrix.
+///
+/// # Description
+/// ZTRMM performs one of the matrix-matrix operations
+/// $$\boldsymbol{B}=\alpha * op(\boldsymbol{A}) * \boldsymbol{B}$$
+/// or
+/// $$\boldsymbol{B}=\alpha * \boldsymbol{B} * op(\boldsymbol{A})$$
+///
+/// where $\boldsymbol{B}$ is an m by n matrix, $\boldsymbol{A}$ is a unit, or non-unit, upper or lower triangular matrix
+/// and $op(\boldsymbol{A})$ is one of $\boldsymbol{A}$, $\boldsymbol{A}^T$, $\boldsymbol{A}^H$ or $\bar{\boldsymbol{A}}$.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a` and `b` are row-major or col-major.
+///
+/// `side`(in) - whether $op(\boldsymbol{A})$ multiplies `b` from the left or from the right.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - the form of $op(\boldsymbol{A})$.
+///
+/// `diag`(in) - whether matrix `a` is unit triangular.
+///
+/// `m`(in) - the number of rows of `b`.
+///
+/// `n`(in) - the number of cols of `b`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$. When $\alpha$ is zero, `a` is not referenced and `b` need not be set on input.
+///
+/// `a`(in) - the triangular matrix, m by m if `side` is left, or n by n otherwise.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in, out) - matrix b. On exit, it is overwritten by the transformed matrix.
+///
+/// `ldb`(in) - the leading dimension of `b`, at least `max(1, m)` for col-major.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ztrmm(
+    order: CBlasOrder,
+    side: CBlasSide,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *mut Complex64,
+    ldb: BlasInt,
+) {
+    let ta = match trans_a {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let sd = match side {
+            CBlasSide::Left => 'L',
+            CBlasSide::Right => 'R',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_trmm(
+            sd,
+            ul,
+            ta,
+            dg,
+            m,
+            n,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            false,
+            "cblas_ztrmm",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major B is a col-major B^T, and (op(A) * B)^T = B^T * op(A)^T, where op(A)^T is
+        // op applied to the col-major view of A with the other triangle. So `side` and `uplo`
+        // are flipped, m and n are swapped, and `trans_a` is kept.
+        let sd = match side {
+            CBlasSide::Left => 'R',
+            CBlasSide::Right => 'L',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_trmm(
+            sd,
+            ul,
+            ta,
+            dg,
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            true,
+            "cblas_ztrmm",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_ztrmm",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZTRSM solve one of the matrix equations $op(\boldsymbol{A}) * \boldsymbol{X}=\alpha * \boldsymbol{B}$
+/// or $\boldsymbol{X} * op(\boldsymbol{A})=\alpha * \boldsymbol{B}$, where $\boldsymbol{A}$ is a triangular matrix.
+///
+/// # Description
+/// ZTRSM solves one of the matrix equations
+/// $$op(\boldsymbol{A}) * \boldsymbol{X}=\alpha * \boldsymbol{B}$$
+/// or
+/// $$\boldsymbol{X} * op(\boldsymbol{A})=\alpha * \boldsymbol{B}$$
+///
+/// where $\boldsymbol{X}$ and $\boldsymbol{B}$ are m by n matrices, $\boldsymbol{A}$ is a unit, or non-unit, upper or lower
+/// triangular matrix and $op(\boldsymbol{A})$ is one of $\boldsymbol{A}$, $\boldsymbol{A}^T$, $\boldsymbol{A}^H$ or $\bar{\boldsymbol{A}}$.
+///
+/// No test for singularity or near-singularity is included in this routine.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a` and `b` are row-major or col-major.
+///
+/// `side`(in) - whether $op(\boldsymbol{A})$ appears on the left or on the right of $\boldsymbol{X}$.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - the form of $op(\boldsymbol{A})$.
+///
+/// `diag`(in) - whether matrix `a` is unit triangular.
+///
+/// `m`(in) - the number of rows of `b`.
+///
+/// `n`(in) - the number of cols of `b`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$. When $\alpha$ is zero, `a` is not referenced and `b` need not be set on input.
+///
+/// `a`(in) - the triangular matrix, m by m if `side` is left, or n by n otherwise.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in, out) - matrix b. On exit, it is overwritten by the solution matrix $\boldsymbol{X}$.
+///
+/// `ldb`(in) - the leading dimension of `b`, at least `max(1, m)` for col-major.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ztrsm(
+    order: CBlasOrder,
+    side: CBlasSide,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *mut Complex64,
+    ldb: BlasInt,
+) {
+    let ta = match trans_a {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let sd = match side {
+            CBlasSide::Left => 'L',
+            CBlasSide::Right => 'R',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_trsm(
+            sd,
+            ul,
+            ta,
+            dg,
+            m,
+            n,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            false,
+            "cblas_ztrsm",
+        );
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major B is a col-major B^T, and (op(A) * B)^T = B^T * op(A)^T, where op(A)^T is
+        // op applied to the col-major view of A with the other triangle. So `side` and `uplo`
+        // are flipped, m and n are swapped, and `trans_a` is kept.
+        let sd = match side {
+            CBlasSide::Left => 'R',
+            CBlasSide::Right => 'L',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_trsm(
+            sd,
+            ul,
+            ta,
+            dg,
+            n,
+            m,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            true,
+            "cblas_ztrsm",
+        );
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_ztrsm",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZTRMM_OOP perform one of the matrix-matrix operations $\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * \boldsymbol{B} + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{B} * op(\boldsymbol{A}) + \beta * \boldsymbol{C}$, where $\boldsymbol{A}$ is a triangular matrix
+/// (**not included in blas**).
+///
+/// # Description
+/// ZTRMM_OOP is the out-of-place version of [`cblas_ztrmm`], like the trmm3 of BLIS. It performs one of
+/// the matrix-matrix operations
+/// $$\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * \boldsymbol{B} + \beta * \boldsymbol{C}$$
+/// or
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{B} * op(\boldsymbol{A}) + \beta * \boldsymbol{C}$$
+///
+/// where $\boldsymbol{B}$ and $\boldsymbol{C}$ are m by n matrices, $\boldsymbol{A}$ is a unit, or non-unit, upper or lower
+/// triangular matrix and $op(\boldsymbol{A})$ is one of $\boldsymbol{A}$, $\boldsymbol{A}^T$, $\boldsymbol{A}^H$ or $\bar{\boldsymbol{A}}$. `b` is not modified.
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, `b` and `c` are row-major or col-major.
+///
+/// `side`(in) - whether $op(\boldsymbol{A})$ multiplies `b` from the left or from the right.
+///
+/// `uplo`(in) - whether matrix `a` is an upper or lower triangular matrix.
+///
+/// `trans_a`(in) - the form of $op(\boldsymbol{A})$.
+///
+/// `diag`(in) - whether matrix `a` is unit triangular.
+///
+/// `m`(in) - the number of rows of `b` and `c`.
+///
+/// `n`(in) - the number of cols of `b` and `c`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the triangular matrix, m by m if `side` is left, or n by n otherwise.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - matrix c. On exit, it is overwritten by the m by n result.
+///
+/// `ldc`(in) - the leading dimension of `c`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_ztrmm_oop(
+    order: CBlasOrder,
+    side: CBlasSide,
+    uplo: CBlasUpLo,
+    trans_a: CBlasTranspose,
+    diag: CBlasDiag,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    let ta = match trans_a {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    let dg = match diag {
+        CBlasDiag::NonUnit => 'N',
+        CBlasDiag::Unit => 'U',
+    };
+    if order == CBlasOrder::ColMajor {
+        let sd = match side {
+            CBlasSide::Left => 'L',
+            CBlasSide::Right => 'R',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_trmm_oop(sd, ul, ta, dg, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
+    } else if order == CBlasOrder::RowMajor {
+        // the same conversion as `cblas_ztrmm`, `side` and `uplo` are flipped, m and n are
+        // swapped, and `trans_a` is kept
+        let sd = match side {
+            CBlasSide::Left => 'R',
+            CBlasSide::Right => 'L',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_trmm_oop(sd, ul, ta, dg, n, m, alpha, a, lda, b, ldb, beta, c, ldc);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_ztrmm_oop",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZSPMM perform one of the matrix-matrix operations $\boldsymbol{C}=\alpha * \boldsymbol{A} * \boldsymbol{B} + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{B} * \boldsymbol{A} + \beta * \boldsymbol{C}$, where $\boldsymbol{A}$ is a complex symmetric matrix
+/// supplied in packed form (**not included in blas**).
+///
+/// # Description
+/// ZSPMM performs the same operations as [`cblas_zsymm`], but the `uplo` triangle of $\boldsymbol{A}$
+/// is packed in `ap` in the same way as [`cblas_zspmv`](crate::level2::cblas_zspmv).
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `ap`, `b` and `c` are row-major or col-major.
+///
+/// `side`(in) - whether `ap` is on the left or on the right of `b`.
+///
+/// `uplo`(in) - whether the upper or lower triangular part of $\boldsymbol{A}$ is packed in `ap`.
+///
+/// `m`(in) - the number of rows of `b` and `c`.
+///
+/// `n`(in) - the number of cols of `b` and `c`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `ap`(in) - the packed matrix, whose order is m if `side` is left, or n otherwise.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - matrix c. On exit, it is overwritten by the m by n result.
+///
+/// `ldc`(in) - the leading dimension of `c`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zspmm(
+    order: CBlasOrder,
+    side: CBlasSide,
+    uplo: CBlasUpLo,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex64,
+    ap: *const Complex64,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    if order == CBlasOrder::ColMajor {
+        let sd = match side {
+            CBlasSide::Left => 'L',
+            CBlasSide::Right => 'R',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'U',
+            CBlasUpLo::Lower => 'L',
+        };
+        core::cz_spmm(sd, ul, m, n, alpha, ap, b, ldb, beta, c, ldc);
+    } else if order == CBlasOrder::RowMajor {
+        // the same conversion as `cblas_zsymm`, a row-major packed triangle is the col-major
+        // packed other triangle of the symmetric A
+        let sd = match side {
+            CBlasSide::Left => 'R',
+            CBlasSide::Right => 'L',
+        };
+        let ul = match uplo {
+            CBlasUpLo::Upper => 'L',
+            CBlasUpLo::Lower => 'U',
+        };
+        core::cz_spmm(sd, ul, n, m, alpha, ap, b, ldb, beta, c, ldc);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zspmm",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
+
+/// ZGBMM perform one of the matrix-matrix operations $\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * \boldsymbol{B} + \beta * \boldsymbol{C}$
+/// or $\boldsymbol{C}=\alpha * \boldsymbol{B} * op(\boldsymbol{A}) + \beta * \boldsymbol{C}$, where $\boldsymbol{A}$ is a band matrix
+/// (**not included in blas**).
+///
+/// # Description
+/// ZGBMM performs one of the matrix-matrix operations
+/// $$\boldsymbol{C}=\alpha * op(\boldsymbol{A}) * \boldsymbol{B} + \beta * \boldsymbol{C}$$
+/// or
+/// $$\boldsymbol{C}=\alpha * \boldsymbol{B} * op(\boldsymbol{A}) + \beta * \boldsymbol{C}$$
+///
+/// where $op(\boldsymbol{A})$ is one of $\boldsymbol{A}$, $\boldsymbol{A}^T$, $\boldsymbol{A}^H$ or $\bar{\boldsymbol{A}}$, and $\boldsymbol{C}$ is an m by n matrix.
+/// $op(\boldsymbol{A})$ is m by k on the left and $\boldsymbol{B}$ is k by n, or $\boldsymbol{B}$ is m by k and
+/// $op(\boldsymbol{A})$ is k by n on the right. $\boldsymbol{A}$ has `kl` sub-diagonals and `ku` super-diagonals,
+/// and it is stored in the same way as [`cblas_zgbmv`](crate::level2::cblas_zgbmv).
+/// When $\beta$ is zero, `c` need not be set on input.
+///
+/// # Arguments
+/// `order`(in) - indicate if matrices `a`, `b` and `c` are row-major or col-major.
+///
+/// `side`(in) - whether $op(\boldsymbol{A})$ multiplies `b` from the left or from the right.
+///
+/// `trans_a`(in) - the form of $op(\boldsymbol{A})$.
+///
+/// `m`(in) - the number of rows of `c`.
+///
+/// `n`(in) - the number of cols of `c`.
+///
+/// `k`(in) - the number of cols of $op(\boldsymbol{A})$ on the left, or the number of rows of it on the right.
+///
+/// `kl`(in) - the number of sub-diagonals of `a`.
+///
+/// `ku`(in) - the number of super-diagonals of `a`.
+///
+/// `alpha`(in) - pointer to the constant scaling factor $\alpha$.
+///
+/// `a`(in) - the band matrix, whose leading dimension is at least `kl + ku + 1`.
+///
+/// `lda`(in) - the leading dimension of `a`.
+///
+/// `b`(in) - matrix b.
+///
+/// `ldb`(in) - the leading dimension of `b`.
+///
+/// `beta`(in) - pointer to the constant scaling factor $\beta$.
+///
+/// `c`(in, out) - matrix c. On exit, it is overwritten by the m by n result.
+///
+/// `ldc`(in) - the leading dimension of `c`.
+///
+#[no_mangle]
+#[inline(always)]
+pub unsafe extern "C" fn cblas_zgbmm(
+    order: CBlasOrder,
+    side: CBlasSide,
+    trans_a: CBlasTranspose,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    kl: BlasInt,
+    ku: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    let ta = match trans_a {
+        CBlasTranspose::NoTrans => 'N',
+        CBlasTranspose::Trans => 'T',
+        CBlasTranspose::ConjTrans => 'C',
+        CBlasTranspose::ConjNoTrans => 'R',
+    };
+    if order == CBlasOrder::ColMajor {
+        let sd = match side {
+            CBlasSide::Left => 'L',
+            CBlasSide::Right => 'R',
+        };
+        core::cz_gbmm(sd, ta, m, n, k, kl, ku, alpha, a, lda, b, ldb, beta, c, ldc);
+    } else if order == CBlasOrder::RowMajor {
+        // a row-major C is a col-major C^T, and (op(A) * B)^T = B^T * op(A)^T. The row-major band
+        // storage of A is the col-major band storage of A^T, which has `ku` sub-diagonals and `kl`
+        // super-diagonals, so `side` is flipped, m and n are swapped, kl and ku are swapped, and
+        // `trans_a` is kept.
+        let sd = match side {
+            CBlasSide::Left => 'R',
+            CBlasSide::Right => 'L',
+        };
+        core::cz_gbmm(sd, ta, n, m, k, ku, kl, alpha, a, lda, b, ldb, beta, c, ldc);
+    } else {
+        xerbla!(
+            false,
+            1,
+            "cblas_zgbmm",
+            "Illegal layout setting, {:?}\n",
+            order
+        );
+    }
+}
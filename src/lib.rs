@@ -18,8 +18,10 @@
 // #![no_std]
 
 pub mod common;
+pub mod dispatch;
 #[macro_use]
 mod error;
 pub mod level1;
 pub mod level2;
+pub mod level3;
 mod utils;
@@ -19,3 +19,21 @@ pub fn letter_same(l: char, r: char) -> bool {
 pub fn col_major_index(i: usize, j: usize, lda: BlasInt) -> usize {
     i + j * lda as usize
 }
+
+/// compute the offset of the first element of a strided vector.
+///
+/// For a negative increment the vector is traversed backwards, so the first logical element
+/// is stored at the end of the array.
+///
+/// # Arguments
+/// `len` - the number of elements in the vector.
+///
+/// `inc` - increment between two consecutive elements.
+#[inline(always)]
+pub fn vec_start_index(len: usize, inc: BlasInt) -> isize {
+    if inc > 0 || len == 0 {
+        0
+    } else {
+        (len - 1) as isize * (-inc) as isize
+    }
+}
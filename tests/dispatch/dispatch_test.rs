@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod dispatch_test {
+    use roblas::dispatch::*;
+
+    #[test]
+    fn core_type_names() {
+        for &core in CoreType::ALL.iter() {
+            assert_eq!(CoreType::from_name(core.name()), Some(core));
+            assert_eq!(
+                CoreType::from_name(&core.name().to_ascii_uppercase()),
+                Some(core)
+            );
+        }
+        assert_eq!(CoreType::from_name("avx2"), Some(CoreType::Haswell));
+        assert_eq!(CoreType::from_name(" AVX512 "), Some(CoreType::SkylakeX));
+        assert_eq!(CoreType::from_name("zen"), None);
+        assert_eq!(CoreType::from_name(""), None);
+    }
+
+    #[test]
+    fn detected_core_type() {
+        let detected = CoreType::detect();
+        assert!(detected.is_supported());
+        assert!(CoreType::Generic.is_supported());
+        // every older kernel set is supported as well
+        for &core in CoreType::ALL.iter().filter(|&&core| core <= detected) {
+            assert!(core.is_supported());
+        }
+        for &core in CoreType::ALL.iter().filter(|&&core| core > detected) {
+            assert!(!core.is_supported());
+        }
+    }
+
+    #[test]
+    fn chosen_core_type() {
+        // `ROBLAS_CORETYPE` is only read on the first call, so it is checked rather than set here
+        let forced = std::env::var(CORETYPE_ENV)
+            .ok()
+            .and_then(|name| CoreType::from_name(&name))
+            .filter(|core| core.is_supported());
+        assert_eq!(core_type(), forced.unwrap_or_else(CoreType::detect));
+        assert_eq!(core_type(), core_type());
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn gemm_isa() {
+        // the gemm micro-kernels of s, d, c and z, which every routine of level 3 runs on
+        for &core in CoreType::ALL.iter() {
+            let expected = match core {
+                CoreType::Generic => ["generic"; 4],
+                CoreType::Sse2 => ["sse2", "sse2", "generic", "generic"],
+                CoreType::Haswell => ["avx2"; 4],
+                CoreType::SkylakeX => ["avx512"; 4],
+            };
+            assert_eq!(core.gemm_isa(), expected, "{}", core.name());
+        }
+    }
+}
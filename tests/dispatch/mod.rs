@@ -0,0 +1 @@
+mod dispatch_test;
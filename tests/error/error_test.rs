@@ -0,0 +1,623 @@
+#[cfg(test)]
+mod error_test {
+    use roblas::common::{
+        CBlasDiag, CBlasOrder, CBlasSide, CBlasTranspose, CBlasUpLo, Complex32, Complex64,
+    };
+    use roblas::level2::*;
+    use roblas::level3::*;
+    use std::env;
+    use std::process::Command;
+
+    /// set in the child process, where the test makes its illegal call
+    const CHILD: &str = "ROBLAS_XERBLA_CHILD";
+
+    /// Run the test `name` of this module in a child process, which exits in `xerbla!`, and return
+    /// what it wrote to stderr.
+    fn child_stderr(name: &str) -> String {
+        // the names of the tests don't have the name of the crate
+        let path = module_path!().splitn(2, "::").nth(1).unwrap();
+        let output = Command::new(env::current_exe().unwrap())
+            .args(["--exact", &format!("{}::{}", path, name), "--nocapture"])
+            .env(CHILD, "1")
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "no error was reported");
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    }
+
+    fn is_child() -> bool {
+        env::var_os(CHILD).is_some()
+    }
+
+    #[test]
+    fn sgbmv_row_major() {
+        if is_child() {
+            let (a, x, mut y) = (vec![0_f32; 4], vec![0_f32; 2], vec![0_f32; 2]);
+            unsafe {
+                cblas_sgbmv(
+                    CBlasOrder::RowMajor,
+                    CBlasTranspose::NoTrans,
+                    -1,
+                    2,
+                    0,
+                    1,
+                    1.0,
+                    a.as_ptr(),
+                    2,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("sgbmv_row_major");
+        assert!(
+            stderr.contains("Parameter 3 to routine cblas_sgbmv was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn dgbmv_row_major() {
+        if is_child() {
+            let (a, x, mut y) = (vec![0_f64; 4], vec![0_f64; 2], vec![0_f64; 2]);
+            unsafe {
+                cblas_dgbmv(
+                    CBlasOrder::RowMajor,
+                    CBlasTranspose::NoTrans,
+                    2,
+                    2,
+                    0,
+                    1,
+                    1.0,
+                    a.as_ptr(),
+                    1,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("dgbmv_row_major");
+        assert!(
+            stderr.contains("Parameter 9 to routine cblas_dgbmv was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn sgbmv_col_major() {
+        if is_child() {
+            let (a, x, mut y) = (vec![0_f32; 4], vec![0_f32; 2], vec![0_f32; 2]);
+            unsafe {
+                cblas_sgbmv(
+                    CBlasOrder::ColMajor,
+                    CBlasTranspose::NoTrans,
+                    2,
+                    -1,
+                    0,
+                    1,
+                    1.0,
+                    a.as_ptr(),
+                    2,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("sgbmv_col_major");
+        assert!(
+            stderr.contains("Parameter 4 to routine cblas_sgbmv was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn sger_row_major() {
+        if is_child() {
+            let (x, y, mut a) = (vec![0_f32; 2], vec![0_f32; 2], vec![0_f32; 4]);
+            unsafe {
+                cblas_sger(
+                    CBlasOrder::RowMajor,
+                    -1,
+                    2,
+                    1.0,
+                    x.as_ptr(),
+                    1,
+                    y.as_ptr(),
+                    1,
+                    a.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("sger_row_major");
+        assert!(
+            stderr.contains("Parameter 2 to routine cblas_sger was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn cgerc_row_major() {
+        if is_child() {
+            let alpha = Complex32::new(1.0, 0.0);
+            let (x, y) = (
+                vec![Complex32::new(0.0, 0.0); 2],
+                vec![Complex32::new(0.0, 0.0); 2],
+            );
+            let mut a = vec![Complex32::new(0.0, 0.0); 4];
+            unsafe {
+                cblas_cgerc(
+                    CBlasOrder::RowMajor,
+                    2,
+                    2,
+                    &alpha,
+                    x.as_ptr(),
+                    0,
+                    y.as_ptr(),
+                    1,
+                    a.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("cgerc_row_major");
+        assert!(
+            stderr.contains("Parameter 6 to routine cblas_cgerc was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn cher2_row_major() {
+        if is_child() {
+            let alpha = Complex32::new(1.0, 0.0);
+            let (x, y) = (
+                vec![Complex32::new(0.0, 0.0); 2],
+                vec![Complex32::new(0.0, 0.0); 2],
+            );
+            let mut a = vec![Complex32::new(0.0, 0.0); 4];
+            unsafe {
+                cblas_cher2(
+                    CBlasOrder::RowMajor,
+                    CBlasUpLo::Upper,
+                    2,
+                    &alpha,
+                    x.as_ptr(),
+                    0,
+                    y.as_ptr(),
+                    1,
+                    a.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("cher2_row_major");
+        assert!(
+            stderr.contains("Parameter 6 to routine cblas_cher2 was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn zhpr2_row_major() {
+        if is_child() {
+            let alpha = Complex64::new(1.0, 0.0);
+            let (x, y) = (
+                vec![Complex64::new(0.0, 0.0); 2],
+                vec![Complex64::new(0.0, 0.0); 2],
+            );
+            let mut ap = vec![Complex64::new(0.0, 0.0); 3];
+            unsafe {
+                cblas_zhpr2(
+                    CBlasOrder::RowMajor,
+                    CBlasUpLo::Lower,
+                    2,
+                    &alpha,
+                    x.as_ptr(),
+                    1,
+                    y.as_ptr(),
+                    0,
+                    ap.as_mut_ptr(),
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("zhpr2_row_major");
+        assert!(
+            stderr.contains("Parameter 8 to routine cblas_zhpr2 was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn dgemm_col_major() {
+        if is_child() {
+            let (a, b, mut c) = (vec![0_f64; 4], vec![0_f64; 4], vec![0_f64; 4]);
+            unsafe {
+                cblas_dgemm(
+                    CBlasOrder::ColMajor,
+                    CBlasTranspose::NoTrans,
+                    CBlasTranspose::NoTrans,
+                    2,
+                    2,
+                    2,
+                    1.0,
+                    a.as_ptr(),
+                    1,
+                    b.as_ptr(),
+                    2,
+                    0.0,
+                    c.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("dgemm_col_major");
+        assert!(
+            stderr.contains("Parameter 9 to routine cblas_dgemm was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn zgemm_col_major() {
+        if is_child() {
+            let (alpha, beta) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0));
+            let (a, b) = (
+                vec![Complex64::new(0.0, 0.0); 4],
+                vec![Complex64::new(0.0, 0.0); 4],
+            );
+            let mut c = vec![Complex64::new(0.0, 0.0); 4];
+            unsafe {
+                cblas_zgemm(
+                    CBlasOrder::ColMajor,
+                    CBlasTranspose::NoTrans,
+                    CBlasTranspose::NoTrans,
+                    2,
+                    2,
+                    2,
+                    &alpha,
+                    a.as_ptr(),
+                    2,
+                    b.as_ptr(),
+                    2,
+                    &beta,
+                    c.as_mut_ptr(),
+                    1,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("zgemm_col_major");
+        assert!(
+            stderr.contains("Parameter 14 to routine cblas_zgemm was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn dsyrk_col_major() {
+        if is_child() {
+            let (a, mut c) = (vec![0_f64; 4], vec![0_f64; 4]);
+            unsafe {
+                cblas_dsyrk(
+                    CBlasOrder::ColMajor,
+                    CBlasUpLo::Upper,
+                    CBlasTranspose::NoTrans,
+                    -1,
+                    2,
+                    1.0,
+                    a.as_ptr(),
+                    2,
+                    0.0,
+                    c.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("dsyrk_col_major");
+        assert!(
+            stderr.contains("Parameter 4 to routine cblas_dsyrk was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn dgemm_batch_strided_col_major() {
+        if is_child() {
+            let (a, b, mut c) = (vec![0_f64; 8], vec![0_f64; 8], vec![0_f64; 8]);
+            unsafe {
+                cblas_dgemm_batch_strided(
+                    CBlasOrder::ColMajor,
+                    CBlasTranspose::NoTrans,
+                    CBlasTranspose::NoTrans,
+                    2,
+                    2,
+                    2,
+                    1.0,
+                    a.as_ptr(),
+                    2,
+                    4,
+                    b.as_ptr(),
+                    2,
+                    4,
+                    0.0,
+                    c.as_mut_ptr(),
+                    1,
+                    4,
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("dgemm_batch_strided_col_major");
+        assert!(
+            stderr.contains("Parameter 16 to routine cblas_dgemm_batch_strided was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn sgemm_row_major() {
+        if is_child() {
+            let (a, b, mut c) = (vec![0_f32; 6], vec![0_f32; 6], vec![0_f32; 4]);
+            unsafe {
+                // B is 3 by 2, so ldb is too small
+                cblas_sgemm(
+                    CBlasOrder::RowMajor,
+                    CBlasTranspose::NoTrans,
+                    CBlasTranspose::NoTrans,
+                    2,
+                    2,
+                    3,
+                    1.0,
+                    a.as_ptr(),
+                    3,
+                    b.as_ptr(),
+                    1,
+                    0.0,
+                    c.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("sgemm_row_major");
+        assert!(
+            stderr.contains("Parameter 11 to routine cblas_sgemm was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn zgemm_row_major() {
+        if is_child() {
+            let (alpha, beta) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0));
+            let (a, b) = (
+                vec![Complex64::new(0.0, 0.0); 4],
+                vec![Complex64::new(0.0, 0.0); 4],
+            );
+            let mut c = vec![Complex64::new(0.0, 0.0); 4];
+            unsafe {
+                cblas_zgemm(
+                    CBlasOrder::RowMajor,
+                    CBlasTranspose::NoTrans,
+                    CBlasTranspose::NoTrans,
+                    -1,
+                    2,
+                    2,
+                    &alpha,
+                    a.as_ptr(),
+                    2,
+                    b.as_ptr(),
+                    2,
+                    &beta,
+                    c.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("zgemm_row_major");
+        assert!(
+            stderr.contains("Parameter 4 to routine cblas_zgemm was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn dgemm_batch_strided_row_major() {
+        if is_child() {
+            let (a, b, mut c) = (vec![0_f64; 12], vec![0_f64; 12], vec![0_f64; 8]);
+            unsafe {
+                // A is 2 by 3, so lda is too small
+                cblas_dgemm_batch_strided(
+                    CBlasOrder::RowMajor,
+                    CBlasTranspose::NoTrans,
+                    CBlasTranspose::NoTrans,
+                    2,
+                    2,
+                    3,
+                    1.0,
+                    a.as_ptr(),
+                    2,
+                    6,
+                    b.as_ptr(),
+                    2,
+                    6,
+                    0.0,
+                    c.as_mut_ptr(),
+                    2,
+                    4,
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("dgemm_batch_strided_row_major");
+        assert!(
+            stderr.contains("Parameter 9 to routine cblas_dgemm_batch_strided was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn ssymm_row_major() {
+        if is_child() {
+            let (a, b, mut c) = (vec![0_f32; 4], vec![0_f32; 4], vec![0_f32; 4]);
+            unsafe {
+                cblas_ssymm(
+                    CBlasOrder::RowMajor,
+                    CBlasSide::Left,
+                    CBlasUpLo::Upper,
+                    -1,
+                    2,
+                    1.0,
+                    a.as_ptr(),
+                    2,
+                    b.as_ptr(),
+                    2,
+                    0.0,
+                    c.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("ssymm_row_major");
+        assert!(
+            stderr.contains("Parameter 4 to routine cblas_ssymm was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn chemm_row_major() {
+        if is_child() {
+            let (alpha, beta) = (Complex32::new(1.0, 0.0), Complex32::new(0.0, 0.0));
+            let (a, b) = (
+                vec![Complex32::new(0.0, 0.0); 4],
+                vec![Complex32::new(0.0, 0.0); 4],
+            );
+            let mut c = vec![Complex32::new(0.0, 0.0); 4];
+            unsafe {
+                cblas_chemm(
+                    CBlasOrder::RowMajor,
+                    CBlasSide::Right,
+                    CBlasUpLo::Lower,
+                    2,
+                    -1,
+                    &alpha,
+                    a.as_ptr(),
+                    2,
+                    b.as_ptr(),
+                    2,
+                    &beta,
+                    c.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("chemm_row_major");
+        assert!(
+            stderr.contains("Parameter 5 to routine cblas_chemm was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn strmm_row_major() {
+        if is_child() {
+            let (a, mut b) = (vec![0_f32; 4], vec![0_f32; 4]);
+            unsafe {
+                cblas_strmm(
+                    CBlasOrder::RowMajor,
+                    CBlasSide::Left,
+                    CBlasUpLo::Upper,
+                    CBlasTranspose::NoTrans,
+                    CBlasDiag::NonUnit,
+                    -1,
+                    2,
+                    1.0,
+                    a.as_ptr(),
+                    2,
+                    b.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("strmm_row_major");
+        assert!(
+            stderr.contains("Parameter 6 to routine cblas_strmm was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+
+    #[test]
+    fn ztrsm_row_major() {
+        if is_child() {
+            let alpha = Complex64::new(1.0, 0.0);
+            let a = [Complex64::new(1.0, 0.0); 4];
+            let mut b = vec![Complex64::new(0.0, 0.0); 4];
+            unsafe {
+                cblas_ztrsm(
+                    CBlasOrder::RowMajor,
+                    CBlasSide::Right,
+                    CBlasUpLo::Lower,
+                    CBlasTranspose::ConjTrans,
+                    CBlasDiag::Unit,
+                    2,
+                    -1,
+                    &alpha,
+                    a.as_ptr(),
+                    2,
+                    b.as_mut_ptr(),
+                    2,
+                );
+            }
+            return;
+        }
+        let stderr = child_stderr("ztrsm_row_major");
+        assert!(
+            stderr.contains("Parameter 7 to routine cblas_ztrsm was incorrect"),
+            "{}",
+            stderr
+        );
+    }
+}
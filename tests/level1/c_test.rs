@@ -70,15 +70,20 @@ mod c_test {
 
     #[test]
     fn cdotu() {
+        // the last two elements are only read with an increment of 2
         let v1 = vec![
             Complex32::new(1_f32, 1_f32),
             Complex32::new(1_f32, -1_f32),
             Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
         ];
         let v2 = vec![
             Complex32::new(3_f32, -4_f32),
             Complex32::new(6_f32, -2_f32),
             Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
         ];
         let result1;
         unsafe {
@@ -113,4 +118,75 @@ mod c_test {
         let expect1 = Complex32::new(7_f32, -3_f32);
         assert_eq!(result1, expect1);
     }
+
+    #[test]
+    fn c_unit_stride() {
+        // the unit stride vectors are compared with the same vectors at stride 2, which are
+        // handled by the scalar code, except iamax; the lengths cover the vector and tail loops
+        for &n in &[1_usize, 3, 4, 9, 19, 38] {
+            // integer entries keep every result exact, the maximum appears twice and NaN once
+            let mut x: Vec<Complex32> = (0..n)
+                .map(|i| {
+                    Complex32::new(
+                        ((i * 7 + 3) % 11) as f32 - 5_f32,
+                        ((i * 3 + 1) % 5) as f32 - 2_f32,
+                    )
+                })
+                .collect();
+            let y: Vec<Complex32> = (0..n)
+                .map(|i| {
+                    Complex32::new(
+                        ((i * 5 + 1) % 9) as f32 - 4_f32,
+                        ((i * 2 + 3) % 7) as f32 - 3_f32,
+                    )
+                })
+                .collect();
+            if n > 5 {
+                x[n / 2] = Complex32::new(9_f32, -9_f32);
+                x[n - 1] = Complex32::new(-9_f32, 9_f32);
+                x[n / 3] = Complex32::new(f32::NAN, 0_f32);
+            }
+            let zero = Complex32::new(0_f32, 0_f32);
+            let stride = |v: &[Complex32]| -> Vec<Complex32> {
+                v.iter().flat_map(|&e| vec![e, zero]).collect()
+            };
+            let ys = stride(&y);
+            let ni = n as BlasInt;
+            unsafe {
+                // the first maximum of |re| + |im|, where NaN is skipped
+                let mut expected = 0;
+                for (i, e) in x.iter().enumerate() {
+                    if e.re.abs() + e.im.abs() > x[expected].re.abs() + x[expected].im.abs() {
+                        expected = i;
+                    }
+                }
+                assert_eq!(cblas_icamax(ni, x.as_ptr(), 1), expected);
+                // the rest are compared without NaN
+                if n > 5 {
+                    x[n / 3] = Complex32::new(1_f32, 0_f32);
+                }
+                let xs = stride(&x);
+                assert_eq!(
+                    cblas_cdotu(ni, x.as_ptr(), 1, y.as_ptr(), 1),
+                    cblas_cdotu(ni, xs.as_ptr(), 2, ys.as_ptr(), 2)
+                );
+                assert_eq!(
+                    cblas_cdotc(ni, x.as_ptr(), 1, y.as_ptr(), 1),
+                    cblas_cdotc(ni, xs.as_ptr(), 2, ys.as_ptr(), 2)
+                );
+                assert_eq!(
+                    cblas_scasum(ni, x.as_ptr(), 1),
+                    cblas_scasum(ni, xs.as_ptr(), 2)
+                );
+                let alpha = Complex32::new(-3_f32, 2_f32);
+                let (mut y1, mut y2) = (y.clone(), ys.clone());
+                cblas_caxpy(ni, &alpha, x.as_ptr(), 1, y1.as_mut_ptr(), 1);
+                cblas_caxpy(ni, &alpha, xs.as_ptr(), 2, y2.as_mut_ptr(), 2);
+                assert_eq!(stride(&y1), y2);
+                cblas_cscal(ni, &alpha, y1.as_mut_ptr(), 1);
+                cblas_cscal(ni, &alpha, y2.as_mut_ptr(), 2);
+                assert_eq!(stride(&y1), y2);
+            }
+        }
+    }
 }
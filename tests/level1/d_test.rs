@@ -164,4 +164,64 @@ mod d_test {
         }
         assert_eq!(result, 1);
     }
+
+    #[test]
+    fn d_unit_stride() {
+        // the unit stride vectors are compared with the same vectors at stride 2, which are
+        // handled by the scalar code; the lengths cover the unrolled, vector and tail loops
+        for &n in &[1_usize, 3, 8, 13, 37, 70] {
+            // integer entries keep every result exact, the maximum appears twice and NaN once
+            let mut x: Vec<f64> = (0..n).map(|i| ((i * 7 + 3) % 11) as f64 - 5_f64).collect();
+            let y: Vec<f64> = (0..n).map(|i| ((i * 5 + 1) % 9) as f64 - 4_f64).collect();
+            if n > 5 {
+                x[n / 2] = 9_f64;
+                x[n - 1] = -9_f64;
+                x[n / 3] = f64::NAN;
+            }
+            let stride =
+                |v: &[f64]| -> Vec<f64> { v.iter().flat_map(|&e| vec![e, 0_f64]).collect() };
+            let (xs, ys) = (stride(&x), stride(&y));
+            let ni = n as BlasInt;
+            unsafe {
+                assert_eq!(
+                    cblas_idamax(ni, x.as_ptr(), 1),
+                    cblas_idamax(ni, xs.as_ptr(), 2)
+                );
+                // the rest are compared without NaN
+                if n > 5 {
+                    x[n / 3] = 1_f64;
+                }
+                let xs = stride(&x);
+                assert_eq!(
+                    cblas_ddot(ni, x.as_ptr(), 1, y.as_ptr(), 1),
+                    cblas_ddot(ni, xs.as_ptr(), 2, ys.as_ptr(), 2)
+                );
+                assert_eq!(
+                    cblas_dasum(ni, x.as_ptr(), 1),
+                    cblas_dasum(ni, xs.as_ptr(), 2)
+                );
+                let nrm2 = cblas_dnrm2(ni, x.as_ptr(), 1);
+                let expected = cblas_dnrm2(ni, xs.as_ptr(), 2);
+                assert!((nrm2 - expected).abs() <= expected * 4_f64 * f64::EPSILON);
+                let (mut y1, mut y2) = (y.clone(), ys.clone());
+                cblas_daxpy(ni, -3_f64, x.as_ptr(), 1, y1.as_mut_ptr(), 1);
+                cblas_daxpy(ni, -3_f64, xs.as_ptr(), 2, y2.as_mut_ptr(), 2);
+                assert_eq!(stride(&y1), y2);
+                cblas_dscal(ni, 2_f64, y1.as_mut_ptr(), 1);
+                cblas_dscal(ni, 2_f64, y2.as_mut_ptr(), 2);
+                assert_eq!(stride(&y1), y2);
+            }
+        }
+    }
+
+    #[test]
+    fn dnrm2_scaled() {
+        // the squares of these elements overflow or underflow, but the norm does not
+        for &e in &[1e200_f64, 1e-200_f64] {
+            let x = vec![e; 20];
+            let nrm2 = unsafe { cblas_dnrm2(20, x.as_ptr(), 1) };
+            let expected = e * 20_f64.sqrt();
+            assert!((nrm2 - expected).abs() <= expected * 4_f64 * f64::EPSILON);
+        }
+    }
 }
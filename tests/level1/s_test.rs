@@ -164,4 +164,64 @@ mod s_test {
         }
         assert_eq!(result, 1);
     }
+
+    #[test]
+    fn s_unit_stride() {
+        // the unit stride vectors are compared with the same vectors at stride 2, which are
+        // handled by the scalar code; the lengths cover the unrolled, vector and tail loops
+        for &n in &[1_usize, 3, 8, 13, 37, 70] {
+            // integer entries keep every result exact, the maximum appears twice and NaN once
+            let mut x: Vec<f32> = (0..n).map(|i| ((i * 7 + 3) % 11) as f32 - 5_f32).collect();
+            let y: Vec<f32> = (0..n).map(|i| ((i * 5 + 1) % 9) as f32 - 4_f32).collect();
+            if n > 5 {
+                x[n / 2] = 9_f32;
+                x[n - 1] = -9_f32;
+                x[n / 3] = f32::NAN;
+            }
+            let stride =
+                |v: &[f32]| -> Vec<f32> { v.iter().flat_map(|&e| vec![e, 0_f32]).collect() };
+            let (xs, ys) = (stride(&x), stride(&y));
+            let ni = n as BlasInt;
+            unsafe {
+                assert_eq!(
+                    cblas_isamax(ni, x.as_ptr(), 1),
+                    cblas_isamax(ni, xs.as_ptr(), 2)
+                );
+                // the rest are compared without NaN
+                if n > 5 {
+                    x[n / 3] = 1_f32;
+                }
+                let xs = stride(&x);
+                assert_eq!(
+                    cblas_sdot(ni, x.as_ptr(), 1, y.as_ptr(), 1),
+                    cblas_sdot(ni, xs.as_ptr(), 2, ys.as_ptr(), 2)
+                );
+                assert_eq!(
+                    cblas_sasum(ni, x.as_ptr(), 1),
+                    cblas_sasum(ni, xs.as_ptr(), 2)
+                );
+                let nrm2 = cblas_snrm2(ni, x.as_ptr(), 1);
+                let expected = cblas_snrm2(ni, xs.as_ptr(), 2);
+                assert!((nrm2 - expected).abs() <= expected * 4_f32 * f32::EPSILON);
+                let (mut y1, mut y2) = (y.clone(), ys.clone());
+                cblas_saxpy(ni, -3_f32, x.as_ptr(), 1, y1.as_mut_ptr(), 1);
+                cblas_saxpy(ni, -3_f32, xs.as_ptr(), 2, y2.as_mut_ptr(), 2);
+                assert_eq!(stride(&y1), y2);
+                cblas_sscal(ni, 2_f32, y1.as_mut_ptr(), 1);
+                cblas_sscal(ni, 2_f32, y2.as_mut_ptr(), 2);
+                assert_eq!(stride(&y1), y2);
+            }
+        }
+    }
+
+    #[test]
+    fn snrm2_scaled() {
+        // the squares of these elements overflow or underflow, but the norm does not
+        for &e in &[1e30_f32, 1e-30_f32] {
+            let x = vec![e; 20];
+            let nrm2 = unsafe { cblas_snrm2(20, x.as_ptr(), 1) };
+            let expected = e * 20_f32.sqrt();
+            assert!((nrm2 - expected).abs() <= expected * 4_f32 * f32::EPSILON);
+        }
+    }
 }
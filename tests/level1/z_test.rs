@@ -121,4 +121,75 @@ mod z_test {
         assert_eq!(result2, expect2);
         assert_eq!(result3, expect3);
     }
+
+    #[test]
+    fn z_unit_stride() {
+        // the unit stride vectors are compared with the same vectors at stride 2, which are
+        // handled by the scalar code, except iamax; the lengths cover the vector and tail loops
+        for &n in &[1_usize, 3, 4, 9, 19, 38] {
+            // integer entries keep every result exact, the maximum appears twice and NaN once
+            let mut x: Vec<Complex64> = (0..n)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + 3) % 11) as f64 - 5_f64,
+                        ((i * 3 + 1) % 5) as f64 - 2_f64,
+                    )
+                })
+                .collect();
+            let y: Vec<Complex64> = (0..n)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 5 + 1) % 9) as f64 - 4_f64,
+                        ((i * 2 + 3) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect();
+            if n > 5 {
+                x[n / 2] = Complex64::new(9_f64, -9_f64);
+                x[n - 1] = Complex64::new(-9_f64, 9_f64);
+                x[n / 3] = Complex64::new(f64::NAN, 0_f64);
+            }
+            let zero = Complex64::new(0_f64, 0_f64);
+            let stride = |v: &[Complex64]| -> Vec<Complex64> {
+                v.iter().flat_map(|&e| vec![e, zero]).collect()
+            };
+            let ys = stride(&y);
+            let ni = n as BlasInt;
+            unsafe {
+                // the first maximum of |re| + |im|, where NaN is skipped
+                let mut expected = 0;
+                for (i, e) in x.iter().enumerate() {
+                    if e.re.abs() + e.im.abs() > x[expected].re.abs() + x[expected].im.abs() {
+                        expected = i;
+                    }
+                }
+                assert_eq!(cblas_izamax(ni, x.as_ptr(), 1), expected);
+                // the rest are compared without NaN
+                if n > 5 {
+                    x[n / 3] = Complex64::new(1_f64, 0_f64);
+                }
+                let xs = stride(&x);
+                assert_eq!(
+                    cblas_zdotu(ni, x.as_ptr(), 1, y.as_ptr(), 1),
+                    cblas_zdotu(ni, xs.as_ptr(), 2, ys.as_ptr(), 2)
+                );
+                assert_eq!(
+                    cblas_zdotc(ni, x.as_ptr(), 1, y.as_ptr(), 1),
+                    cblas_zdotc(ni, xs.as_ptr(), 2, ys.as_ptr(), 2)
+                );
+                assert_eq!(
+                    cblas_dzasum(ni, x.as_ptr(), 1),
+                    cblas_dzasum(ni, xs.as_ptr(), 2)
+                );
+                let alpha = Complex64::new(-3_f64, 2_f64);
+                let (mut y1, mut y2) = (y.clone(), ys.clone());
+                cblas_zaxpy(ni, &alpha, x.as_ptr(), 1, y1.as_mut_ptr(), 1);
+                cblas_zaxpy(ni, &alpha, xs.as_ptr(), 2, y2.as_mut_ptr(), 2);
+                assert_eq!(stride(&y1), y2);
+                cblas_zscal(ni, &alpha, y1.as_mut_ptr(), 1);
+                cblas_zscal(ni, &alpha, y2.as_mut_ptr(), 2);
+                assert_eq!(stride(&y1), y2);
+            }
+        }
+    }
 }
@@ -0,0 +1,2308 @@
+#[cfg(test)]
+mod c_test {
+    use roblas::common::{CBlasDiag, CBlasOrder, CBlasTranspose, CBlasUpLo, Complex32};
+    use roblas::level2::*;
+
+    #[test]
+    fn cgemv1() {
+        // col major test
+        //
+        // (1+i) * [1+i 2 3-i ] * [ 1 ] + 2 * [1-i]
+        //         [ i  4 5+2i]   [ i ]       [ 2 ]
+        //                        [2-i]
+        let a = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(4_f32, 0_f32),
+            Complex32::new(3_f32, -1_f32),
+            Complex32::new(5_f32, 2_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![Complex32::new(1_f32, -1_f32), Complex32::new(2_f32, 0_f32)];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_cgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::NoTrans,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(10_f32, 2_f32),
+                Complex32::new(12_f32, 16_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cgemv2() {
+        // row major, conjugate transposed test
+        //
+        // (1+i) * [1+i 2 3-i ]^H * [1+2i] + 2 * [1]
+        //         [ i  4 5+2i]     [ -1 ]       [0]
+        //                                       [i]
+        let a = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(3_f32, -1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(4_f32, 0_f32),
+            Complex32::new(5_f32, 2_f32),
+        ];
+        let x = vec![Complex32::new(1_f32, 2_f32), Complex32::new(-1_f32, 0_f32)];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_cgemv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::ConjTrans,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(3_f32, 5_f32),
+                Complex32::new(-6_f32, 2_f32),
+                Complex32::new(-13_f32, 7_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cgemv3() {
+        // row major, conjugate without transpose test
+        //
+        // (1+i) * conj([1+i 2 3-i ]) * [ 1 ] + 2 * [1-i]
+        //              [ i  4 5+2i]    [ i ]       [ 2 ]
+        //                              [2-i]
+        let a = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(3_f32, -1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(4_f32, 0_f32),
+            Complex32::new(5_f32, 2_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![Complex32::new(1_f32, -1_f32), Complex32::new(2_f32, 0_f32)];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_cgemv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::ConjNoTrans,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![Complex32::new(10_f32, 6_f32), Complex32::new(18_f32, 2_f32)]
+        )
+    }
+
+    #[test]
+    fn cgemv4() {
+        // col major, transposed test with a negative increment of y
+        //
+        // (1+i) * [1+i 2 3-i ]^T * [1+2i] + 2 * [1]
+        //         [ i  4 5+2i]     [ -1 ]       [0]
+        //                                       [i]
+        let a = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(4_f32, 0_f32),
+            Complex32::new(3_f32, -1_f32),
+            Complex32::new(5_f32, 2_f32),
+        ];
+        let x = vec![Complex32::new(1_f32, 2_f32), Complex32::new(-1_f32, 0_f32)];
+        let mut y = vec![
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(1_f32, 0_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_cgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::Trans,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(-3_f32, 5_f32),
+                Complex32::new(-6_f32, 2_f32),
+                Complex32::new(-1_f32, 1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cgbmv1() {
+        // col major conjugate transposed band test, kl = 1, ku = 1
+        //
+        // [1+i  2    0 ]
+        // [3i  4-i   5 ]
+        // [ 0   6  7+2i]
+        let a = vec![
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(4_f32, -1_f32),
+            Complex32::new(6_f32, 0_f32),
+            Complex32::new(5_f32, 0_f32),
+            Complex32::new(7_f32, 2_f32),
+            Complex32::new(0_f32, 0_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(2_f32, -1_f32);
+        let beta = Complex32::new(0_f32, 1_f32);
+        unsafe {
+            cblas_cgbmv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::ConjTrans,
+                3,
+                3,
+                1,
+                1,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(7_f32, -5_f32),
+                Complex32::new(30_f32, -5_f32),
+                Complex32::new(30_f32, -12_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cgbmv2() {
+        // row major conjugate band test, kl = 1, ku = 1
+        //
+        // [1+i  2    0 ]
+        // [3i  4-i   5 ]
+        // [ 0   6  7+2i]
+        let a = vec![
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(4_f32, -1_f32),
+            Complex32::new(5_f32, 0_f32),
+            Complex32::new(6_f32, 0_f32),
+            Complex32::new(7_f32, 2_f32),
+            Complex32::new(0_f32, 0_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(2_f32, -1_f32);
+        let beta = Complex32::new(0_f32, 1_f32);
+        unsafe {
+            cblas_cgbmv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::ConjNoTrans,
+                3,
+                3,
+                1,
+                1,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(3_f32, 2_f32),
+                Complex32::new(19_f32, -7_f32),
+                Complex32::new(31_f32, -10_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chemv1() {
+        // col major test, only the upper triangle and the real parts of the diagonal are referenced
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let a = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_chemv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(2_f32, -2_f32),
+                Complex32::new(11_f32, 9_f32),
+                Complex32::new(11_f32, 9_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chemv2() {
+        // row major test, only the upper triangle and the real parts of the diagonal are referenced
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let a = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_chemv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(2_f32, -2_f32),
+                Complex32::new(11_f32, 9_f32),
+                Complex32::new(11_f32, 9_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chbmv1() {
+        // col major tridiagonal test, k = 1, the lower band is stored
+        //
+        // [2 1+i 0 0]
+        // [1-i 3 2i 0]
+        // [0 -2i 1 1-3i]
+        // [0 0 1+3i 5]
+        let a = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(0_f32, -2_f32),
+            Complex32::new(1_f32, 7_f32),
+            Complex32::new(1_f32, 3_f32),
+            Complex32::new(5_f32, 7_f32),
+            Complex32::new(99_f32, 99_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+            Complex32::new(-1_f32, 0_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(2_f32, 0_f32),
+        ];
+        let alpha = Complex32::new(1_f32, -1_f32);
+        let beta = Complex32::new(0_f32, 1_f32);
+        unsafe {
+            cblas_chbmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                4,
+                1,
+                &alpha,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(2_f32, 1_f32),
+                Complex32::new(9_f32, 3_f32),
+                Complex32::new(6_f32, -1_f32),
+                Complex32::new(5_f32, 7_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chbmv2() {
+        // row major tridiagonal test, k = 1, the upper band is stored
+        //
+        // [2 1+i 0 0]
+        // [1-i 3 2i 0]
+        // [0 -2i 1 1-3i]
+        // [0 0 1+3i 5]
+        let a = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(1_f32, 7_f32),
+            Complex32::new(1_f32, -3_f32),
+            Complex32::new(5_f32, 7_f32),
+            Complex32::new(99_f32, 99_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+            Complex32::new(-1_f32, 0_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(2_f32, 0_f32),
+        ];
+        let alpha = Complex32::new(1_f32, -1_f32);
+        let beta = Complex32::new(0_f32, 1_f32);
+        unsafe {
+            cblas_chbmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                4,
+                1,
+                &alpha,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(2_f32, 1_f32),
+                Complex32::new(9_f32, 3_f32),
+                Complex32::new(6_f32, -1_f32),
+                Complex32::new(5_f32, 7_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chpmv1() {
+        // col major packed test, the upper triangle is stored
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let ap = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_chpmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(2_f32, -2_f32),
+                Complex32::new(11_f32, 9_f32),
+                Complex32::new(11_f32, 9_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chpmv2() {
+        // row major packed test, the lower triangle is stored
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let ap = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, -2_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(4_f32, -1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_chpmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(2_f32, -2_f32),
+                Complex32::new(11_f32, 9_f32),
+                Complex32::new(11_f32, 9_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctrmv1() {
+        // col major, upper, ConjTrans, non-unit test
+        //
+        // [2+i 1-i 3i]
+        // [0 3 4+2i]
+        // [0 0 5-i]
+        let a = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(4_f32, 2_f32),
+            Complex32::new(5_f32, -1_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_ctrmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(2_f32, -1_f32),
+                Complex32::new(1_f32, 4_f32),
+                Complex32::new(13_f32, -2_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctrmv2() {
+        // row major, lower, ConjTrans, unit test
+        //
+        // [1 0 0]
+        // [1+i 1 0]
+        // [2i -1+i 1]
+        let a = vec![
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(-1_f32, 1_f32),
+            Complex32::new(99_f32, 99_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_ctrmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::Unit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(0_f32, -3_f32),
+                Complex32::new(-3_f32, 0_f32),
+                Complex32::new(2_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctrmv3() {
+        // row major, upper, ConjNoTrans, non-unit test
+        //
+        // [2+i 1-i 3i]
+        // [0 3 4+2i]
+        // [0 0 5-i]
+        let a = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(4_f32, 2_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(5_f32, -1_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_ctrmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjNoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(-2_f32, -6_f32),
+                Complex32::new(6_f32, -5_f32),
+                Complex32::new(11_f32, -3_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctrsv1() {
+        // col major, upper, ConjTrans, non-unit test
+        //
+        // [2 1-i 3i]
+        // [0 1+i 4+2i]
+        // [0 0 2i]
+        let a = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(4_f32, 2_f32),
+            Complex32::new(0_f32, 2_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(2_f32, 2_f32),
+            Complex32::new(0_f32, -3_f32),
+        ];
+        unsafe {
+            cblas_ctrsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(0_f32, 1_f32),
+                Complex32::new(2_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctrsv2() {
+        // row major, lower, ConjTrans, non-unit test
+        //
+        // [2 0 0]
+        // [1+i 1+i 0]
+        // [2i -1+i 2i]
+        let a = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(-1_f32, 1_f32),
+            Complex32::new(0_f32, 2_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, -3_f32),
+            Complex32::new(-2_f32, 0_f32),
+            Complex32::new(-2_f32, -4_f32),
+        ];
+        unsafe {
+            cblas_ctrsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(0_f32, 1_f32),
+                Complex32::new(2_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctrsv3() {
+        // row major, upper, ConjNoTrans, unit test
+        //
+        // [1 1-i 3i]
+        // [0 1 4+2i]
+        // [0 0 1]
+        let a = vec![
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(4_f32, 2_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(-3_f32, -5_f32),
+            Complex32::new(6_f32, -7_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_ctrsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjNoTrans,
+                CBlasDiag::Unit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(0_f32, 1_f32),
+                Complex32::new(2_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctbmv1() {
+        // row major band, upper, ConjTrans, non-unit test, k = 2
+        //
+        // [2 1-i 3i 0]
+        // [0 1+i 4+2i 1]
+        // [0 0 2i -i]
+        // [0 0 0 2]
+        let a = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(4_f32, 2_f32),
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+            Complex32::new(-1_f32, 0_f32),
+        ];
+        unsafe {
+            cblas_ctbmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                4,
+                2,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(2_f32, 0_f32),
+                Complex32::new(2_f32, 2_f32),
+                Complex32::new(0_f32, -3_f32),
+                Complex32::new(-1_f32, 3_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctbmv2() {
+        // col major band, lower, ConjNoTrans, unit test, k = 2
+        //
+        // [1 0 0 0]
+        // [1+i 1 0 0]
+        // [2i -1+i 1 0]
+        // [0 1 1-i 1]
+        let a = vec![
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(-1_f32, 1_f32),
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+            Complex32::new(-1_f32, 0_f32),
+        ];
+        unsafe {
+            cblas_ctbmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::ConjNoTrans,
+                CBlasDiag::Unit,
+                4,
+                2,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(3_f32, -4_f32),
+                Complex32::new(2_f32, 2_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctbsv1() {
+        // col major band, upper, ConjTrans, non-unit test, k = 2
+        //
+        // [2 1-i 3i 0]
+        // [0 1+i 4+2i 1]
+        // [0 0 2i -i]
+        // [0 0 0 2]
+        let a = vec![
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(4_f32, 2_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(2_f32, 0_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(2_f32, 2_f32),
+            Complex32::new(0_f32, -3_f32),
+            Complex32::new(-1_f32, 3_f32),
+        ];
+        unsafe {
+            cblas_ctbsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                4,
+                2,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(0_f32, 1_f32),
+                Complex32::new(2_f32, -1_f32),
+                Complex32::new(-1_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctbsv2() {
+        // row major band, lower, NoTrans, non-unit test, k = 2, x with a negative increment
+        //
+        // [2 0 0 0]
+        // [1+i 1+i 0 0]
+        // [2i -1+i 2i 0]
+        // [0 1 1-i 2]
+        let a = vec![
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(-1_f32, 1_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(2_f32, 0_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(-1_f32, -2_f32),
+            Complex32::new(1_f32, 5_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(2_f32, 0_f32),
+        ];
+        unsafe {
+            cblas_ctbsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                4,
+                2,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(-1_f32, 0_f32),
+                Complex32::new(2_f32, -1_f32),
+                Complex32::new(0_f32, 1_f32),
+                Complex32::new(1_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctpmv1() {
+        // col major packed, upper, ConjTrans, non-unit test
+        //
+        // [2 1-i 3i]
+        // [0 1+i 4+2i]
+        // [0 0 2i]
+        let ap = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(4_f32, 2_f32),
+            Complex32::new(0_f32, 2_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_ctpmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(2_f32, 0_f32),
+                Complex32::new(2_f32, 2_f32),
+                Complex32::new(0_f32, -3_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctpmv2() {
+        // row major packed, lower, Trans, non-unit test
+        //
+        // [2 0 0]
+        // [1+i 1+i 0]
+        // [2i -1+i 2i]
+        let ap = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(-1_f32, 1_f32),
+            Complex32::new(0_f32, 2_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_ctpmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(3_f32, 5_f32),
+                Complex32::new(-2_f32, 4_f32),
+                Complex32::new(2_f32, 4_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctpsv1() {
+        // row major packed, upper, ConjTrans, unit test
+        //
+        // [1 1-i 3i]
+        // [0 1 4+2i]
+        // [0 0 1]
+        let ap = vec![
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(0_f32, 3_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(4_f32, 2_f32),
+            Complex32::new(99_f32, 99_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(4_f32, 0_f32),
+        ];
+        unsafe {
+            cblas_ctpsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::Unit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(0_f32, 1_f32),
+                Complex32::new(2_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn ctpsv2() {
+        // col major packed, lower, ConjNoTrans, non-unit test
+        //
+        // [2 0 0]
+        // [1+i 1+i 0]
+        // [2i -1+i 2i]
+        let ap = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 2_f32),
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(-1_f32, 1_f32),
+            Complex32::new(0_f32, 2_f32),
+        ];
+        let mut x = vec![
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(-1_f32, -7_f32),
+        ];
+        unsafe {
+            cblas_ctpsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::ConjNoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(0_f32, 1_f32),
+                Complex32::new(2_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cgeru1() {
+        // col major test, A := alpha * x * y^T + A
+        //
+        // [1+i 2 3-i]
+        // [i 4 5+2i]
+        let mut a = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(4_f32, 0_f32),
+            Complex32::new(3_f32, -1_f32),
+            Complex32::new(5_f32, 2_f32),
+        ];
+        let x = vec![Complex32::new(1_f32, 1_f32), Complex32::new(0_f32, 2_f32)];
+        let y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, -1_f32);
+        unsafe {
+            cblas_cgeru(
+                CBlasOrder::ColMajor,
+                2,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(3_f32, 1_f32),
+                Complex32::new(2_f32, 3_f32),
+                Complex32::new(4_f32, -2_f32),
+                Complex32::new(8_f32, 0_f32),
+                Complex32::new(3_f32, -3_f32),
+                Complex32::new(7_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cgeru2() {
+        // row major test, A := alpha * x * y^T + A
+        //
+        // [1+i 2 3-i]
+        // [i 4 5+2i]
+        let mut a = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(3_f32, -1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(4_f32, 0_f32),
+            Complex32::new(5_f32, 2_f32),
+        ];
+        let x = vec![Complex32::new(1_f32, 1_f32), Complex32::new(0_f32, 2_f32)];
+        let y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, -1_f32);
+        unsafe {
+            cblas_cgeru(
+                CBlasOrder::RowMajor,
+                2,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(3_f32, 1_f32),
+                Complex32::new(4_f32, -2_f32),
+                Complex32::new(3_f32, -3_f32),
+                Complex32::new(2_f32, 3_f32),
+                Complex32::new(8_f32, 0_f32),
+                Complex32::new(7_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cgerc1() {
+        // col major test, A := alpha * x * y^H + A
+        //
+        // [1+i 2 3-i]
+        // [i 4 5+2i]
+        let mut a = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(4_f32, 0_f32),
+            Complex32::new(3_f32, -1_f32),
+            Complex32::new(5_f32, 2_f32),
+        ];
+        let x = vec![Complex32::new(1_f32, 1_f32), Complex32::new(0_f32, 2_f32)];
+        let y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, -1_f32);
+        unsafe {
+            cblas_cgerc(
+                CBlasOrder::ColMajor,
+                2,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(3_f32, 1_f32),
+                Complex32::new(2_f32, 3_f32),
+                Complex32::new(4_f32, 2_f32),
+                Complex32::new(4_f32, 4_f32),
+                Complex32::new(3_f32, 1_f32),
+                Complex32::new(3_f32, 4_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cgerc2() {
+        // row major test, A := alpha * x * y^H + A, x with a negative increment
+        //
+        // [1+i 2 3-i]
+        // [i 4 5+2i]
+        let mut a = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(2_f32, 0_f32),
+            Complex32::new(3_f32, -1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(4_f32, 0_f32),
+            Complex32::new(5_f32, 2_f32),
+        ];
+        let x = vec![Complex32::new(0_f32, 2_f32), Complex32::new(1_f32, 1_f32)];
+        let y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(1_f32, -1_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, -1_f32);
+        unsafe {
+            cblas_cgerc(
+                CBlasOrder::RowMajor,
+                2,
+                3,
+                &alpha,
+                x.as_ptr(),
+                -1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(3_f32, 1_f32),
+                Complex32::new(4_f32, 2_f32),
+                Complex32::new(3_f32, 1_f32),
+                Complex32::new(2_f32, 3_f32),
+                Complex32::new(4_f32, 4_f32),
+                Complex32::new(3_f32, 4_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cher_1() {
+        // col major test, only the upper triangle of A := alpha * x * x^H + A is updated
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut a = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_cher(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f32,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(4_f32, 0_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(5_f32, 0_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(4_f32, 1_f32),
+                Complex32::new(2_f32, 5_f32),
+                Complex32::new(15_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cher_2() {
+        // row major test, only the upper triangle of A := alpha * x * x^H + A is updated
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut a = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_cher(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                -1_f32,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(1_f32, 3_f32),
+                Complex32::new(-2_f32, -2_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(2_f32, 0_f32),
+                Complex32::new(5_f32, -1_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(0_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cher2_1() {
+        // col major test, only the lower triangle of A := alpha * x * y^H + conj(alpha) * y * x^H + A is updated
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut a = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, -2_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(4_f32, -1_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let y = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(-1_f32, 0_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        unsafe {
+            cblas_cher2(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(6_f32, 0_f32),
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(3_f32, 0_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(3_f32, 0_f32),
+                Complex32::new(5_f32, 0_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(-1_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cher2_2() {
+        // row major test, only the lower triangle of A := alpha * x * y^H + conj(alpha) * y * x^H + A is updated, x with a negative increment
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut a = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(1_f32, -2_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(99_f32, 99_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(4_f32, -1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(2_f32, -1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(1_f32, 0_f32),
+        ];
+        let y = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(-1_f32, 0_f32),
+        ];
+        let alpha = Complex32::new(2_f32, -1_f32);
+        unsafe {
+            cblas_cher2(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                -1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(4_f32, 0_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(4_f32, -1_f32),
+                Complex32::new(3_f32, 0_f32),
+                Complex32::new(99_f32, 99_f32),
+                Complex32::new(-3_f32, -7_f32),
+                Complex32::new(3_f32, 1_f32),
+                Complex32::new(-1_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chpr_1() {
+        // col major packed test, the upper triangle of A := alpha * x * x^H + A is stored
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut ap = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        unsafe {
+            cblas_chpr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f32,
+                x.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex32::new(4_f32, 0_f32),
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(5_f32, 0_f32),
+                Complex32::new(4_f32, 1_f32),
+                Complex32::new(2_f32, 5_f32),
+                Complex32::new(15_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chpr_2() {
+        // row major packed test, the lower triangle of A := alpha * x * x^H + A is stored, x with a negative increment
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut ap = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, -2_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(4_f32, -1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(2_f32, -1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(1_f32, 0_f32),
+        ];
+        unsafe {
+            cblas_chpr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f32,
+                x.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex32::new(4_f32, 0_f32),
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(5_f32, 0_f32),
+                Complex32::new(4_f32, -1_f32),
+                Complex32::new(2_f32, -5_f32),
+                Complex32::new(15_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chpr2_1() {
+        // col major packed test, the lower triangle of A := alpha * x * y^H + conj(alpha) * y * x^H + A is stored
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut ap = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, -2_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(4_f32, -1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let y = vec![
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(-1_f32, 0_f32),
+            Complex32::new(1_f32, 1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        unsafe {
+            cblas_chpr2(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex32::new(4_f32, 0_f32),
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(3_f32, -2_f32),
+                Complex32::new(5_f32, 0_f32),
+                Complex32::new(1_f32, -4_f32),
+                Complex32::new(13_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn chpr2_2() {
+        // row major packed test, the upper triangle of A := alpha * x * y^H + conj(alpha) * y * x^H + A is stored, y with a negative increment
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut ap = vec![
+            Complex32::new(2_f32, 7_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(3_f32, 7_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, 7_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let y = vec![
+            Complex32::new(1_f32, 1_f32),
+            Complex32::new(-1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        unsafe {
+            cblas_chpr2(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex32::new(4_f32, 0_f32),
+                Complex32::new(1_f32, 0_f32),
+                Complex32::new(3_f32, 2_f32),
+                Complex32::new(5_f32, 0_f32),
+                Complex32::new(1_f32, 4_f32),
+                Complex32::new(13_f32, 0_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn csymv1() {
+        // col major test, only the upper triangle is referenced
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let a = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, -2_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_csymv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(1_f32, -1_f32),
+                Complex32::new(7_f32, 13_f32),
+                Complex32::new(13_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn csymv2() {
+        // row major test, only the lower triangle is referenced
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let a = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, -2_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_csymv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(1_f32, -1_f32),
+                Complex32::new(7_f32, 13_f32),
+                Complex32::new(13_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cspmv1() {
+        // col major packed test, the lower triangle is stored
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let ap = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, -2_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_cspmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(1_f32, -1_f32),
+                Complex32::new(7_f32, 13_f32),
+                Complex32::new(13_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cspmv2() {
+        // row major packed test, the upper triangle is stored
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let ap = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, -2_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let mut y = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        let beta = Complex32::new(2_f32, 0_f32);
+        unsafe {
+            cblas_cspmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex32::new(1_f32, -1_f32),
+                Complex32::new(7_f32, 13_f32),
+                Complex32::new(13_f32, -1_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn csyr_1() {
+        // col major test, only the upper triangle of A := alpha * x * x^T + A is updated
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let mut a = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, -2_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        unsafe {
+            cblas_csyr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(3_f32, 2_f32),
+                Complex32::new(99_f32, 0_f32),
+                Complex32::new(99_f32, 0_f32),
+                Complex32::new(0_f32, 3_f32),
+                Complex32::new(2_f32, -1_f32),
+                Complex32::new(99_f32, 0_f32),
+                Complex32::new(3_f32, 0_f32),
+                Complex32::new(3_f32, 4_f32),
+                Complex32::new(12_f32, -3_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn csyr_2() {
+        // row major test, only the lower triangle of A := alpha * x * x^T + A is updated, x with a negative increment
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let mut a = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(99_f32, 0_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, -2_f32),
+        ];
+        let x = vec![
+            Complex32::new(2_f32, -1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(1_f32, 0_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        unsafe {
+            cblas_csyr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                -1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex32::new(3_f32, 2_f32),
+                Complex32::new(99_f32, 0_f32),
+                Complex32::new(99_f32, 0_f32),
+                Complex32::new(0_f32, 3_f32),
+                Complex32::new(2_f32, -1_f32),
+                Complex32::new(99_f32, 0_f32),
+                Complex32::new(3_f32, 0_f32),
+                Complex32::new(3_f32, 4_f32),
+                Complex32::new(12_f32, -3_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cspr_1() {
+        // col major packed test, the lower triangle of A := alpha * x * x^T + A is stored
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let mut ap = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, -2_f32),
+        ];
+        let x = vec![
+            Complex32::new(1_f32, 0_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(2_f32, -1_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        unsafe {
+            cblas_cspr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex32::new(3_f32, 2_f32),
+                Complex32::new(0_f32, 3_f32),
+                Complex32::new(3_f32, 0_f32),
+                Complex32::new(2_f32, -1_f32),
+                Complex32::new(3_f32, 4_f32),
+                Complex32::new(12_f32, -3_f32)
+            ]
+        )
+    }
+
+    #[test]
+    fn cspr_2() {
+        // row major packed test, the upper triangle of A := alpha * x * x^T + A is stored, x with a negative increment
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let mut ap = vec![
+            Complex32::new(2_f32, 1_f32),
+            Complex32::new(1_f32, 2_f32),
+            Complex32::new(0_f32, -1_f32),
+            Complex32::new(3_f32, 0_f32),
+            Complex32::new(4_f32, 1_f32),
+            Complex32::new(5_f32, -2_f32),
+        ];
+        let x = vec![
+            Complex32::new(2_f32, -1_f32),
+            Complex32::new(0_f32, 1_f32),
+            Complex32::new(1_f32, 0_f32),
+        ];
+        let alpha = Complex32::new(1_f32, 1_f32);
+        unsafe {
+            cblas_cspr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                x.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex32::new(3_f32, 2_f32),
+                Complex32::new(0_f32, 3_f32),
+                Complex32::new(3_f32, 0_f32),
+                Complex32::new(2_f32, -1_f32),
+                Complex32::new(3_f32, 4_f32),
+                Complex32::new(12_f32, -3_f32)
+            ]
+        )
+    }
+}
@@ -0,0 +1,1079 @@
+#[cfg(test)]
+mod d_test {
+    use roblas::common::{CBlasDiag, CBlasOrder, CBlasTranspose, CBlasUpLo};
+    use roblas::level2::*;
+
+    #[test]
+    fn dgemv1() {
+        // col major test
+        //
+        // 3 * [1 2 3] * [1] + 2 * [-3]
+        //     [3 4 5]   [2]       [-2]
+        //               [3]
+        let a = vec![1_f64, 3_f64, 2_f64, 4_f64, 3_f64, 5_f64];
+        let x = vec![1_f64, 2_f64, 3_f64];
+        let mut y = vec![-3_f64, -2_f64];
+        unsafe {
+            cblas_dgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::NoTrans,
+                2,
+                3,
+                3_f64,
+                a.as_ptr(),
+                2, // here `lda` is 2 because of col-major array
+                x.as_ptr(),
+                1,
+                2_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![36_f64, 74_f64])
+    }
+
+    #[test]
+    fn dgemv2() {
+        // row major test
+        //
+        // 3 * [1 2 3] * [1] + 2 * [-3]
+        //     [3 4 5]   [2]       [-2]
+        //               [3]
+        let a = vec![1_f64, 2_f64, 3_f64, 3_f64, 4_f64, 5_f64];
+        let x = vec![1_f64, 2_f64, 3_f64];
+        let mut y = vec![-3_f64, 0_f64, -2_f64];
+        unsafe {
+            cblas_dgemv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::NoTrans,
+                2,
+                3,
+                3_f64,
+                a.as_ptr(),
+                3, // here `lda` is 3 because of row-major array
+                x.as_ptr(),
+                1,
+                2_f64,
+                y.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(y, vec![36_f64, 0_f64, 74_f64])
+    }
+
+    #[test]
+    fn dgemv_unit_stride() {
+        // the unit stride vectors are compared with the same vectors at stride 2, which are
+        // handled by the scalar code; the sizes cover the blocks of rows, the blocks of cols and
+        // the tails of both
+        for &(m, n) in &[(1_usize, 1_usize), (7, 5), (37, 11), (70, 4), (2053, 6)] {
+            let lda = m.max(n) + 3;
+            // integer entries keep every result exact
+            let a: Vec<f64> = (0..lda * m.max(n))
+                .map(|i| ((i * 7 + 3) % 11) as f64 - 5_f64)
+                .collect();
+            let stride =
+                |v: &[f64]| -> Vec<f64> { v.iter().flat_map(|&e| vec![e, 0_f64]).collect() };
+            // the enums are not `Copy`, so they are made for each call
+            let order = |row_major| match row_major {
+                false => CBlasOrder::ColMajor,
+                true => CBlasOrder::RowMajor,
+            };
+            let op = |trans| match trans {
+                false => CBlasTranspose::NoTrans,
+                true => CBlasTranspose::Trans,
+            };
+            for &row_major in &[false, true] {
+                for &trans in &[false, true] {
+                    let (len_x, len_y) = if trans { (m, n) } else { (n, m) };
+                    let x: Vec<f64> = (0..len_x)
+                        .map(|i| ((i * 5 + 1) % 9) as f64 - 4_f64)
+                        .collect();
+                    let y: Vec<f64> = (0..len_y).map(|i| (i % 3) as f64 - 1_f64).collect();
+                    let (xs, mut ys) = (stride(&x), stride(&y));
+                    let mut y1 = y.clone();
+                    let (mi, ni, ldai) = (m as i32, n as i32, lda as i32);
+                    unsafe {
+                        cblas_dgemv(
+                            order(row_major),
+                            op(trans),
+                            mi,
+                            ni,
+                            2_f64,
+                            a.as_ptr(),
+                            ldai,
+                            x.as_ptr(),
+                            1,
+                            -3_f64,
+                            y1.as_mut_ptr(),
+                            1,
+                        );
+                        cblas_dgemv(
+                            order(row_major),
+                            op(trans),
+                            mi,
+                            ni,
+                            2_f64,
+                            a.as_ptr(),
+                            ldai,
+                            xs.as_ptr(),
+                            2,
+                            -3_f64,
+                            ys.as_mut_ptr(),
+                            2,
+                        );
+                    }
+                    assert_eq!(stride(&y1), ys);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dgemv3() {
+        // col major, transposed test
+        //
+        // 3 * [1 2 3]^T * [1] + 2 * [1]
+        //     [3 4 5]     [2]       [1]
+        //                           [1]
+        let a = vec![1_f64, 3_f64, 2_f64, 4_f64, 3_f64, 5_f64];
+        let x = vec![1_f64, 2_f64];
+        let mut y = vec![1_f64, 1_f64, 1_f64];
+        unsafe {
+            cblas_dgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::Trans,
+                2,
+                3,
+                3_f64,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                2_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![23_f64, 32_f64, 41_f64])
+    }
+
+    #[test]
+    fn dgemv4() {
+        // col major, conjugate transposed test, which is the same as transposed for real matrices
+        //
+        // 3 * [1 2 3]^H * [1] + 2 * [1]
+        //     [3 4 5]     [2]       [1]
+        //                           [1]
+        let a = vec![1_f64, 3_f64, 2_f64, 4_f64, 3_f64, 5_f64];
+        let x = vec![1_f64, 2_f64];
+        let mut y = vec![1_f64, 1_f64, 1_f64];
+        unsafe {
+            cblas_dgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::ConjTrans,
+                2,
+                3,
+                3_f64,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                2_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![23_f64, 32_f64, 41_f64])
+    }
+    #[test]
+    fn dgemv5() {
+        // col major test, x and y with negative increments, which are stored in reverse
+        //
+        // 3 * [1 2 3] * [1] + 2 * [-3]    3 * [1 3]^T * [1] + 2 * [1]
+        //     [3 4 5]   [2]       [-2]        [2 4]     [2]       [1]
+        //               [3]                   [3 5]               [1]
+        let a = vec![1_f64, 3_f64, 2_f64, 4_f64, 3_f64, 5_f64];
+        let x = vec![3_f64, 2_f64, 1_f64];
+        let mut y = vec![-2_f64, -3_f64];
+        unsafe {
+            cblas_dgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::NoTrans,
+                2,
+                3,
+                3_f64,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                -1,
+                2_f64,
+                y.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(y, vec![74_f64, 36_f64]);
+
+        let x = vec![2_f64, 0_f64, 1_f64];
+        let mut y = vec![1_f64, 1_f64, 1_f64];
+        unsafe {
+            cblas_dgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::Trans,
+                2,
+                3,
+                3_f64,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                -2,
+                2_f64,
+                y.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(y, vec![41_f64, 32_f64, 23_f64])
+    }
+
+    #[test]
+    fn dgbmv1() {
+        // row major band test, kl = 1, ku = 2
+        //
+        // [1 2 3 0 0]
+        // [4 5 6 7 0]
+        // [0 8 9 1 2]
+        // [0 0 3 4 5]
+        let a = vec![
+            0_f64, 1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 1_f64, 2_f64,
+            3_f64, 4_f64, 5_f64, 0_f64,
+        ];
+        let x = vec![1_f64, 2_f64, 3_f64, 4_f64, 5_f64];
+        let mut y = vec![1_f64, 1_f64, 1_f64, 1_f64];
+        unsafe {
+            cblas_dgbmv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::NoTrans,
+                4,
+                5,
+                1,
+                2,
+                2_f64,
+                a.as_ptr(),
+                4,
+                x.as_ptr(),
+                1,
+                -1_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![27_f64, 119_f64, 113_f64, 99_f64])
+    }
+
+    #[test]
+    fn dgbmv2() {
+        // col major conjugate transposed band test, kl = 1, ku = 2
+        //
+        // [1 2 3 0 0]
+        // [4 5 6 7 0]
+        // [0 8 9 1 2]
+        // [0 0 3 4 5]
+        let a = vec![
+            0_f64, 0_f64, 1_f64, 4_f64, 0_f64, 2_f64, 5_f64, 8_f64, 3_f64, 6_f64, 9_f64, 3_f64,
+            7_f64, 1_f64, 4_f64, 0_f64, 2_f64, 5_f64, 0_f64, 0_f64,
+        ];
+        let x = vec![1_f64, -1_f64, 2_f64, 0_f64];
+        let mut y = vec![1_f64, 2_f64, 3_f64, 4_f64, 5_f64];
+        unsafe {
+            cblas_dgbmv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::ConjTrans,
+                4,
+                5,
+                1,
+                2,
+                1_f64,
+                a.as_ptr(),
+                4,
+                x.as_ptr(),
+                1,
+                2_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![-1_f64, 17_f64, 21_f64, 3_f64, 14_f64])
+    }
+
+    #[test]
+    fn dsymv1() {
+        // col major test, only the lower triangle is referenced, x with a negative increment
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let a = vec![
+            2_f64, 1_f64, -1_f64, 99_f64, 3_f64, 4_f64, 99_f64, 99_f64, 5_f64,
+        ];
+        let x = vec![3_f64, 2_f64, 1_f64];
+        let mut y = vec![1_f64, -1_f64, 2_f64];
+        unsafe {
+            cblas_dsymv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f64,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                -1,
+                3_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![5_f64, 35_f64, 50_f64])
+    }
+
+    #[test]
+    fn dsymv2() {
+        // row major test, only the upper triangle is referenced
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let a = vec![
+            2_f64, 1_f64, -1_f64, 99_f64, 3_f64, 4_f64, 99_f64, 99_f64, 5_f64,
+        ];
+        let x = vec![1_f64, 0_f64, -2_f64];
+        let mut y = vec![1_f64, -1_f64, 2_f64];
+        unsafe {
+            cblas_dsymv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                -1_f64,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                1_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![-3_f64, 6_f64, 13_f64])
+    }
+
+    #[test]
+    fn dsbmv1() {
+        // col major pentadiagonal test, k = 2, the lower band is stored
+        //
+        // [4 -1 2 0 0]
+        // [-1 4 -1 2 0]
+        // [2 -1 4 -1 2]
+        // [0 2 -1 4 -1]
+        // [0 0 2 -1 4]
+        let a = vec![
+            4_f64, -1_f64, 2_f64, 4_f64, -1_f64, 2_f64, 4_f64, -1_f64, 2_f64, 4_f64, -1_f64,
+            99_f64, 4_f64, 99_f64, 99_f64,
+        ];
+        let x = vec![1_f64, 2_f64, 3_f64, 4_f64, 5_f64];
+        let mut y = vec![1_f64, -1_f64, 2_f64, 0_f64, 1_f64];
+        unsafe {
+            cblas_dsbmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                5,
+                2,
+                2_f64,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                1_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![17_f64, 23_f64, 38_f64, 24_f64, 45_f64])
+    }
+
+    #[test]
+    fn dsbmv2() {
+        // row major pentadiagonal test, k = 2, the upper band is stored, y with a negative increment
+        //
+        // [4 -1 2 0 0]
+        // [-1 4 -1 2 0]
+        // [2 -1 4 -1 2]
+        // [0 2 -1 4 -1]
+        // [0 0 2 -1 4]
+        let a = vec![
+            4_f64, -1_f64, 2_f64, 4_f64, -1_f64, 2_f64, 4_f64, -1_f64, 2_f64, 4_f64, -1_f64,
+            99_f64, 4_f64, 99_f64, 99_f64,
+        ];
+        let x = vec![1_f64, 2_f64, 3_f64, 4_f64, 5_f64];
+        let mut y = vec![1_f64, 0_f64, 2_f64, -1_f64, 1_f64];
+        unsafe {
+            cblas_dsbmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                5,
+                2,
+                1_f64,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                0_f64,
+                y.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(y, vec![22_f64, 12_f64, 18_f64, 12_f64, 8_f64])
+    }
+
+    #[test]
+    fn dspmv1() {
+        // col major packed test, the lower triangle is stored
+        //
+        // [2 1 -1 3]
+        // [1 3 4 0]
+        // [-1 4 5 -2]
+        // [3 0 -2 1]
+        let ap = vec![
+            2_f64, 1_f64, -1_f64, 3_f64, 3_f64, 4_f64, 0_f64, 5_f64, -2_f64, 1_f64,
+        ];
+        let x = vec![1_f64, 2_f64, 3_f64, -1_f64];
+        let mut y = vec![1_f64, -1_f64, 2_f64, 0_f64];
+        unsafe {
+            cblas_dspmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                4,
+                2_f64,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                1_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![-3_f64, 37_f64, 50_f64, -8_f64])
+    }
+
+    #[test]
+    fn dspmv2() {
+        // row major packed test, the lower triangle is stored, x with a negative increment
+        //
+        // [2 1 -1 3]
+        // [1 3 4 0]
+        // [-1 4 5 -2]
+        // [3 0 -2 1]
+        let ap = vec![
+            2_f64, 1_f64, 3_f64, -1_f64, 4_f64, 5_f64, 3_f64, 0_f64, -2_f64, 1_f64,
+        ];
+        let x = vec![-1_f64, 3_f64, 2_f64, 1_f64];
+        let mut y = vec![1_f64, -1_f64, 2_f64, 0_f64];
+        unsafe {
+            cblas_dspmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                4,
+                1_f64,
+                ap.as_ptr(),
+                x.as_ptr(),
+                -1,
+                0_f64,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![-2_f64, 19_f64, 24_f64, -4_f64])
+    }
+
+    #[test]
+    fn dtrmv1() {
+        // col major, lower, Trans, non-unit test, x with a negative increment
+        //
+        // [2 0 0]
+        // [3 3 0]
+        // [-2 6 5]
+        let a = vec![
+            2_f64, 3_f64, -2_f64, 99_f64, 3_f64, 6_f64, 99_f64, 99_f64, 5_f64,
+        ];
+        let mut x = vec![-3_f64, 2_f64, 1_f64];
+        unsafe {
+            cblas_dtrmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(x, vec![-15_f64, -12_f64, 14_f64])
+    }
+
+    #[test]
+    fn dtrmv2() {
+        // row major, upper, NoTrans, unit test
+        //
+        // [1 1 -1]
+        // [0 1 4]
+        // [0 0 1]
+        let a = vec![
+            99_f64, 1_f64, -1_f64, 99_f64, 99_f64, 4_f64, 99_f64, 99_f64, 99_f64,
+        ];
+        let mut x = vec![1_f64, 2_f64, -3_f64];
+        unsafe {
+            cblas_dtrmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::Unit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![6_f64, -10_f64, -3_f64])
+    }
+
+    #[test]
+    fn dtrsv1() {
+        // col major, lower, Trans, non-unit test, x with a negative increment
+        //
+        // [2 0 0]
+        // [3 4 0]
+        // [-2 6 1]
+        let a = vec![
+            2_f64, 3_f64, -2_f64, 99_f64, 4_f64, 6_f64, 99_f64, 99_f64, 1_f64,
+        ];
+        let mut x = vec![-3_f64, -10_f64, 14_f64];
+        unsafe {
+            cblas_dtrsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(x, vec![-3_f64, 2_f64, 1_f64])
+    }
+
+    #[test]
+    fn dtrsv2() {
+        // row major, upper, NoTrans, non-unit test
+        //
+        // [2 1 -1]
+        // [0 4 4]
+        // [0 0 1]
+        let a = vec![
+            2_f64, 1_f64, -1_f64, 99_f64, 4_f64, 4_f64, 99_f64, 99_f64, 1_f64,
+        ];
+        let mut x = vec![7_f64, -4_f64, -3_f64];
+        unsafe {
+            cblas_dtrsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f64, 2_f64, -3_f64])
+    }
+
+    #[test]
+    fn dtbmv1() {
+        // row major band, upper, NoTrans, non-unit test, k = 1
+        //
+        // [2 1 0 0]
+        // [0 4 -1 0]
+        // [0 0 1 2]
+        // [0 0 0 2]
+        let a = vec![2_f64, 1_f64, 4_f64, -1_f64, 1_f64, 2_f64, 2_f64, 99_f64];
+        let mut x = vec![1_f64, 2_f64, -3_f64, 1_f64];
+        unsafe {
+            cblas_dtbmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                4,
+                1,
+                a.as_ptr(),
+                2,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![4_f64, 11_f64, -1_f64, 2_f64])
+    }
+
+    #[test]
+    fn dtbmv2() {
+        // col major band, lower, Trans, unit test, k = 1, x with a negative increment
+        //
+        // [1 0 0 0]
+        // [3 1 0 0]
+        // [0 6 1 0]
+        // [0 0 -2 1]
+        let a = vec![99_f64, 3_f64, 99_f64, 6_f64, 99_f64, -2_f64, 99_f64, 99_f64];
+        let mut x = vec![1_f64, -3_f64, 2_f64, 1_f64];
+        unsafe {
+            cblas_dtbmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::Unit,
+                4,
+                1,
+                a.as_ptr(),
+                2,
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(x, vec![1_f64, -5_f64, -16_f64, 7_f64])
+    }
+
+    #[test]
+    fn dtbsv1() {
+        // row major band, lower, NoTrans, non-unit test, k = 1
+        //
+        // [2 0 0 0]
+        // [3 4 0 0]
+        // [0 6 1 0]
+        // [0 0 -2 2]
+        let a = vec![99_f64, 2_f64, 3_f64, 4_f64, 6_f64, 1_f64, -2_f64, 2_f64];
+        let mut x = vec![2_f64, 11_f64, 9_f64, 8_f64];
+        unsafe {
+            cblas_dtbsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                4,
+                1,
+                a.as_ptr(),
+                2,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f64, 2_f64, -3_f64, 1_f64])
+    }
+
+    #[test]
+    fn dtbsv2() {
+        // col major band, upper, Trans, non-unit test, k = 1
+        //
+        // [2 1 0 0]
+        // [0 4 -1 0]
+        // [0 0 1 2]
+        // [0 0 0 2]
+        let a = vec![99_f64, 2_f64, 1_f64, 4_f64, -1_f64, 1_f64, 2_f64, 2_f64];
+        let mut x = vec![2_f64, 9_f64, -5_f64, -4_f64];
+        unsafe {
+            cblas_dtbsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                4,
+                1,
+                a.as_ptr(),
+                2,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f64, 2_f64, -3_f64, 1_f64])
+    }
+
+    #[test]
+    fn dtpmv1() {
+        // col major packed, upper, Trans, non-unit test
+        //
+        // [2 1 -1]
+        // [0 4 4]
+        // [0 0 1]
+        let ap = vec![2_f64, 1_f64, 4_f64, -1_f64, 4_f64, 1_f64];
+        let mut x = vec![1_f64, 2_f64, -3_f64];
+        unsafe {
+            cblas_dtpmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![2_f64, 9_f64, 4_f64])
+    }
+
+    #[test]
+    fn dtpmv2() {
+        // row major packed, lower, NoTrans, unit test
+        //
+        // [1 0 0]
+        // [3 1 0]
+        // [-2 6 1]
+        let ap = vec![99_f64, 3_f64, 99_f64, -2_f64, 6_f64, 99_f64];
+        let mut x = vec![1_f64, 2_f64, -3_f64];
+        unsafe {
+            cblas_dtpmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::Unit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f64, 5_f64, 7_f64])
+    }
+
+    #[test]
+    fn dtpsv1() {
+        // row major packed, upper, NoTrans, non-unit test, x with a negative increment
+        //
+        // [2 1 -1]
+        // [0 4 4]
+        // [0 0 1]
+        let ap = vec![2_f64, 1_f64, -1_f64, 4_f64, 4_f64, 1_f64];
+        let mut x = vec![-3_f64, -4_f64, 7_f64];
+        unsafe {
+            cblas_dtpsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(x, vec![-3_f64, 2_f64, 1_f64])
+    }
+
+    #[test]
+    fn dtpsv2() {
+        // col major packed, lower, Trans, non-unit test
+        //
+        // [2 0 0]
+        // [3 4 0]
+        // [-2 6 1]
+        let ap = vec![2_f64, 3_f64, -2_f64, 4_f64, 6_f64, 1_f64];
+        let mut x = vec![14_f64, -10_f64, -3_f64];
+        unsafe {
+            cblas_dtpsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f64, 2_f64, -3_f64])
+    }
+
+    #[test]
+    fn dger1() {
+        // row major test, A := alpha * x * y^T + A
+        //
+        // [1 2 3]
+        // [3 4 5]
+        let mut a = vec![1_f64, 2_f64, 3_f64, 3_f64, 4_f64, 5_f64];
+        let x = vec![1_f64, -2_f64];
+        let y = vec![2_f64, 0_f64, 1_f64];
+        unsafe {
+            cblas_dger(
+                CBlasOrder::RowMajor,
+                2,
+                3,
+                2_f64,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(a, vec![5_f64, 2_f64, 5_f64, -5_f64, 4_f64, 1_f64])
+    }
+
+    #[test]
+    fn dger2() {
+        // col major test, A := alpha * x * y^T + A, x with a negative increment
+        //
+        // [1 2 3]
+        // [3 4 5]
+        let mut a = vec![1_f64, 3_f64, 2_f64, 4_f64, 3_f64, 5_f64];
+        let x = vec![-2_f64, 1_f64];
+        let y = vec![2_f64, 0_f64, 1_f64];
+        unsafe {
+            cblas_dger(
+                CBlasOrder::ColMajor,
+                2,
+                3,
+                3_f64,
+                x.as_ptr(),
+                -1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(a, vec![7_f64, -9_f64, 2_f64, 4_f64, 6_f64, -1_f64])
+    }
+
+    #[test]
+    fn dsyr_1() {
+        // col major test, only the lower triangle of A := alpha * x * x^T + A is updated
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut a = vec![
+            2_f64, 1_f64, -1_f64, 99_f64, 3_f64, 4_f64, 99_f64, 99_f64, 5_f64,
+        ];
+        let x = vec![1_f64, 2_f64, -1_f64];
+        unsafe {
+            cblas_dsyr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f64,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![4_f64, 5_f64, -3_f64, 99_f64, 11_f64, 0_f64, 99_f64, 99_f64, 7_f64]
+        )
+    }
+
+    #[test]
+    fn dsyr_2() {
+        // row major test, only the lower triangle of A := alpha * x * x^T + A is updated
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut a = vec![
+            2_f64, 99_f64, 99_f64, 1_f64, 3_f64, 99_f64, -1_f64, 4_f64, 5_f64,
+        ];
+        let x = vec![1_f64, 2_f64, -1_f64];
+        unsafe {
+            cblas_dsyr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                3_f64,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![5_f64, 99_f64, 99_f64, 7_f64, 15_f64, 99_f64, -4_f64, -2_f64, 8_f64]
+        )
+    }
+
+    #[test]
+    fn dsyr2_1() {
+        // col major test, only the upper triangle of A := alpha * x * y^T + alpha * y * x^T + A is updated
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut a = vec![
+            2_f64, 99_f64, 99_f64, 1_f64, 3_f64, 99_f64, -1_f64, 4_f64, 5_f64,
+        ];
+        let x = vec![1_f64, 2_f64, -1_f64];
+        let y = vec![0_f64, 1_f64, 3_f64];
+        unsafe {
+            cblas_dsyr2(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                -2_f64,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![2_f64, 99_f64, 99_f64, -1_f64, -5_f64, 99_f64, -7_f64, -6_f64, 17_f64]
+        )
+    }
+
+    #[test]
+    fn dsyr2_2() {
+        // row major test, only the lower triangle of A := alpha * x * y^T + alpha * y * x^T + A is updated
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut a = vec![
+            2_f64, 99_f64, 99_f64, 1_f64, 3_f64, 99_f64, -1_f64, 4_f64, 5_f64,
+        ];
+        let x = vec![1_f64, 2_f64, -1_f64];
+        let y = vec![0_f64, 1_f64, 3_f64];
+        unsafe {
+            cblas_dsyr2(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                1_f64,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![2_f64, 99_f64, 99_f64, 2_f64, 7_f64, 99_f64, 2_f64, 9_f64, -1_f64]
+        )
+    }
+
+    #[test]
+    fn dspr_1() {
+        // col major packed test, the upper triangle of A := alpha * x * x^T + A is stored
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut ap = vec![2_f64, 1_f64, 3_f64, -1_f64, 4_f64, 5_f64];
+        let x = vec![1_f64, 2_f64, -1_f64];
+        unsafe {
+            cblas_dspr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f64,
+                x.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(ap, vec![4_f64, 5_f64, 11_f64, -3_f64, 0_f64, 7_f64])
+    }
+
+    #[test]
+    fn dspr_2() {
+        // row major packed test, the lower triangle of A := alpha * x * x^T + A is stored, x with a negative increment
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut ap = vec![2_f64, 1_f64, 3_f64, -1_f64, 4_f64, 5_f64];
+        let x = vec![-1_f64, 2_f64, 1_f64];
+        unsafe {
+            cblas_dspr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f64,
+                x.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(ap, vec![4_f64, 5_f64, 11_f64, -3_f64, 0_f64, 7_f64])
+    }
+
+    #[test]
+    fn dspr2_1() {
+        // col major packed test, the lower triangle of A := alpha * x * y^T + alpha * y * x^T + A is stored
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut ap = vec![2_f64, 1_f64, -1_f64, 3_f64, 4_f64, 5_f64];
+        let x = vec![1_f64, 2_f64, -1_f64];
+        let y = vec![0_f64, 1_f64, 3_f64];
+        unsafe {
+            cblas_dspr2(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f64,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(ap, vec![2_f64, 3_f64, 5_f64, 11_f64, 14_f64, -7_f64])
+    }
+
+    #[test]
+    fn dspr2_2() {
+        // row major packed test, the upper triangle of A := alpha * x * y^T + alpha * y * x^T + A is stored, y with a negative increment
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut ap = vec![2_f64, 1_f64, -1_f64, 3_f64, 4_f64, 5_f64];
+        let x = vec![1_f64, 2_f64, -1_f64];
+        let y = vec![3_f64, 1_f64, 0_f64];
+        unsafe {
+            cblas_dspr2(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f64,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(ap, vec![2_f64, 3_f64, 5_f64, 11_f64, 14_f64, -7_f64])
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod s_test {
-    use roblas::common::{CBlasOrder, CBlasTranspose};
+    use roblas::common::{CBlasDiag, CBlasOrder, CBlasTranspose, CBlasUpLo};
     use roblas::level2::*;
 
     #[test]
@@ -60,4 +60,943 @@ mod s_test {
         }
         assert_eq!(y, vec![36_f32, 0_f32, 74_f32])
     }
+
+    #[test]
+    fn sgemv_unit_stride() {
+        // the unit stride vectors are compared with the same vectors at stride 2, which are
+        // handled by the scalar code; the sizes cover the blocks of rows, the blocks of cols and
+        // the tails of both
+        for &(m, n) in &[(1_usize, 1_usize), (7, 5), (37, 11), (70, 4), (2053, 6)] {
+            let lda = m.max(n) + 3;
+            // integer entries keep every result exact
+            let a: Vec<f32> = (0..lda * m.max(n))
+                .map(|i| ((i * 7 + 3) % 11) as f32 - 5_f32)
+                .collect();
+            let stride =
+                |v: &[f32]| -> Vec<f32> { v.iter().flat_map(|&e| vec![e, 0_f32]).collect() };
+            // the enums are not `Copy`, so they are made for each call
+            let order = |row_major| match row_major {
+                false => CBlasOrder::ColMajor,
+                true => CBlasOrder::RowMajor,
+            };
+            let op = |trans| match trans {
+                false => CBlasTranspose::NoTrans,
+                true => CBlasTranspose::Trans,
+            };
+            for &row_major in &[false, true] {
+                for &trans in &[false, true] {
+                    let (len_x, len_y) = if trans { (m, n) } else { (n, m) };
+                    let x: Vec<f32> = (0..len_x)
+                        .map(|i| ((i * 5 + 1) % 9) as f32 - 4_f32)
+                        .collect();
+                    let y: Vec<f32> = (0..len_y).map(|i| (i % 3) as f32 - 1_f32).collect();
+                    let (xs, mut ys) = (stride(&x), stride(&y));
+                    let mut y1 = y.clone();
+                    let (mi, ni, ldai) = (m as i32, n as i32, lda as i32);
+                    unsafe {
+                        cblas_sgemv(
+                            order(row_major),
+                            op(trans),
+                            mi,
+                            ni,
+                            2_f32,
+                            a.as_ptr(),
+                            ldai,
+                            x.as_ptr(),
+                            1,
+                            -3_f32,
+                            y1.as_mut_ptr(),
+                            1,
+                        );
+                        cblas_sgemv(
+                            order(row_major),
+                            op(trans),
+                            mi,
+                            ni,
+                            2_f32,
+                            a.as_ptr(),
+                            ldai,
+                            xs.as_ptr(),
+                            2,
+                            -3_f32,
+                            ys.as_mut_ptr(),
+                            2,
+                        );
+                    }
+                    assert_eq!(stride(&y1), ys);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sgemv3() {
+        // col major, conjugate transposed test, which is the same as transposed for real matrices
+        //
+        // 3 * [1 2 3]^H * [1] + 2 * [1]
+        //     [3 4 5]     [2]       [1]
+        //                           [1]
+        let a = vec![1_f32, 3_f32, 2_f32, 4_f32, 3_f32, 5_f32];
+        let x = vec![1_f32, 2_f32];
+        let mut y = vec![1_f32, 1_f32, 1_f32];
+        unsafe {
+            cblas_sgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::ConjTrans,
+                2,
+                3,
+                3_f32,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                2_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![23_f32, 32_f32, 41_f32])
+    }
+
+    #[test]
+    fn sgbmv1() {
+        // col major band test, kl = 1, ku = 2
+        //
+        // [1 2 3 0 0]
+        // [4 5 6 7 0]
+        // [0 8 9 1 2]
+        // [0 0 3 4 5]
+        let a = vec![
+            0_f32, 0_f32, 1_f32, 4_f32, 0_f32, 2_f32, 5_f32, 8_f32, 3_f32, 6_f32, 9_f32, 3_f32,
+            7_f32, 1_f32, 4_f32, 0_f32, 2_f32, 5_f32, 0_f32, 0_f32,
+        ];
+        let x = vec![1_f32, 2_f32, 3_f32, 4_f32, 5_f32];
+        let mut y = vec![1_f32, 1_f32, 1_f32, 1_f32];
+        unsafe {
+            cblas_sgbmv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::NoTrans,
+                4,
+                5,
+                1,
+                2,
+                2_f32,
+                a.as_ptr(),
+                4,
+                x.as_ptr(),
+                1,
+                -1_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![27_f32, 119_f32, 113_f32, 99_f32])
+    }
+
+    #[test]
+    fn sgbmv2() {
+        // row major transposed band test, kl = 1, ku = 2
+        //
+        // [1 2 3 0 0]
+        // [4 5 6 7 0]
+        // [0 8 9 1 2]
+        // [0 0 3 4 5]
+        let a = vec![
+            0_f32, 1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32, 9_f32, 1_f32, 2_f32,
+            3_f32, 4_f32, 5_f32, 0_f32,
+        ];
+        let x = vec![1_f32, -1_f32, 2_f32, 0_f32];
+        let mut y = vec![1_f32, 2_f32, 3_f32, 4_f32, 5_f32];
+        unsafe {
+            cblas_sgbmv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::Trans,
+                4,
+                5,
+                1,
+                2,
+                1_f32,
+                a.as_ptr(),
+                4,
+                x.as_ptr(),
+                1,
+                2_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![-1_f32, 17_f32, 21_f32, 3_f32, 14_f32])
+    }
+
+    #[test]
+    fn ssymv1() {
+        // col major test, only the upper triangle is referenced
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let a = vec![
+            2_f32, 99_f32, 99_f32, 1_f32, 3_f32, 99_f32, -1_f32, 4_f32, 5_f32,
+        ];
+        let x = vec![1_f32, 2_f32, 3_f32];
+        let mut y = vec![1_f32, -1_f32, 2_f32];
+        unsafe {
+            cblas_ssymv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f32,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                3_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![5_f32, 35_f32, 50_f32])
+    }
+
+    #[test]
+    fn ssymv2() {
+        // row major test, only the lower triangle is referenced
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let a = vec![
+            2_f32, 99_f32, 99_f32, 1_f32, 3_f32, 99_f32, -1_f32, 4_f32, 5_f32,
+        ];
+        let x = vec![1_f32, 2_f32, 3_f32];
+        let mut y = vec![1_f32, -1_f32, 2_f32];
+        unsafe {
+            cblas_ssymv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                1_f32,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                0_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![1_f32, 19_f32, 22_f32])
+    }
+
+    #[test]
+    fn ssbmv1() {
+        // col major pentadiagonal test, k = 2, the upper band is stored
+        //
+        // [4 -1 2 0 0]
+        // [-1 4 -1 2 0]
+        // [2 -1 4 -1 2]
+        // [0 2 -1 4 -1]
+        // [0 0 2 -1 4]
+        let a = vec![
+            99_f32, 99_f32, 4_f32, 99_f32, -1_f32, 4_f32, 2_f32, -1_f32, 4_f32, 2_f32, -1_f32,
+            4_f32, 2_f32, -1_f32, 4_f32,
+        ];
+        let x = vec![1_f32, 2_f32, 3_f32, 4_f32, 5_f32];
+        let mut y = vec![1_f32, -1_f32, 2_f32, 0_f32, 1_f32];
+        unsafe {
+            cblas_ssbmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                5,
+                2,
+                2_f32,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                1_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![17_f32, 23_f32, 38_f32, 24_f32, 45_f32])
+    }
+
+    #[test]
+    fn ssbmv2() {
+        // row major pentadiagonal test, k = 2, the lower band is stored
+        //
+        // [4 -1 2 0 0]
+        // [-1 4 -1 2 0]
+        // [2 -1 4 -1 2]
+        // [0 2 -1 4 -1]
+        // [0 0 2 -1 4]
+        let a = vec![
+            99_f32, 99_f32, 4_f32, 99_f32, -1_f32, 4_f32, 2_f32, -1_f32, 4_f32, 2_f32, -1_f32,
+            4_f32, 2_f32, -1_f32, 4_f32,
+        ];
+        let x = vec![1_f32, 2_f32, 3_f32, 4_f32, 5_f32];
+        let mut y = vec![1_f32, -1_f32, 2_f32, 0_f32, 1_f32];
+        unsafe {
+            cblas_ssbmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                5,
+                2,
+                1_f32,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                -1_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![7_f32, 13_f32, 16_f32, 12_f32, 21_f32])
+    }
+
+    #[test]
+    fn sspmv1() {
+        // col major packed test, the upper triangle is stored
+        //
+        // [2 1 -1 3]
+        // [1 3 4 0]
+        // [-1 4 5 -2]
+        // [3 0 -2 1]
+        let ap = vec![
+            2_f32, 1_f32, 3_f32, -1_f32, 4_f32, 5_f32, 3_f32, 0_f32, -2_f32, 1_f32,
+        ];
+        let x = vec![1_f32, 2_f32, 3_f32, -1_f32];
+        let mut y = vec![1_f32, -1_f32, 2_f32, 0_f32];
+        unsafe {
+            cblas_sspmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                4,
+                2_f32,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                1_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![-3_f32, 37_f32, 50_f32, -8_f32])
+    }
+
+    #[test]
+    fn sspmv2() {
+        // row major packed test, the upper triangle is stored
+        //
+        // [2 1 -1 3]
+        // [1 3 4 0]
+        // [-1 4 5 -2]
+        // [3 0 -2 1]
+        let ap = vec![
+            2_f32, 1_f32, -1_f32, 3_f32, 3_f32, 4_f32, 0_f32, 5_f32, -2_f32, 1_f32,
+        ];
+        let x = vec![1_f32, 2_f32, 3_f32, -1_f32];
+        let mut y = vec![1_f32, -1_f32, 2_f32, 0_f32];
+        unsafe {
+            cblas_sspmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                4,
+                1_f32,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                -1_f32,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(y, vec![-3_f32, 20_f32, 22_f32, -4_f32])
+    }
+
+    #[test]
+    fn strmv1() {
+        // col major, upper, NoTrans, non-unit test
+        //
+        // [2 1 -1]
+        // [0 3 4]
+        // [0 0 5]
+        let a = vec![
+            2_f32, 99_f32, 99_f32, 1_f32, 3_f32, 99_f32, -1_f32, 4_f32, 5_f32,
+        ];
+        let mut x = vec![1_f32, 2_f32, -3_f32];
+        unsafe {
+            cblas_strmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![7_f32, -6_f32, -15_f32])
+    }
+
+    #[test]
+    fn strmv2() {
+        // row major, lower, Trans, unit test
+        //
+        // [1 0 0]
+        // [3 1 0]
+        // [-2 6 1]
+        let a = vec![
+            99_f32, 99_f32, 99_f32, 3_f32, 99_f32, 99_f32, -2_f32, 6_f32, 99_f32,
+        ];
+        let mut x = vec![1_f32, 2_f32, -3_f32];
+        unsafe {
+            cblas_strmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::Unit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![13_f32, -16_f32, -3_f32])
+    }
+
+    #[test]
+    fn strsv1() {
+        // col major, upper, NoTrans, non-unit test
+        //
+        // [2 1 -1]
+        // [0 4 4]
+        // [0 0 1]
+        let a = vec![
+            2_f32, 99_f32, 99_f32, 1_f32, 4_f32, 99_f32, -1_f32, 4_f32, 1_f32,
+        ];
+        let mut x = vec![7_f32, -4_f32, -3_f32];
+        unsafe {
+            cblas_strsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f32, 2_f32, -3_f32])
+    }
+
+    #[test]
+    fn strsv2() {
+        // row major, lower, Trans, unit test
+        //
+        // [1 0 0]
+        // [3 1 0]
+        // [-2 6 1]
+        let a = vec![
+            99_f32, 99_f32, 99_f32, 3_f32, 99_f32, 99_f32, -2_f32, 6_f32, 99_f32,
+        ];
+        let mut x = vec![13_f32, -16_f32, -3_f32];
+        unsafe {
+            cblas_strsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::Unit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f32, 2_f32, -3_f32])
+    }
+
+    #[test]
+    fn stbmv1() {
+        // col major band, upper, NoTrans, non-unit test, k = 1
+        //
+        // [2 1 0 0]
+        // [0 4 -1 0]
+        // [0 0 1 2]
+        // [0 0 0 2]
+        let a = vec![99_f32, 2_f32, 1_f32, 4_f32, -1_f32, 1_f32, 2_f32, 2_f32];
+        let mut x = vec![1_f32, 2_f32, -3_f32, 1_f32];
+        unsafe {
+            cblas_stbmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                4,
+                1,
+                a.as_ptr(),
+                2,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![4_f32, 11_f32, -1_f32, 2_f32])
+    }
+
+    #[test]
+    fn stbmv2() {
+        // row major band, lower, Trans, unit test, k = 1, x with a negative increment
+        //
+        // [1 0 0 0]
+        // [3 1 0 0]
+        // [0 6 1 0]
+        // [0 0 -2 1]
+        let a = vec![99_f32, 99_f32, 3_f32, 99_f32, 6_f32, 99_f32, -2_f32, 99_f32];
+        let mut x = vec![1_f32, -3_f32, 2_f32, 1_f32];
+        unsafe {
+            cblas_stbmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::Unit,
+                4,
+                1,
+                a.as_ptr(),
+                2,
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(x, vec![1_f32, -5_f32, -16_f32, 7_f32])
+    }
+
+    #[test]
+    fn stbsv1() {
+        // col major band, lower, NoTrans, non-unit test, k = 1
+        //
+        // [2 0 0 0]
+        // [3 4 0 0]
+        // [0 6 1 0]
+        // [0 0 -2 2]
+        let a = vec![2_f32, 3_f32, 4_f32, 6_f32, 1_f32, -2_f32, 2_f32, 99_f32];
+        let mut x = vec![2_f32, 11_f32, 9_f32, 8_f32];
+        unsafe {
+            cblas_stbsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                4,
+                1,
+                a.as_ptr(),
+                2,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f32, 2_f32, -3_f32, 1_f32])
+    }
+
+    #[test]
+    fn stbsv2() {
+        // row major band, upper, Trans, non-unit test, k = 1
+        //
+        // [2 1 0 0]
+        // [0 4 -1 0]
+        // [0 0 1 2]
+        // [0 0 0 2]
+        let a = vec![2_f32, 1_f32, 4_f32, -1_f32, 1_f32, 2_f32, 2_f32, 99_f32];
+        let mut x = vec![2_f32, 9_f32, -5_f32, -4_f32];
+        unsafe {
+            cblas_stbsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                4,
+                1,
+                a.as_ptr(),
+                2,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f32, 2_f32, -3_f32, 1_f32])
+    }
+
+    #[test]
+    fn stpmv1() {
+        // row major packed, upper, Trans, non-unit test
+        //
+        // [2 1 -1]
+        // [0 4 4]
+        // [0 0 1]
+        let ap = vec![2_f32, 1_f32, -1_f32, 4_f32, 4_f32, 1_f32];
+        let mut x = vec![1_f32, 2_f32, -3_f32];
+        unsafe {
+            cblas_stpmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![2_f32, 9_f32, 4_f32])
+    }
+
+    #[test]
+    fn stpmv2() {
+        // col major packed, lower, NoTrans, unit test
+        //
+        // [1 0 0]
+        // [3 1 0]
+        // [-2 6 1]
+        let ap = vec![99_f32, 3_f32, -2_f32, 99_f32, 6_f32, 99_f32];
+        let mut x = vec![1_f32, 2_f32, -3_f32];
+        unsafe {
+            cblas_stpmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::Unit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f32, 5_f32, 7_f32])
+    }
+
+    #[test]
+    fn stpsv1() {
+        // col major packed, upper, NoTrans, non-unit test, x with a negative increment
+        //
+        // [2 1 -1]
+        // [0 4 4]
+        // [0 0 1]
+        let ap = vec![2_f32, 1_f32, 4_f32, -1_f32, 4_f32, 1_f32];
+        let mut x = vec![-3_f32, -4_f32, 7_f32];
+        unsafe {
+            cblas_stpsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(x, vec![-3_f32, 2_f32, 1_f32])
+    }
+
+    #[test]
+    fn stpsv2() {
+        // row major packed, lower, Trans, non-unit test
+        //
+        // [2 0 0]
+        // [3 4 0]
+        // [-2 6 1]
+        let ap = vec![2_f32, 3_f32, 4_f32, -2_f32, 6_f32, 1_f32];
+        let mut x = vec![14_f32, -10_f32, -3_f32];
+        unsafe {
+            cblas_stpsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(x, vec![1_f32, 2_f32, -3_f32])
+    }
+
+    #[test]
+    fn sger1() {
+        // col major test, A := alpha * x * y^T + A
+        //
+        // [1 2 3]
+        // [3 4 5]
+        let mut a = vec![1_f32, 3_f32, 2_f32, 4_f32, 3_f32, 5_f32];
+        let x = vec![1_f32, -2_f32];
+        let y = vec![2_f32, 0_f32, 1_f32];
+        unsafe {
+            cblas_sger(
+                CBlasOrder::ColMajor,
+                2,
+                3,
+                2_f32,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(a, vec![5_f32, -5_f32, 2_f32, 4_f32, 5_f32, 1_f32])
+    }
+
+    #[test]
+    fn sger2() {
+        // row major test, A := alpha * x * y^T + A, x with a negative increment
+        //
+        // [1 2 3]
+        // [3 4 5]
+        let mut a = vec![1_f32, 2_f32, 3_f32, 3_f32, 4_f32, 5_f32];
+        let x = vec![-2_f32, 1_f32];
+        let y = vec![2_f32, 0_f32, 1_f32];
+        unsafe {
+            cblas_sger(
+                CBlasOrder::RowMajor,
+                2,
+                3,
+                -1_f32,
+                x.as_ptr(),
+                -1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(a, vec![-1_f32, 2_f32, 2_f32, 7_f32, 4_f32, 7_f32])
+    }
+
+    #[test]
+    fn ssyr_1() {
+        // col major test, only the upper triangle of A := alpha * x * x^T + A is updated
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut a = vec![
+            2_f32, 99_f32, 99_f32, 1_f32, 3_f32, 99_f32, -1_f32, 4_f32, 5_f32,
+        ];
+        let x = vec![1_f32, 2_f32, -1_f32];
+        unsafe {
+            cblas_ssyr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f32,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![4_f32, 99_f32, 99_f32, 5_f32, 11_f32, 99_f32, -3_f32, 0_f32, 7_f32]
+        )
+    }
+
+    #[test]
+    fn ssyr_2() {
+        // row major test, only the upper triangle of A := alpha * x * x^T + A is updated, x with a negative increment
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut a = vec![
+            2_f32, 1_f32, -1_f32, 99_f32, 3_f32, 4_f32, 99_f32, 99_f32, 5_f32,
+        ];
+        let x = vec![-1_f32, 2_f32, 1_f32];
+        unsafe {
+            cblas_ssyr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                -1_f32,
+                x.as_ptr(),
+                -1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![1_f32, -1_f32, 0_f32, 99_f32, -1_f32, 6_f32, 99_f32, 99_f32, 4_f32]
+        )
+    }
+
+    #[test]
+    fn ssyr2_1() {
+        // col major test, only the lower triangle of A := alpha * x * y^T + alpha * y * x^T + A is updated
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut a = vec![
+            2_f32, 1_f32, -1_f32, 99_f32, 3_f32, 4_f32, 99_f32, 99_f32, 5_f32,
+        ];
+        let x = vec![1_f32, 2_f32, -1_f32];
+        let y = vec![0_f32, 1_f32, 3_f32];
+        unsafe {
+            cblas_ssyr2(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f32,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![2_f32, 3_f32, 5_f32, 99_f32, 11_f32, 14_f32, 99_f32, 99_f32, -7_f32]
+        )
+    }
+
+    #[test]
+    fn ssyr2_2() {
+        // row major test, only the upper triangle of A := alpha * x * y^T + alpha * y * x^T + A is updated
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut a = vec![
+            2_f32, 1_f32, -1_f32, 99_f32, 3_f32, 4_f32, 99_f32, 99_f32, 5_f32,
+        ];
+        let x = vec![1_f32, 2_f32, -1_f32];
+        let y = vec![0_f32, 1_f32, 3_f32];
+        unsafe {
+            cblas_ssyr2(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                1_f32,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![2_f32, 2_f32, 2_f32, 99_f32, 7_f32, 9_f32, 99_f32, 99_f32, -1_f32]
+        )
+    }
+
+    #[test]
+    fn sspr_1() {
+        // col major packed test, the upper triangle of A := alpha * x * x^T + A is stored
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut ap = vec![2_f32, 1_f32, 3_f32, -1_f32, 4_f32, 5_f32];
+        let x = vec![1_f32, 2_f32, -1_f32];
+        unsafe {
+            cblas_sspr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f32,
+                x.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(ap, vec![4_f32, 5_f32, 11_f32, -3_f32, 0_f32, 7_f32])
+    }
+
+    #[test]
+    fn sspr_2() {
+        // row major packed test, the lower triangle of A := alpha * x * x^T + A is stored, x with a negative increment
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut ap = vec![2_f32, 1_f32, 3_f32, -1_f32, 4_f32, 5_f32];
+        let x = vec![-1_f32, 2_f32, 1_f32];
+        unsafe {
+            cblas_sspr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f32,
+                x.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(ap, vec![4_f32, 5_f32, 11_f32, -3_f32, 0_f32, 7_f32])
+    }
+
+    #[test]
+    fn sspr2_1() {
+        // col major packed test, the lower triangle of A := alpha * x * y^T + alpha * y * x^T + A is stored
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut ap = vec![2_f32, 1_f32, -1_f32, 3_f32, 4_f32, 5_f32];
+        let x = vec![1_f32, 2_f32, -1_f32];
+        let y = vec![0_f32, 1_f32, 3_f32];
+        unsafe {
+            cblas_sspr2(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f32,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(ap, vec![2_f32, 3_f32, 5_f32, 11_f32, 14_f32, -7_f32])
+    }
+
+    #[test]
+    fn sspr2_2() {
+        // row major packed test, the upper triangle of A := alpha * x * y^T + alpha * y * x^T + A is stored, y with a negative increment
+        //
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        let mut ap = vec![2_f32, 1_f32, -1_f32, 3_f32, 4_f32, 5_f32];
+        let x = vec![1_f32, 2_f32, -1_f32];
+        let y = vec![3_f32, 1_f32, 0_f32];
+        unsafe {
+            cblas_sspr2(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f32,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(ap, vec![2_f32, 3_f32, 5_f32, 11_f32, 14_f32, -7_f32])
+    }
 }
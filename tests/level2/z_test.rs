@@ -0,0 +1,2308 @@
+#[cfg(test)]
+mod z_test {
+    use roblas::common::{CBlasDiag, CBlasOrder, CBlasTranspose, CBlasUpLo, Complex64};
+    use roblas::level2::*;
+
+    #[test]
+    fn zgemv1() {
+        // col major test
+        //
+        // (1+i) * [1+i 2 3-i ] * [ 1 ] + 2 * [1-i]
+        //         [ i  4 5+2i]   [ i ]       [ 2 ]
+        //                        [2-i]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(5_f64, 2_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![Complex64::new(1_f64, -1_f64), Complex64::new(2_f64, 0_f64)];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::NoTrans,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(10_f64, 2_f64),
+                Complex64::new(12_f64, 16_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zgemv2() {
+        // row major, conjugate transposed test
+        //
+        // (1+i) * [1+i 2 3-i ]^H * [1+2i] + 2 * [1]
+        //         [ i  4 5+2i]     [ -1 ]       [0]
+        //                                       [i]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(5_f64, 2_f64),
+        ];
+        let x = vec![Complex64::new(1_f64, 2_f64), Complex64::new(-1_f64, 0_f64)];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zgemv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::ConjTrans,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(3_f64, 5_f64),
+                Complex64::new(-6_f64, 2_f64),
+                Complex64::new(-13_f64, 7_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zgemv3() {
+        // row major, conjugate without transpose test
+        //
+        // (1+i) * conj([1+i 2 3-i ]) * [ 1 ] + 2 * [1-i]
+        //              [ i  4 5+2i]    [ i ]       [ 2 ]
+        //                              [2-i]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(5_f64, 2_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![Complex64::new(1_f64, -1_f64), Complex64::new(2_f64, 0_f64)];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zgemv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::ConjNoTrans,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![Complex64::new(10_f64, 6_f64), Complex64::new(18_f64, 2_f64)]
+        )
+    }
+
+    #[test]
+    fn zgemv4() {
+        // col major, transposed test with a negative increment of y
+        //
+        // (1+i) * [1+i 2 3-i ]^T * [1+2i] + 2 * [1]
+        //         [ i  4 5+2i]     [ -1 ]       [0]
+        //                                       [i]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(5_f64, 2_f64),
+        ];
+        let x = vec![Complex64::new(1_f64, 2_f64), Complex64::new(-1_f64, 0_f64)];
+        let mut y = vec![
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(1_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zgemv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::Trans,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(-3_f64, 5_f64),
+                Complex64::new(-6_f64, 2_f64),
+                Complex64::new(-1_f64, 1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zgbmv1() {
+        // col major conjugate transposed band test, kl = 1, ku = 1
+        //
+        // [1+i  2    0 ]
+        // [3i  4-i   5 ]
+        // [ 0   6  7+2i]
+        let a = vec![
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(6_f64, 0_f64),
+            Complex64::new(5_f64, 0_f64),
+            Complex64::new(7_f64, 2_f64),
+            Complex64::new(0_f64, 0_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(2_f64, -1_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zgbmv(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::ConjTrans,
+                3,
+                3,
+                1,
+                1,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(7_f64, -5_f64),
+                Complex64::new(30_f64, -5_f64),
+                Complex64::new(30_f64, -12_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zgbmv2() {
+        // row major conjugate band test, kl = 1, ku = 1
+        //
+        // [1+i  2    0 ]
+        // [3i  4-i   5 ]
+        // [ 0   6  7+2i]
+        let a = vec![
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(5_f64, 0_f64),
+            Complex64::new(6_f64, 0_f64),
+            Complex64::new(7_f64, 2_f64),
+            Complex64::new(0_f64, 0_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(2_f64, -1_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zgbmv(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::ConjNoTrans,
+                3,
+                3,
+                1,
+                1,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(3_f64, 2_f64),
+                Complex64::new(19_f64, -7_f64),
+                Complex64::new(31_f64, -10_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhemv1() {
+        // col major test, only the lower triangle and the real parts of the diagonal are referenced
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let a = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zhemv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(2_f64, -2_f64),
+                Complex64::new(11_f64, 9_f64),
+                Complex64::new(11_f64, 9_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhemv2() {
+        // row major test, only the lower triangle and the real parts of the diagonal are referenced
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let a = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zhemv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(2_f64, -2_f64),
+                Complex64::new(11_f64, 9_f64),
+                Complex64::new(11_f64, 9_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhbmv1() {
+        // col major tridiagonal test, k = 1, the upper band is stored
+        //
+        // [2 1+i 0 0]
+        // [1-i 3 2i 0]
+        // [0 -2i 1 1-3i]
+        // [0 0 1+3i 5]
+        let a = vec![
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(1_f64, 7_f64),
+            Complex64::new(1_f64, -3_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(-1_f64, 0_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zhbmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                4,
+                1,
+                &alpha,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(2_f64, 1_f64),
+                Complex64::new(9_f64, 3_f64),
+                Complex64::new(6_f64, -1_f64),
+                Complex64::new(5_f64, 7_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhbmv2() {
+        // row major tridiagonal test, k = 1, the lower band is stored
+        //
+        // [2 1+i 0 0]
+        // [1-i 3 2i 0]
+        // [0 -2i 1 1-3i]
+        // [0 0 1+3i 5]
+        let a = vec![
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(0_f64, -2_f64),
+            Complex64::new(1_f64, 7_f64),
+            Complex64::new(1_f64, 3_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(-1_f64, 0_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zhbmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                4,
+                1,
+                &alpha,
+                a.as_ptr(),
+                2,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(2_f64, 1_f64),
+                Complex64::new(9_f64, 3_f64),
+                Complex64::new(6_f64, -1_f64),
+                Complex64::new(5_f64, 7_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhpmv1() {
+        // col major packed test, the lower triangle is stored
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let ap = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zhpmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(2_f64, -2_f64),
+                Complex64::new(11_f64, 9_f64),
+                Complex64::new(11_f64, 9_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhpmv2() {
+        // row major packed test, the upper triangle is stored
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let ap = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zhpmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(2_f64, -2_f64),
+                Complex64::new(11_f64, 9_f64),
+                Complex64::new(11_f64, 9_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztrmv1() {
+        // col major, lower, ConjNoTrans, non-unit test
+        //
+        // [2+i 0 0]
+        // [1+i 3 0]
+        // [2i -1+i 5-i]
+        let a = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(-1_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(5_f64, -1_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        unsafe {
+            cblas_ztrmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::ConjNoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(2_f64, -1_f64),
+                Complex64::new(1_f64, 2_f64),
+                Complex64::new(12_f64, -6_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztrmv2() {
+        // row major, upper, NoTrans, non-unit test, x with a negative increment
+        //
+        // [2+i 1-i 3i]
+        // [0 3 4+2i]
+        // [0 0 5-i]
+        let a = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(4_f64, 2_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(5_f64, -1_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 0_f64),
+        ];
+        unsafe {
+            cblas_ztrmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(9_f64, -7_f64),
+                Complex64::new(10_f64, 3_f64),
+                Complex64::new(6_f64, 8_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztrmv3() {
+        // col major, upper, ConjTrans, unit test
+        //
+        // [1 1-i 3i]
+        // [0 1 4+2i]
+        // [0 0 1]
+        let a = vec![
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(4_f64, 2_f64),
+            Complex64::new(99_f64, 99_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        unsafe {
+            cblas_ztrmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::Unit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(1_f64, 2_f64),
+                Complex64::new(4_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztrsv1() {
+        // col major, lower, ConjNoTrans, non-unit test
+        //
+        // [2 0 0]
+        // [1+i 1+i 0]
+        // [2i -1+i 2i]
+        let a = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(-1_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 2_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(-1_f64, -7_f64),
+        ];
+        unsafe {
+            cblas_ztrsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::ConjNoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(0_f64, 1_f64),
+                Complex64::new(2_f64, -1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztrsv2() {
+        // row major, upper, NoTrans, non-unit test, x with a negative increment
+        //
+        // [2 1-i 3i]
+        // [0 1+i 4+2i]
+        // [0 0 2i]
+        let a = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(4_f64, 2_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 2_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(2_f64, 4_f64),
+            Complex64::new(9_f64, 1_f64),
+            Complex64::new(6_f64, 7_f64),
+        ];
+        unsafe {
+            cblas_ztrsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(2_f64, -1_f64),
+                Complex64::new(0_f64, 1_f64),
+                Complex64::new(1_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztrsv3() {
+        // col major, upper, Trans, unit test
+        //
+        // [1 1-i 3i]
+        // [0 1 4+2i]
+        // [0 0 1]
+        let a = vec![
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(4_f64, 2_f64),
+            Complex64::new(99_f64, 99_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 6_f64),
+        ];
+        unsafe {
+            cblas_ztrsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::Trans,
+                CBlasDiag::Unit,
+                3,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(0_f64, 1_f64),
+                Complex64::new(2_f64, -1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztbmv1() {
+        // col major band, upper, ConjTrans, non-unit test, k = 2
+        //
+        // [2 1-i 3i 0]
+        // [0 1+i 4+2i 1]
+        // [0 0 2i -i]
+        // [0 0 0 2]
+        let a = vec![
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(4_f64, 2_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(-1_f64, 0_f64),
+        ];
+        unsafe {
+            cblas_ztbmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                4,
+                2,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(2_f64, 0_f64),
+                Complex64::new(2_f64, 2_f64),
+                Complex64::new(0_f64, -3_f64),
+                Complex64::new(-1_f64, 3_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztbmv2() {
+        // row major band, lower, ConjNoTrans, unit test, k = 2
+        //
+        // [1 0 0 0]
+        // [1+i 1 0 0]
+        // [2i -1+i 1 0]
+        // [0 1 1-i 1]
+        let a = vec![
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(-1_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(-1_f64, 0_f64),
+        ];
+        unsafe {
+            cblas_ztbmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::ConjNoTrans,
+                CBlasDiag::Unit,
+                4,
+                2,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(3_f64, -4_f64),
+                Complex64::new(2_f64, 2_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztbsv1() {
+        // row major band, upper, ConjTrans, non-unit test, k = 2
+        //
+        // [2 1-i 3i 0]
+        // [0 1+i 4+2i 1]
+        // [0 0 2i -i]
+        // [0 0 0 2]
+        let a = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(4_f64, 2_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(2_f64, 2_f64),
+            Complex64::new(0_f64, -3_f64),
+            Complex64::new(-1_f64, 3_f64),
+        ];
+        unsafe {
+            cblas_ztbsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                4,
+                2,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(0_f64, 1_f64),
+                Complex64::new(2_f64, -1_f64),
+                Complex64::new(-1_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztbsv2() {
+        // col major band, lower, NoTrans, non-unit test, k = 2, x with a negative increment
+        //
+        // [2 0 0 0]
+        // [1+i 1+i 0 0]
+        // [2i -1+i 2i 0]
+        // [0 1 1-i 2]
+        let a = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(-1_f64, 1_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(-1_f64, -2_f64),
+            Complex64::new(1_f64, 5_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        unsafe {
+            cblas_ztbsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                CBlasDiag::NonUnit,
+                4,
+                2,
+                a.as_ptr(),
+                3,
+                x.as_mut_ptr(),
+                -1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(-1_f64, 0_f64),
+                Complex64::new(2_f64, -1_f64),
+                Complex64::new(0_f64, 1_f64),
+                Complex64::new(1_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztpmv1() {
+        // row major packed, upper, ConjTrans, non-unit test
+        //
+        // [2 1-i 3i]
+        // [0 1+i 4+2i]
+        // [0 0 2i]
+        let ap = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(4_f64, 2_f64),
+            Complex64::new(0_f64, 2_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        unsafe {
+            cblas_ztpmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(2_f64, 0_f64),
+                Complex64::new(2_f64, 2_f64),
+                Complex64::new(0_f64, -3_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztpmv2() {
+        // col major packed, lower, Trans, non-unit test
+        //
+        // [2 0 0]
+        // [1+i 1+i 0]
+        // [2i -1+i 2i]
+        let ap = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(-1_f64, 1_f64),
+            Complex64::new(0_f64, 2_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        unsafe {
+            cblas_ztpmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(3_f64, 5_f64),
+                Complex64::new(-2_f64, 4_f64),
+                Complex64::new(2_f64, 4_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztpsv1() {
+        // col major packed, upper, ConjTrans, unit test
+        //
+        // [1 1-i 3i]
+        // [0 1 4+2i]
+        // [0 0 1]
+        let ap = vec![
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 3_f64),
+            Complex64::new(4_f64, 2_f64),
+            Complex64::new(99_f64, 99_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(4_f64, 0_f64),
+        ];
+        unsafe {
+            cblas_ztpsv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                CBlasDiag::Unit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(0_f64, 1_f64),
+                Complex64::new(2_f64, -1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn ztpsv2() {
+        // row major packed, lower, ConjNoTrans, non-unit test
+        //
+        // [2 0 0]
+        // [1+i 1+i 0]
+        // [2i -1+i 2i]
+        let ap = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 2_f64),
+            Complex64::new(-1_f64, 1_f64),
+            Complex64::new(0_f64, 2_f64),
+        ];
+        let mut x = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(-1_f64, -7_f64),
+        ];
+        unsafe {
+            cblas_ztpsv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::ConjNoTrans,
+                CBlasDiag::NonUnit,
+                3,
+                ap.as_ptr(),
+                x.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            x,
+            vec![
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(0_f64, 1_f64),
+                Complex64::new(2_f64, -1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zgeru1() {
+        // row major test, A := alpha * x * y^T + A
+        //
+        // [1+i 2 3-i]
+        // [i 4 5+2i]
+        let mut a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(5_f64, 2_f64),
+        ];
+        let x = vec![Complex64::new(1_f64, 1_f64), Complex64::new(0_f64, 2_f64)];
+        let y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        unsafe {
+            cblas_zgeru(
+                CBlasOrder::RowMajor,
+                2,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(3_f64, 1_f64),
+                Complex64::new(4_f64, -2_f64),
+                Complex64::new(3_f64, -3_f64),
+                Complex64::new(2_f64, 3_f64),
+                Complex64::new(8_f64, 0_f64),
+                Complex64::new(7_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zgeru2() {
+        // col major test, A := alpha * x * y^T + A
+        //
+        // [1+i 2 3-i]
+        // [i 4 5+2i]
+        let mut a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(5_f64, 2_f64),
+        ];
+        let x = vec![Complex64::new(1_f64, 1_f64), Complex64::new(0_f64, 2_f64)];
+        let y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        unsafe {
+            cblas_zgeru(
+                CBlasOrder::ColMajor,
+                2,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(3_f64, 1_f64),
+                Complex64::new(2_f64, 3_f64),
+                Complex64::new(4_f64, -2_f64),
+                Complex64::new(8_f64, 0_f64),
+                Complex64::new(3_f64, -3_f64),
+                Complex64::new(7_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zgerc1() {
+        // row major test, A := alpha * x * y^H + A
+        //
+        // [1+i 2 3-i]
+        // [i 4 5+2i]
+        let mut a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(5_f64, 2_f64),
+        ];
+        let x = vec![Complex64::new(1_f64, 1_f64), Complex64::new(0_f64, 2_f64)];
+        let y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        unsafe {
+            cblas_zgerc(
+                CBlasOrder::RowMajor,
+                2,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(3_f64, 1_f64),
+                Complex64::new(4_f64, 2_f64),
+                Complex64::new(3_f64, 1_f64),
+                Complex64::new(2_f64, 3_f64),
+                Complex64::new(4_f64, 4_f64),
+                Complex64::new(3_f64, 4_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zgerc2() {
+        // col major test, A := alpha * x * y^H + A, x with a negative increment
+        //
+        // [1+i 2 3-i]
+        // [i 4 5+2i]
+        let mut a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(5_f64, 2_f64),
+        ];
+        let x = vec![Complex64::new(0_f64, 2_f64), Complex64::new(1_f64, 1_f64)];
+        let y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        unsafe {
+            cblas_zgerc(
+                CBlasOrder::ColMajor,
+                2,
+                3,
+                &alpha,
+                x.as_ptr(),
+                -1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(3_f64, 1_f64),
+                Complex64::new(2_f64, 3_f64),
+                Complex64::new(4_f64, 2_f64),
+                Complex64::new(4_f64, 4_f64),
+                Complex64::new(3_f64, 1_f64),
+                Complex64::new(3_f64, 4_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zher_1() {
+        // row major test, only the upper triangle of A := alpha * x * x^H + A is updated
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut a = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        unsafe {
+            cblas_zher(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f64,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(4_f64, 0_f64),
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(4_f64, 1_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(5_f64, 0_f64),
+                Complex64::new(2_f64, 5_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(15_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zher_2() {
+        // col major test, only the upper triangle of A := alpha * x * x^H + A is updated
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut a = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        unsafe {
+            cblas_zher(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                -1_f64,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(1_f64, 3_f64),
+                Complex64::new(2_f64, 0_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(-2_f64, -2_f64),
+                Complex64::new(5_f64, -1_f64),
+                Complex64::new(0_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zher2_1() {
+        // row major test, only the lower triangle of A := alpha * x * y^H + conj(alpha) * y * x^H + A is updated
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut a = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let y = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        unsafe {
+            cblas_zher2(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(6_f64, 0_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(3_f64, 0_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(3_f64, 0_f64),
+                Complex64::new(5_f64, 0_f64),
+                Complex64::new(-1_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zher2_2() {
+        // col major test, only the lower triangle of A := alpha * x * y^H + conj(alpha) * y * x^H + A is updated, x with a negative increment
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut a = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 0_f64),
+        ];
+        let y = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(2_f64, -1_f64);
+        unsafe {
+            cblas_zher2(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                -1,
+                y.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(4_f64, 0_f64),
+                Complex64::new(4_f64, -1_f64),
+                Complex64::new(-3_f64, -7_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(3_f64, 0_f64),
+                Complex64::new(3_f64, 1_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(-1_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhpr_1() {
+        // col major packed test, the upper triangle of A := alpha * x * x^H + A is stored
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut ap = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        unsafe {
+            cblas_zhpr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                2_f64,
+                x.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex64::new(4_f64, 0_f64),
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(5_f64, 0_f64),
+                Complex64::new(4_f64, 1_f64),
+                Complex64::new(2_f64, 5_f64),
+                Complex64::new(15_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhpr_2() {
+        // row major packed test, the lower triangle of A := alpha * x * x^H + A is stored, x with a negative increment
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut ap = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 0_f64),
+        ];
+        unsafe {
+            cblas_zhpr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                2_f64,
+                x.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex64::new(4_f64, 0_f64),
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(5_f64, 0_f64),
+                Complex64::new(4_f64, -1_f64),
+                Complex64::new(2_f64, -5_f64),
+                Complex64::new(15_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhpr2_1() {
+        // col major packed test, the lower triangle of A := alpha * x * y^H + conj(alpha) * y * x^H + A is stored
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut ap = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let y = vec![
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        unsafe {
+            cblas_zhpr2(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex64::new(4_f64, 0_f64),
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(3_f64, -2_f64),
+                Complex64::new(5_f64, 0_f64),
+                Complex64::new(1_f64, -4_f64),
+                Complex64::new(13_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zhpr2_2() {
+        // row major packed test, the upper triangle of A := alpha * x * y^H + conj(alpha) * y * x^H + A is stored, y with a negative increment
+        //
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        let mut ap = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let y = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        unsafe {
+            cblas_zhpr2(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                y.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex64::new(4_f64, 0_f64),
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(3_f64, 2_f64),
+                Complex64::new(5_f64, 0_f64),
+                Complex64::new(1_f64, 4_f64),
+                Complex64::new(13_f64, 0_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zsymv1() {
+        // col major test, only the upper triangle is referenced
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let a = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zsymv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(1_f64, -1_f64),
+                Complex64::new(7_f64, 13_f64),
+                Complex64::new(13_f64, -1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zsymv2() {
+        // row major test, only the lower triangle is referenced
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let a = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zsymv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(1_f64, -1_f64),
+                Complex64::new(7_f64, 13_f64),
+                Complex64::new(13_f64, -1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zspmv1() {
+        // col major packed test, the lower triangle is stored
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let ap = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zspmv(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(1_f64, -1_f64),
+                Complex64::new(7_f64, 13_f64),
+                Complex64::new(13_f64, -1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zspmv2() {
+        // row major packed test, the upper triangle is stored
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let ap = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut y = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zspmv(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                ap.as_ptr(),
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1,
+            )
+        }
+        assert_eq!(
+            y,
+            vec![
+                Complex64::new(1_f64, -1_f64),
+                Complex64::new(7_f64, 13_f64),
+                Complex64::new(13_f64, -1_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zsyr_1() {
+        // col major test, only the upper triangle of A := alpha * x * x^T + A is updated
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let mut a = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        unsafe {
+            cblas_zsyr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(3_f64, 2_f64),
+                Complex64::new(99_f64, 0_f64),
+                Complex64::new(99_f64, 0_f64),
+                Complex64::new(0_f64, 3_f64),
+                Complex64::new(2_f64, -1_f64),
+                Complex64::new(99_f64, 0_f64),
+                Complex64::new(3_f64, 0_f64),
+                Complex64::new(3_f64, 4_f64),
+                Complex64::new(12_f64, -3_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zsyr_2() {
+        // row major test, only the lower triangle of A := alpha * x * x^T + A is updated, x with a negative increment
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let mut a = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(99_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let x = vec![
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        unsafe {
+            cblas_zsyr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                -1,
+                a.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(3_f64, 2_f64),
+                Complex64::new(99_f64, 0_f64),
+                Complex64::new(99_f64, 0_f64),
+                Complex64::new(0_f64, 3_f64),
+                Complex64::new(2_f64, -1_f64),
+                Complex64::new(99_f64, 0_f64),
+                Complex64::new(3_f64, 0_f64),
+                Complex64::new(3_f64, 4_f64),
+                Complex64::new(12_f64, -3_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zspr_1() {
+        // col major packed test, the lower triangle of A := alpha * x * x^T + A is stored
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let mut ap = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let x = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        unsafe {
+            cblas_zspr(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                3,
+                &alpha,
+                x.as_ptr(),
+                1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex64::new(3_f64, 2_f64),
+                Complex64::new(0_f64, 3_f64),
+                Complex64::new(3_f64, 0_f64),
+                Complex64::new(2_f64, -1_f64),
+                Complex64::new(3_f64, 4_f64),
+                Complex64::new(12_f64, -3_f64)
+            ]
+        )
+    }
+
+    #[test]
+    fn zspr_2() {
+        // row major packed test, the upper triangle of A := alpha * x * x^T + A is stored, x with a negative increment
+        //
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        let mut ap = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let x = vec![
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        unsafe {
+            cblas_zspr(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                3,
+                &alpha,
+                x.as_ptr(),
+                -1,
+                ap.as_mut_ptr(),
+            )
+        }
+        assert_eq!(
+            ap,
+            vec![
+                Complex64::new(3_f64, 2_f64),
+                Complex64::new(0_f64, 3_f64),
+                Complex64::new(3_f64, 0_f64),
+                Complex64::new(2_f64, -1_f64),
+                Complex64::new(3_f64, 4_f64),
+                Complex64::new(12_f64, -3_f64)
+            ]
+        )
+    }
+}
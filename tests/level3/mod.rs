@@ -0,0 +1,4 @@
+mod c_test;
+mod d_test;
+mod s_test;
+mod z_test;
@@ -0,0 +1,1320 @@
+#[cfg(test)]
+mod s_test {
+    use roblas::common::{CBlasDiag, CBlasOrder, CBlasSide, CBlasTranspose, CBlasUpLo};
+    use roblas::level3::*;
+
+    #[test]
+    fn sgemm1() {
+        // col major test
+        //
+        // alpha = 2, beta = 1, op(A) = A, op(B) = B
+        // A:
+        // [1 2 3]
+        // [3 4 5]
+        // B:
+        // [1 -1]
+        // [2 0]
+        // [-1 3]
+        // C:
+        // [1 0]
+        // [-2 1]
+        let a = vec![1_f32, 3_f32, 2_f32, 4_f32, 3_f32, 5_f32];
+        let b = vec![1_f32, 2_f32, -1_f32, -1_f32, 0_f32, 3_f32];
+        let mut c = vec![1_f32, -2_f32, 0_f32, 1_f32];
+        unsafe {
+            cblas_sgemm(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::NoTrans,
+                CBlasTranspose::NoTrans,
+                2,
+                2,
+                3,
+                2_f32,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                3,
+                1_f32,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(c, vec![5_f32, 10_f32, 16_f32, 25_f32]);
+    }
+
+    #[test]
+    fn sgemm2() {
+        // row major test, B is transposed
+        //
+        // alpha = 1, beta = -1, op(A) = A, op(B) = B^T
+        // A:
+        // [1 2 3]
+        // [3 4 5]
+        // B:
+        // [1 2 -1]
+        // [-1 0 3]
+        // C:
+        // [1 0]
+        // [-2 1]
+        let a = vec![1_f32, 2_f32, 3_f32, 3_f32, 4_f32, 5_f32];
+        let b = vec![1_f32, 2_f32, -1_f32, -1_f32, 0_f32, 3_f32];
+        let mut c = vec![1_f32, 0_f32, -2_f32, 1_f32];
+        unsafe {
+            cblas_sgemm(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::NoTrans,
+                CBlasTranspose::Trans,
+                2,
+                2,
+                3,
+                1_f32,
+                a.as_ptr(),
+                3,
+                b.as_ptr(),
+                3,
+                -1_f32,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(c, vec![1_f32, 8_f32, 8_f32, 11_f32]);
+    }
+
+    #[test]
+    fn sgemm3() {
+        // col major test, both A and B are transposed, lda is larger than the number of rows
+        //
+        // alpha = 1, beta = 2, op(A) = A^T, op(B) = B^T
+        // A:
+        // [1 3]
+        // [2 4]
+        // [3 5]
+        // B:
+        // [1 2 -1]
+        // [-1 0 3]
+        // C:
+        // [1 0]
+        // [-2 1]
+        let a = vec![1_f32, 2_f32, 3_f32, 0_f32, 3_f32, 4_f32, 5_f32, 0_f32];
+        let b = vec![1_f32, -1_f32, 2_f32, 0_f32, -1_f32, 3_f32];
+        let mut c = vec![1_f32, -2_f32, 0_f32, 1_f32];
+        unsafe {
+            cblas_sgemm(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::Trans,
+                CBlasTranspose::Trans,
+                2,
+                2,
+                3,
+                1_f32,
+                a.as_ptr(),
+                4,
+                b.as_ptr(),
+                2,
+                2_f32,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(c, vec![4_f32, 2_f32, 8_f32, 14_f32]);
+    }
+
+    #[test]
+    fn sgemm4() {
+        // row major test, beta is zero so c is not read
+        //
+        // alpha = 3, beta = 0, op(A) = A^T, op(B) = B
+        // A:
+        // [1 3]
+        // [2 4]
+        // [3 5]
+        // B:
+        // [1 -1]
+        // [2 0]
+        // [-1 3]
+        let a = vec![1_f32, 3_f32, 2_f32, 4_f32, 3_f32, 5_f32];
+        let b = vec![1_f32, -1_f32, 2_f32, 0_f32, -1_f32, 3_f32];
+        let mut c = vec![f32::NAN; 4];
+        unsafe {
+            cblas_sgemm(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::Trans,
+                CBlasTranspose::NoTrans,
+                2,
+                2,
+                3,
+                3_f32,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                0_f32,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(c, vec![6_f32, 24_f32, 18_f32, 36_f32]);
+    }
+
+    #[test]
+    fn sgemm_naive() {
+        // compare every layout and transpose combination with a naive triple loop
+        let (m, n, k) = (5_usize, 4_usize, 3_usize);
+        let (alpha, beta) = (2_f32, -1_f32);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        let order_of = |row_major: bool| {
+            if row_major {
+                CBlasOrder::RowMajor
+            } else {
+                CBlasOrder::ColMajor
+            }
+        };
+        let trans_of = |no_trans: bool| {
+            if no_trans {
+                CBlasTranspose::NoTrans
+            } else {
+                CBlasTranspose::Trans
+            }
+        };
+        for &row_major in &[false, true] {
+            for &nota in &[true, false] {
+                for &notb in &[true, false] {
+                    let a = gen(m * k, 1);
+                    let b = gen(k * n, 2);
+                    let c0 = gen(m * n, 3);
+                    // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                    let idx = |i: usize, j: usize, ld: usize| {
+                        if row_major {
+                            i * ld + j
+                        } else {
+                            i + j * ld
+                        }
+                    };
+                    // leading dimensions of the stored A, B and C
+                    let lda = match (row_major, nota) {
+                        (false, true) | (true, false) => m,
+                        _ => k,
+                    };
+                    let ldb = match (row_major, notb) {
+                        (false, true) | (true, false) => k,
+                        _ => n,
+                    };
+                    let ldc = if row_major { n } else { m };
+                    let mut expected = c0.clone();
+                    for i in 0..m {
+                        for j in 0..n {
+                            let mut sum = 0_f32;
+                            for l in 0..k {
+                                let ail = if nota {
+                                    a[idx(i, l, lda)]
+                                } else {
+                                    a[idx(l, i, lda)]
+                                };
+                                let blj = if notb {
+                                    b[idx(l, j, ldb)]
+                                } else {
+                                    b[idx(j, l, ldb)]
+                                };
+                                sum += ail * blj;
+                            }
+                            expected[idx(i, j, ldc)] = alpha * sum + beta * c0[idx(i, j, ldc)];
+                        }
+                    }
+                    let mut c = c0.clone();
+                    unsafe {
+                        cblas_sgemm(
+                            order_of(row_major),
+                            trans_of(nota),
+                            trans_of(notb),
+                            m as i32,
+                            n as i32,
+                            k as i32,
+                            alpha,
+                            a.as_ptr(),
+                            lda as i32,
+                            b.as_ptr(),
+                            ldb as i32,
+                            beta,
+                            c.as_mut_ptr(),
+                            ldc as i32,
+                        )
+                    }
+                    assert_eq!(c, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ssymm1() {
+        // col major test, A is on the left and its upper triangle is referenced
+        //
+        // alpha = 2, beta = 1
+        // A:
+        // [2 1 -1]
+        // [1 3 4]
+        // [-1 4 5]
+        // B:
+        // [1 -1]
+        // [2 0]
+        // [-1 3]
+        // C:
+        // [1 0]
+        // [-2 1]
+        // [0 2]
+        let a = vec![
+            2_f32, 99_f32, 99_f32, 1_f32, 3_f32, 99_f32, -1_f32, 4_f32, 5_f32,
+        ];
+        let b = vec![1_f32, 2_f32, -1_f32, -1_f32, 0_f32, 3_f32];
+        let mut c = vec![1_f32, -2_f32, 0_f32, 0_f32, 1_f32, 2_f32];
+        unsafe {
+            cblas_ssymm(
+                CBlasOrder::ColMajor,
+                CBlasSide::Left,
+                CBlasUpLo::Upper,
+                3,
+                2,
+                2_f32,
+                a.as_ptr(),
+                3,
+                b.as_ptr(),
+                3,
+                1_f32,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(c, vec![11_f32, 4_f32, 4_f32, -10_f32, 23_f32, 34_f32]);
+    }
+
+    #[test]
+    fn ssymm2() {
+        // row major test, A is on the right and its lower triangle is referenced
+        //
+        // alpha = 1, beta = -1
+        // A:
+        // [1 2]
+        // [2 -3]
+        // B:
+        // [1 -1]
+        // [2 0]
+        // [-1 3]
+        // C:
+        // [1 0]
+        // [-2 1]
+        // [0 2]
+        let a = vec![1_f32, 99_f32, 2_f32, -3_f32];
+        let b = vec![1_f32, -1_f32, 2_f32, 0_f32, -1_f32, 3_f32];
+        let mut c = vec![1_f32, 0_f32, -2_f32, 1_f32, 0_f32, 2_f32];
+        unsafe {
+            cblas_ssymm(
+                CBlasOrder::RowMajor,
+                CBlasSide::Right,
+                CBlasUpLo::Lower,
+                3,
+                2,
+                1_f32,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                -1_f32,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(c, vec![-2_f32, 5_f32, 4_f32, 3_f32, 5_f32, -13_f32]);
+    }
+
+    #[test]
+    fn ssyrk1() {
+        // col major test, only the upper triangle of C is updated
+        //
+        // alpha = 2, beta = 1
+        // A:
+        // [1 2]
+        // [3 -1]
+        // [0 2]
+        // C:
+        // [1 2 3]
+        // [2 4 5]
+        // [3 5 6]
+        let a = vec![1_f32, 3_f32, 0_f32, 2_f32, -1_f32, 2_f32];
+        let mut c = vec![
+            1_f32, 99_f32, 99_f32, 2_f32, 4_f32, 99_f32, 3_f32, 5_f32, 6_f32,
+        ];
+        unsafe {
+            cblas_ssyrk(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                3,
+                2,
+                2_f32,
+                a.as_ptr(),
+                3,
+                1_f32,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![11_f32, 99_f32, 99_f32, 4_f32, 24_f32, 99_f32, 11_f32, 1_f32, 14_f32]
+        );
+    }
+
+    #[test]
+    fn ssyrk2() {
+        // row major test, only the lower triangle of C is updated, A is transposed
+        //
+        // alpha = 1, beta = -1
+        // A:
+        // [1 3 0]
+        // [2 -1 2]
+        // C:
+        // [1 2 3]
+        // [2 4 5]
+        // [3 5 6]
+        let a = vec![1_f32, 3_f32, 0_f32, 2_f32, -1_f32, 2_f32];
+        let mut c = vec![
+            1_f32, 99_f32, 99_f32, 2_f32, 4_f32, 99_f32, 3_f32, 5_f32, 6_f32,
+        ];
+        unsafe {
+            cblas_ssyrk(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                3,
+                2,
+                1_f32,
+                a.as_ptr(),
+                3,
+                -1_f32,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![4_f32, 99_f32, 99_f32, -1_f32, 6_f32, 99_f32, 1_f32, -7_f32, -2_f32]
+        );
+    }
+
+    #[test]
+    fn ssyr2k1() {
+        // col major test, only the lower triangle of C is updated, A and B are transposed
+        //
+        // alpha = 2, beta = 0
+        // A:
+        // [1 3 0]
+        // [2 -1 2]
+        // B:
+        // [2 1 -1]
+        // [0 1 3]
+        // C:
+        // [1 2 3]
+        // [2 4 5]
+        // [3 5 6]
+        let a = vec![1_f32, 2_f32, 3_f32, -1_f32, 0_f32, 2_f32];
+        let b = vec![2_f32, 0_f32, 1_f32, 1_f32, -1_f32, 3_f32];
+        let mut c = vec![
+            1_f32, 2_f32, 3_f32, 99_f32, 4_f32, 5_f32, 99_f32, 99_f32, 6_f32,
+        ];
+        unsafe {
+            cblas_ssyr2k(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                3,
+                2,
+                2_f32,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                0_f32,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![8_f32, 18_f32, 10_f32, 99_f32, 8_f32, -8_f32, 99_f32, 99_f32, 24_f32]
+        );
+    }
+
+    #[test]
+    fn ssyr2k2() {
+        // row major test, only the upper triangle of C is updated
+        //
+        // alpha = -1, beta = 2
+        // A:
+        // [1 2]
+        // [3 -1]
+        // [0 2]
+        // B:
+        // [2 0]
+        // [1 1]
+        // [-1 3]
+        // C:
+        // [1 2 3]
+        // [2 4 5]
+        // [3 5 6]
+        let a = vec![1_f32, 2_f32, 3_f32, -1_f32, 0_f32, 2_f32];
+        let b = vec![2_f32, 0_f32, 1_f32, 1_f32, -1_f32, 3_f32];
+        let mut c = vec![
+            1_f32, 2_f32, 3_f32, 99_f32, 4_f32, 5_f32, 99_f32, 99_f32, 6_f32,
+        ];
+        unsafe {
+            cblas_ssyr2k(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                3,
+                2,
+                -1_f32,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                2_f32,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![-2_f32, -5_f32, 1_f32, 99_f32, 4_f32, 14_f32, 99_f32, 99_f32, 0_f32]
+        );
+    }
+
+    // Check every side, uplo, transpose, diag and layout combination of trmm, or trsm if `solve`.
+    // The expected result is computed with a naive triple loop on the whole triangular matrix,
+    // and the diagonal is chosen so that the solutions of trsm are exact.
+    fn check_trmm(solve: bool) {
+        let (m, n) = (4_usize, 3_usize);
+        let alpha = 2_f32;
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            _ => CBlasTranspose::ConjTrans,
+        };
+        for &row_major in &[false, true] {
+            for s in 0..2 {
+                for u in 0..2 {
+                    for t in 0..3 {
+                        for d in 0..2 {
+                            let (left, upper, unit) = (s == 0, u == 0, d == 1);
+                            // ConjTrans is the same as Trans for real matrices
+                            let no_trans = t == 0;
+                            let k = if left { m } else { n };
+                            // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                            let idx = |i: usize, j: usize, ld: usize| {
+                                if row_major {
+                                    i * ld + j
+                                } else {
+                                    i + j * ld
+                                }
+                            };
+                            let diag_vals = [1_f32, 2_f32, -1_f32, 4_f32];
+                            // the stored A, with garbage in the unreferenced parts, and the whole triangular matrix
+                            let mut a = gen(k * k, 1);
+                            let mut full = vec![0_f32; k * k];
+                            for i in 0..k {
+                                for j in 0..k {
+                                    let pos = idx(i, j, k);
+                                    if i == j {
+                                        a[pos] = if unit { 99_f32 } else { diag_vals[i] };
+                                        full[i + j * k] = if unit { 1_f32 } else { diag_vals[i] };
+                                    } else if (i < j) == upper {
+                                        full[i + j * k] = a[pos];
+                                    } else {
+                                        a[pos] = 99_f32;
+                                    }
+                                }
+                            }
+                            // op(A)(i, j)
+                            let op_a = |i: usize, j: usize| {
+                                if no_trans {
+                                    full[i + j * k]
+                                } else {
+                                    full[j + i * k]
+                                }
+                            };
+                            let ldb = if row_major { n } else { m };
+                            // `x` is the input of trmm and the expected output of trsm
+                            let x = gen(m * n, 2);
+                            let mut product = vec![0_f32; m * n];
+                            for i in 0..m {
+                                for j in 0..n {
+                                    let mut sum = 0_f32;
+                                    for l in 0..k {
+                                        sum += if left {
+                                            op_a(i, l) * x[idx(l, j, ldb)]
+                                        } else {
+                                            x[idx(i, l, ldb)] * op_a(l, j)
+                                        };
+                                    }
+                                    product[idx(i, j, ldb)] = sum;
+                                }
+                            }
+                            // trmm: B := alpha * op(A) * x, trsm: op(A) * X = alpha * product
+                            let (mut b, expected): (Vec<f32>, Vec<f32>) = if solve {
+                                (product, x.iter().map(|&v| alpha * v).collect())
+                            } else {
+                                (x, product.iter().map(|&v| alpha * v).collect())
+                            };
+                            let order = if row_major {
+                                CBlasOrder::RowMajor
+                            } else {
+                                CBlasOrder::ColMajor
+                            };
+                            let routine = if solve { cblas_strsm } else { cblas_strmm };
+                            unsafe {
+                                routine(
+                                    order,
+                                    if left {
+                                        CBlasSide::Left
+                                    } else {
+                                        CBlasSide::Right
+                                    },
+                                    if upper {
+                                        CBlasUpLo::Upper
+                                    } else {
+                                        CBlasUpLo::Lower
+                                    },
+                                    trans_of(t),
+                                    if unit {
+                                        CBlasDiag::Unit
+                                    } else {
+                                        CBlasDiag::NonUnit
+                                    },
+                                    m as i32,
+                                    n as i32,
+                                    alpha,
+                                    a.as_ptr(),
+                                    k as i32,
+                                    b.as_mut_ptr(),
+                                    ldb as i32,
+                                )
+                            }
+                            assert_eq!(b, expected);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn strmm_naive() {
+        check_trmm(false);
+    }
+
+    #[test]
+    fn strsm_naive() {
+        check_trmm(true);
+    }
+
+    #[test]
+    fn sgemmt1() {
+        // col major test, only the upper triangle of C is updated
+        //
+        // alpha = 1, beta = 1, op(A) = A, op(B) = B
+        // A:
+        // [1 2]
+        // [3 4]
+        // B:
+        // [1 0]
+        // [2 1]
+        // C:
+        // [1 1]
+        // [1 1]
+        // A * B:
+        // [5  2]
+        // [11 4]
+        let a = vec![1_f32, 3_f32, 2_f32, 4_f32];
+        let b = vec![1_f32, 2_f32, 0_f32, 1_f32];
+        let mut c = vec![1_f32; 4];
+        unsafe {
+            cblas_sgemmt(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                CBlasTranspose::NoTrans,
+                2,
+                2,
+                1_f32,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                1_f32,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        // the strictly lower part is left untouched
+        assert_eq!(c, vec![6_f32, 1_f32, 3_f32, 5_f32]);
+    }
+
+    #[test]
+    fn sgemmt_naive() {
+        // compare every layout, triangle and transpose combination with a naive triple loop, the
+        // other triangle of C must be left untouched
+        let (n, k) = (4_usize, 3_usize);
+        let (alpha, beta) = (2_f32, -1_f32);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        let trans_of = |t: usize| {
+            if t == 0 {
+                CBlasTranspose::NoTrans
+            } else {
+                CBlasTranspose::Trans
+            }
+        };
+        for &row_major in &[false, true] {
+            for &upper in &[true, false] {
+                for ta in 0..2 {
+                    for tb in 0..2 {
+                        let a = gen(n * k, 1);
+                        let b = gen(k * n, 2);
+                        let c0 = gen(n * n, 3);
+                        let (nota, notb) = (ta == 0, tb == 0);
+                        // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                        let idx = |i: usize, j: usize, ld: usize| {
+                            if row_major {
+                                i * ld + j
+                            } else {
+                                i + j * ld
+                            }
+                        };
+                        // leading dimensions of the stored A and B
+                        let lda = if row_major == nota { k } else { n };
+                        let ldb = if row_major == notb { n } else { k };
+                        let mut expected = c0.clone();
+                        for i in 0..n {
+                            for j in 0..n {
+                                if (upper && i > j) || (!upper && i < j) {
+                                    continue;
+                                }
+                                let mut sum = 0_f32;
+                                for l in 0..k {
+                                    let ail = if nota {
+                                        a[idx(i, l, lda)]
+                                    } else {
+                                        a[idx(l, i, lda)]
+                                    };
+                                    let blj = if notb {
+                                        b[idx(l, j, ldb)]
+                                    } else {
+                                        b[idx(j, l, ldb)]
+                                    };
+                                    sum += ail * blj;
+                                }
+                                expected[idx(i, j, n)] = alpha * sum + beta * c0[idx(i, j, n)];
+                            }
+                        }
+                        let mut c = c0.clone();
+                        let order = if row_major {
+                            CBlasOrder::RowMajor
+                        } else {
+                            CBlasOrder::ColMajor
+                        };
+                        let uplo = if upper {
+                            CBlasUpLo::Upper
+                        } else {
+                            CBlasUpLo::Lower
+                        };
+                        unsafe {
+                            cblas_sgemmt(
+                                order,
+                                uplo,
+                                trans_of(ta),
+                                trans_of(tb),
+                                n as i32,
+                                k as i32,
+                                alpha,
+                                a.as_ptr(),
+                                lda as i32,
+                                b.as_ptr(),
+                                ldb as i32,
+                                beta,
+                                c.as_mut_ptr(),
+                                n as i32,
+                            )
+                        }
+                        assert_eq!(c, expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sgemm_batch() {
+        // two groups, the first one has two col major m = 3, n = 2, k = 4 products with
+        // op(A) = A^T, and the second one has three m = 2, n = 3, k = 1 products with
+        // op(B) = B^T, compare
+        // them with sgemm
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        let trans_a = vec![CBlasTranspose::Trans, CBlasTranspose::NoTrans];
+        let trans_b = vec![CBlasTranspose::NoTrans, CBlasTranspose::Trans];
+        let (m, n, k) = (vec![3, 2], vec![2, 3], vec![4, 1]);
+        let (lda, ldb, ldc) = (vec![4, 2], vec![4, 3], vec![3, 2]);
+        let alphas = vec![2_f32, 1_f32];
+        let betas = vec![-1_f32, 0_f32];
+        let group_size = vec![2_i32, 3];
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        let mut c = Vec::new();
+        for (g, &size) in group_size.iter().enumerate() {
+            for i in 0..size {
+                let seed = 3 * g + i as usize;
+                a.push(gen((k[g] * m[g]) as usize, seed));
+                b.push(gen((k[g] * n[g]) as usize, seed + 1));
+                c.push(gen((m[g] * n[g]) as usize, seed + 2));
+            }
+        }
+        let mut expected = c.clone();
+        let mut first = 0;
+        for (g, &size) in group_size.iter().enumerate() {
+            for i in first..first + size as usize {
+                unsafe {
+                    cblas_sgemm(
+                        CBlasOrder::ColMajor,
+                        if g == 0 {
+                            CBlasTranspose::Trans
+                        } else {
+                            CBlasTranspose::NoTrans
+                        },
+                        if g == 0 {
+                            CBlasTranspose::NoTrans
+                        } else {
+                            CBlasTranspose::Trans
+                        },
+                        m[g],
+                        n[g],
+                        k[g],
+                        alphas[g],
+                        a[i].as_ptr(),
+                        lda[g],
+                        b[i].as_ptr(),
+                        ldb[g],
+                        betas[g],
+                        expected[i].as_mut_ptr(),
+                        ldc[g],
+                    )
+                }
+            }
+            first += size as usize;
+        }
+        let a_array: Vec<_> = a.iter().map(|x| x.as_ptr()).collect();
+        let b_array: Vec<_> = b.iter().map(|x| x.as_ptr()).collect();
+        let c_array: Vec<_> = c.iter_mut().map(|x| x.as_mut_ptr()).collect();
+        unsafe {
+            cblas_sgemm_batch(
+                CBlasOrder::ColMajor,
+                trans_a.as_ptr(),
+                trans_b.as_ptr(),
+                m.as_ptr(),
+                n.as_ptr(),
+                k.as_ptr(),
+                alphas.as_ptr(),
+                a_array.as_ptr(),
+                lda.as_ptr(),
+                b_array.as_ptr(),
+                ldb.as_ptr(),
+                betas.as_ptr(),
+                c_array.as_ptr(),
+                ldc.as_ptr(),
+                2,
+                group_size.as_ptr(),
+            )
+        }
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn sgemm_batch_strided() {
+        // four row major m = 3, n = 2, k = 4 products whose matrices are stored one after another
+        // with some padding, compare them with sgemm
+        let (m, n, k, batch) = (3_usize, 2_usize, 4_usize, 4_usize);
+        let (stride_a, stride_b, stride_c) = (m * k + 1, k * n + 2, m * n + 3);
+        let (alpha, beta) = (2_f32, -1_f32);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        let a = gen(stride_a * batch, 1);
+        let b = gen(stride_b * batch, 2);
+        let c0 = gen(stride_c * batch, 3);
+        let mut expected = c0.clone();
+        for i in 0..batch {
+            unsafe {
+                cblas_sgemm(
+                    CBlasOrder::RowMajor,
+                    CBlasTranspose::Trans,
+                    CBlasTranspose::NoTrans,
+                    m as i32,
+                    n as i32,
+                    k as i32,
+                    alpha,
+                    a[i * stride_a..].as_ptr(),
+                    m as i32,
+                    b[i * stride_b..].as_ptr(),
+                    n as i32,
+                    beta,
+                    expected[i * stride_c..].as_mut_ptr(),
+                    n as i32,
+                )
+            }
+        }
+        let mut c = c0.clone();
+        unsafe {
+            cblas_sgemm_batch_strided(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::Trans,
+                CBlasTranspose::NoTrans,
+                m as i32,
+                n as i32,
+                k as i32,
+                alpha,
+                a.as_ptr(),
+                m as i32,
+                stride_a as i32,
+                b.as_ptr(),
+                n as i32,
+                stride_b as i32,
+                beta,
+                c.as_mut_ptr(),
+                n as i32,
+                stride_c as i32,
+                batch as i32,
+            )
+        }
+        // the padding between matrices c is left untouched
+        assert_eq!(c, expected);
+        assert_ne!(c, c0);
+    }
+
+    #[test]
+    fn strmm_oop_naive() {
+        // compare every layout, side, triangle, transpose and diagonal combination with strmm
+        let (m, n) = (4_usize, 3_usize);
+        let (alpha, beta) = (2_f32, -1_f32);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            _ => CBlasTranspose::ConjTrans,
+        };
+        let order_of = |row_major: bool| {
+            if row_major {
+                CBlasOrder::RowMajor
+            } else {
+                CBlasOrder::ColMajor
+            }
+        };
+        let side_of = |left: bool| {
+            if left {
+                CBlasSide::Left
+            } else {
+                CBlasSide::Right
+            }
+        };
+        let uplo_of = |upper: bool| {
+            if upper {
+                CBlasUpLo::Upper
+            } else {
+                CBlasUpLo::Lower
+            }
+        };
+        for &row_major in &[false, true] {
+            for &left in &[true, false] {
+                for &upper in &[true, false] {
+                    for t in 0..3 {
+                        for &unit in &[false, true] {
+                            let k = if left { m } else { n };
+                            let ldb = if row_major { n } else { m };
+                            let a = gen(k * k, 1);
+                            let b = gen(m * n, 2);
+                            let c0 = gen(m * n, 3);
+                            let diag_of = || {
+                                if unit {
+                                    CBlasDiag::Unit
+                                } else {
+                                    CBlasDiag::NonUnit
+                                }
+                            };
+                            // expected = (alpha * op(A) * B) + beta * C
+                            let mut expected = b.clone();
+                            let mut c = c0.clone();
+                            unsafe {
+                                cblas_strmm(
+                                    order_of(row_major),
+                                    side_of(left),
+                                    uplo_of(upper),
+                                    trans_of(t),
+                                    diag_of(),
+                                    m as i32,
+                                    n as i32,
+                                    alpha,
+                                    a.as_ptr(),
+                                    k as i32,
+                                    expected.as_mut_ptr(),
+                                    ldb as i32,
+                                );
+                                cblas_strmm_oop(
+                                    order_of(row_major),
+                                    side_of(left),
+                                    uplo_of(upper),
+                                    trans_of(t),
+                                    diag_of(),
+                                    m as i32,
+                                    n as i32,
+                                    alpha,
+                                    a.as_ptr(),
+                                    k as i32,
+                                    b.as_ptr(),
+                                    ldb as i32,
+                                    beta,
+                                    c.as_mut_ptr(),
+                                    ldb as i32,
+                                );
+                            }
+                            for (e, &c0) in expected.iter_mut().zip(c0.iter()) {
+                                *e += beta * c0;
+                            }
+                            assert_eq!(c, expected);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sspmm_naive() {
+        // compare every layout, side and triangle combination with ssymm
+        let (m, n) = (4_usize, 3_usize);
+        let (alpha, beta) = (2_f32, -1_f32);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        let order_of = |row_major: bool| {
+            if row_major {
+                CBlasOrder::RowMajor
+            } else {
+                CBlasOrder::ColMajor
+            }
+        };
+        let side_of = |left: bool| {
+            if left {
+                CBlasSide::Left
+            } else {
+                CBlasSide::Right
+            }
+        };
+        let uplo_of = |upper: bool| {
+            if upper {
+                CBlasUpLo::Upper
+            } else {
+                CBlasUpLo::Lower
+            }
+        };
+        for &row_major in &[false, true] {
+            for &left in &[true, false] {
+                for &upper in &[true, false] {
+                    let k = if left { m } else { n };
+                    let ldb = if row_major { n } else { m };
+                    // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                    let idx = |i: usize, j: usize, ld: usize| {
+                        if row_major {
+                            i * ld + j
+                        } else {
+                            i + j * ld
+                        }
+                    };
+                    let a = gen(k * k, 1);
+                    let b = gen(m * n, 2);
+                    let c0 = gen(m * n, 3);
+                    // pack the `uplo` triangle of the stored A in the storage order of `order`
+                    let mut ap = Vec::new();
+                    for outer in 0..k {
+                        for inner in 0..k {
+                            let (i, j) = if row_major {
+                                (outer, inner)
+                            } else {
+                                (inner, outer)
+                            };
+                            if i == j || (i < j) == upper {
+                                ap.push(a[idx(i, j, k)]);
+                            }
+                        }
+                    }
+                    let mut expected = c0.clone();
+                    let mut c = c0.clone();
+                    unsafe {
+                        cblas_ssymm(
+                            order_of(row_major),
+                            side_of(left),
+                            uplo_of(upper),
+                            m as i32,
+                            n as i32,
+                            alpha,
+                            a.as_ptr(),
+                            k as i32,
+                            b.as_ptr(),
+                            ldb as i32,
+                            beta,
+                            expected.as_mut_ptr(),
+                            ldb as i32,
+                        );
+                        cblas_sspmm(
+                            order_of(row_major),
+                            side_of(left),
+                            uplo_of(upper),
+                            m as i32,
+                            n as i32,
+                            alpha,
+                            ap.as_ptr(),
+                            b.as_ptr(),
+                            ldb as i32,
+                            beta,
+                            c.as_mut_ptr(),
+                            ldb as i32,
+                        );
+                    }
+                    assert_eq!(c, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sgbmm_naive() {
+        // compare every layout, side and transpose combination with sgemm on the dense A
+        let (m, n, k, kl, ku) = (5_usize, 4_usize, 6_usize, 1_usize, 2_usize);
+        // one more row than needed in the band storage
+        let lda_band = kl + ku + 2;
+        let (alpha, beta) = (2_f32, -1_f32);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            _ => CBlasTranspose::ConjTrans,
+        };
+        let order_of = |row_major: bool| {
+            if row_major {
+                CBlasOrder::RowMajor
+            } else {
+                CBlasOrder::ColMajor
+            }
+        };
+        let side_of = |left: bool| {
+            if left {
+                CBlasSide::Left
+            } else {
+                CBlasSide::Right
+            }
+        };
+        for &row_major in &[false, true] {
+            for &left in &[true, false] {
+                for t in 0..3 {
+                    let no_trans = t == 0;
+                    // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                    let idx = |i: usize, j: usize, ld: usize| {
+                        if row_major {
+                            i * ld + j
+                        } else {
+                            i + j * ld
+                        }
+                    };
+                    // op(A) is m by k on the left and k by n on the right
+                    let (op_rows, op_cols) = if left { (m, k) } else { (k, n) };
+                    let (rows, cols) = if no_trans {
+                        (op_rows, op_cols)
+                    } else {
+                        (op_cols, op_rows)
+                    };
+                    let (b_rows, b_cols) = if left { (k, n) } else { (m, k) };
+                    let lda = if row_major { cols } else { rows };
+                    let ldb = if row_major { b_cols } else { b_rows };
+                    let ldc = if row_major { n } else { m };
+                    // the dense A and its band storage, which is filled with garbage outside the band
+                    let values = gen(rows * cols, 1);
+                    let mut dense = vec![0_f32; rows * cols];
+                    let band_len = if row_major { rows } else { cols } * lda_band;
+                    let mut band = vec![99_f32; band_len];
+                    for i in 0..rows {
+                        for j in 0..cols {
+                            if i + ku >= j && i <= j + kl {
+                                dense[idx(i, j, lda)] = values[idx(i, j, lda)];
+                                let pos = if row_major {
+                                    i * lda_band + kl + j - i
+                                } else {
+                                    ku + i - j + j * lda_band
+                                };
+                                band[pos] = values[idx(i, j, lda)];
+                            }
+                        }
+                    }
+                    let b = gen(b_rows * b_cols, 2);
+                    let c0 = gen(m * n, 3);
+                    let mut expected = c0.clone();
+                    let mut c = c0.clone();
+                    unsafe {
+                        if left {
+                            cblas_sgemm(
+                                order_of(row_major),
+                                trans_of(t),
+                                CBlasTranspose::NoTrans,
+                                m as i32,
+                                n as i32,
+                                k as i32,
+                                alpha,
+                                dense.as_ptr(),
+                                lda as i32,
+                                b.as_ptr(),
+                                ldb as i32,
+                                beta,
+                                expected.as_mut_ptr(),
+                                ldc as i32,
+                            );
+                        } else {
+                            cblas_sgemm(
+                                order_of(row_major),
+                                CBlasTranspose::NoTrans,
+                                trans_of(t),
+                                m as i32,
+                                n as i32,
+                                k as i32,
+                                alpha,
+                                b.as_ptr(),
+                                ldb as i32,
+                                dense.as_ptr(),
+                                lda as i32,
+                                beta,
+                                expected.as_mut_ptr(),
+                                ldc as i32,
+                            );
+                        }
+                        cblas_sgbmm(
+                            order_of(row_major),
+                            side_of(left),
+                            trans_of(t),
+                            m as i32,
+                            n as i32,
+                            k as i32,
+                            kl as i32,
+                            ku as i32,
+                            alpha,
+                            band.as_ptr(),
+                            lda_band as i32,
+                            b.as_ptr(),
+                            ldb as i32,
+                            beta,
+                            c.as_mut_ptr(),
+                            ldc as i32,
+                        );
+                    }
+                    assert_eq!(c, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sgemm_blocked() {
+        // large enough to cross every cache block and leave partial register tiles
+        let (m, n, k) = (203_usize, 131_usize, 517_usize);
+        let (alpha, beta) = (2_f32, -1_f32);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<f32> {
+            (0..len)
+                .map(|i| ((i * 7 + seed) % 11) as f32 - 5_f32)
+                .collect()
+        };
+        for &(ta, tb) in &[(false, false), (true, false), (false, true), (true, true)] {
+            let (lda, ldb) = (if ta { k } else { m }, if tb { n } else { k });
+            let a = gen(m * k, 1);
+            let b = gen(k * n, 2);
+            let mut c = gen(m * n, 3);
+            let mut expected = c.clone();
+            for j in 0..n {
+                for i in 0..m {
+                    let mut sum = 0_f32;
+                    for l in 0..k {
+                        let ail = if ta { a[l + i * lda] } else { a[i + l * lda] };
+                        let blj = if tb { b[j + l * ldb] } else { b[l + j * ldb] };
+                        sum += ail * blj;
+                    }
+                    expected[i + j * m] = alpha * sum + beta * expected[i + j * m];
+                }
+            }
+            let trans_of = |t: bool| {
+                if t {
+                    CBlasTranspose::Trans
+                } else {
+                    CBlasTranspose::NoTrans
+                }
+            };
+            unsafe {
+                cblas_sgemm(
+                    CBlasOrder::ColMajor,
+                    trans_of(ta),
+                    trans_of(tb),
+                    m as i32,
+                    n as i32,
+                    k as i32,
+                    alpha,
+                    a.as_ptr(),
+                    lda as i32,
+                    b.as_ptr(),
+                    ldb as i32,
+                    beta,
+                    c.as_mut_ptr(),
+                    m as i32,
+                )
+            }
+            assert_eq!(c, expected);
+        }
+    }
+}
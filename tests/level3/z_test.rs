@@ -0,0 +1,2188 @@
+#[cfg(test)]
+mod z_test {
+    use roblas::common::{CBlasDiag, CBlasOrder, CBlasSide, CBlasTranspose, CBlasUpLo, Complex64};
+    use roblas::level3::*;
+
+    type Gemm = unsafe extern "C" fn(
+        CBlasOrder,
+        CBlasTranspose,
+        CBlasTranspose,
+        i32,
+        i32,
+        i32,
+        *const Complex64,
+        *const Complex64,
+        i32,
+        *const Complex64,
+        i32,
+        *const Complex64,
+        *mut Complex64,
+        i32,
+    );
+
+    // compare every layout and transpose combination of `gemm` with a naive triple loop
+    fn check_gemm(gemm: Gemm) {
+        let (m, n, k) = (5_usize, 4_usize, 3_usize);
+        let alpha = Complex64::new(2_f64, -1_f64);
+        let beta = Complex64::new(-1_f64, 1_f64);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 5 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            2 => CBlasTranspose::ConjTrans,
+            _ => CBlasTranspose::ConjNoTrans,
+        };
+        for &row_major in &[false, true] {
+            for ta in 0..4 {
+                for tb in 0..4 {
+                    let a = gen(m * k, 1);
+                    let b = gen(k * n, 2);
+                    let c0 = gen(m * n, 3);
+                    // NoTrans and ConjNoTrans are not transposed, ConjTrans and ConjNoTrans are conjugated
+                    let (nota, conja) = (ta == 0 || ta == 3, ta >= 2);
+                    let (notb, conjb) = (tb == 0 || tb == 3, tb >= 2);
+                    // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                    let idx = |i: usize, j: usize, ld: usize| {
+                        if row_major {
+                            i * ld + j
+                        } else {
+                            i + j * ld
+                        }
+                    };
+                    // leading dimensions of the stored A, B and C
+                    let lda = if row_major == nota { k } else { m };
+                    let ldb = if row_major == notb { n } else { k };
+                    let ldc = if row_major { n } else { m };
+                    let mut expected = c0.clone();
+                    for i in 0..m {
+                        for j in 0..n {
+                            let mut sum = Complex64::new(0_f64, 0_f64);
+                            for l in 0..k {
+                                let mut ail = if nota {
+                                    a[idx(i, l, lda)]
+                                } else {
+                                    a[idx(l, i, lda)]
+                                };
+                                let mut blj = if notb {
+                                    b[idx(l, j, ldb)]
+                                } else {
+                                    b[idx(j, l, ldb)]
+                                };
+                                if conja {
+                                    ail = ail.conj();
+                                }
+                                if conjb {
+                                    blj = blj.conj();
+                                }
+                                sum += ail * blj;
+                            }
+                            expected[idx(i, j, ldc)] = alpha * sum + beta * c0[idx(i, j, ldc)];
+                        }
+                    }
+                    let mut c = c0.clone();
+                    let order = if row_major {
+                        CBlasOrder::RowMajor
+                    } else {
+                        CBlasOrder::ColMajor
+                    };
+                    unsafe {
+                        gemm(
+                            order,
+                            trans_of(ta),
+                            trans_of(tb),
+                            m as i32,
+                            n as i32,
+                            k as i32,
+                            &alpha,
+                            a.as_ptr(),
+                            lda as i32,
+                            b.as_ptr(),
+                            ldb as i32,
+                            &beta,
+                            c.as_mut_ptr(),
+                            ldc as i32,
+                        )
+                    }
+                    assert_eq!(c, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zgemm1() {
+        // col major test
+        //
+        // alpha = 1+i, beta = 2, op(A) = A, op(B) = B
+        // A:
+        // [1+i 2 -i]
+        // [3 1-2i 2+i]
+        // B:
+        // [1 -i]
+        // [2+i 0]
+        // [-1 3-i]
+        // C:
+        // [1 i]
+        // [-2 1+i]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 1_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(-2_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zgemm(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::NoTrans,
+                CBlasTranspose::NoTrans,
+                2,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                3,
+                &beta,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(3_f64, 9_f64),
+                Complex64::new(5_f64, 1_f64),
+                Complex64::new(4_f64, -2_f64),
+                Complex64::new(11_f64, 7_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zgemm2() {
+        // row major test, A is conjugate transposed and B is conjugated
+        //
+        // alpha = 2, beta = i, op(A) = A^H, op(B) = conj(B)
+        // A:
+        // [1-i 3]
+        // [2 1+2i]
+        // [i 2-i]
+        // B:
+        // [1 -i]
+        // [2+i 0]
+        // [-1 3-i]
+        // C:
+        // [1 i]
+        // [-2 1+i]
+        let a = vec![
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(-2_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+        ];
+        let alpha = Complex64::new(2_f64, 0_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zgemm(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::ConjTrans,
+                CBlasTranspose::ConjNoTrans,
+                2,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                &beta,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(10_f64, 1_f64),
+                Complex64::new(-1_f64, -4_f64),
+                Complex64::new(2_f64, -14_f64),
+                Complex64::new(9_f64, 17_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zgemm3m1() {
+        // col major test
+        //
+        // alpha = 1-i, beta = -1, op(A) = A^T, op(B) = B^H
+        // A:
+        // [1+i 3]
+        // [2 1-2i]
+        // [-i 2+i]
+        // B:
+        // [1 2-i -1]
+        // [i 0 3+i]
+        // C:
+        // [1 i]
+        // [-2 1+i]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(2_f64, 1_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(3_f64, 1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(-2_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 1_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        let beta = Complex64::new(-1_f64, 0_f64);
+        unsafe {
+            cblas_zgemm3m(
+                CBlasOrder::ColMajor,
+                CBlasTranspose::Trans,
+                CBlasTranspose::ConjTrans,
+                2,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                b.as_ptr(),
+                2,
+                &beta,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(8_f64, -1_f64),
+                Complex64::new(3_f64, -9_f64),
+                Complex64::new(-4_f64, -5_f64),
+                Complex64::new(4_f64, -10_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zgemm3m2() {
+        // row major test
+        //
+        // alpha = 2, beta = i, op(A) = A, op(B) = B^T
+        // A:
+        // [1+i 2 -i]
+        // [3 1-2i 2+i]
+        // B:
+        // [1 2+i -1]
+        // [-i 0 3-i]
+        // C:
+        // [1 i]
+        // [-2 1+i]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(2_f64, 1_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(-2_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+        ];
+        let alpha = Complex64::new(2_f64, 0_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zgemm3m(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::NoTrans,
+                CBlasTranspose::Trans,
+                2,
+                2,
+                3,
+                &alpha,
+                a.as_ptr(),
+                3,
+                b.as_ptr(),
+                3,
+                &beta,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(10_f64, 9_f64),
+                Complex64::new(-1_f64, -8_f64),
+                Complex64::new(10_f64, -10_f64),
+                Complex64::new(13_f64, -3_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zgemm_naive() {
+        check_gemm(cblas_zgemm);
+    }
+
+    #[test]
+    fn zgemm3m_naive() {
+        check_gemm(cblas_zgemm3m);
+    }
+
+    #[test]
+    fn zsymm1() {
+        // col major test, A is on the left and its lower triangle is referenced
+        //
+        // alpha = 1+i, beta = 2
+        // A:
+        // [2+i 1+2i -i]
+        // [1+2i 3 4+i]
+        // [-i 4+i 5-2i]
+        // B:
+        // [1 -i]
+        // [2+i 0]
+        // [-1 3-i]
+        // C:
+        // [1 i]
+        // [-2 1+i]
+        // [0 2]
+        let a = vec![
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(1_f64, 2_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(4_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(5_f64, -2_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(-2_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zsymm(
+                CBlasOrder::ColMajor,
+                CBlasSide::Left,
+                CBlasUpLo::Lower,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                3,
+                b.as_ptr(),
+                3,
+                &beta,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(-3_f64, 9_f64),
+                Complex64::new(-5_f64, 7_f64),
+                Complex64::new(-5_f64, 9_f64),
+                Complex64::new(5_f64, -3_f64),
+                Complex64::new(19_f64, 15_f64),
+                Complex64::new(27_f64, 1_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zsymm2() {
+        // row major test, A is on the right and its upper triangle is referenced
+        //
+        // alpha = 2, beta = i
+        // A:
+        // [i 2-i]
+        // [2-i -3]
+        // B:
+        // [1 -i]
+        // [2+i 0]
+        // [-1 3-i]
+        // C:
+        // [1 i]
+        // [-2 1+i]
+        // [0 2]
+        let a = vec![
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(-3_f64, 0_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(-2_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(2_f64, 0_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zsymm(
+                CBlasOrder::RowMajor,
+                CBlasSide::Right,
+                CBlasUpLo::Upper,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                &beta,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(-2_f64, -1_f64),
+                Complex64::new(3_f64, 4_f64),
+                Complex64::new(-2_f64, 2_f64),
+                Complex64::new(9_f64, 1_f64),
+                Complex64::new(10_f64, -12_f64),
+                Complex64::new(-22_f64, 10_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zhemm1() {
+        // col major test, A is on the right, only its upper triangle and the real parts of the diagonal are referenced
+        //
+        // alpha = 1-i, beta = -1
+        // A:
+        // [1 2-i]
+        // [2+i -3]
+        // B:
+        // [1 -i]
+        // [2+i 0]
+        // [-1 3-i]
+        // C:
+        // [1 i]
+        // [-2 1+i]
+        // [0 2]
+        let a = vec![
+            Complex64::new(1_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(-3_f64, 7_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(-2_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        let beta = Complex64::new(-1_f64, 0_f64);
+        unsafe {
+            cblas_zhemm(
+                CBlasOrder::ColMajor,
+                CBlasSide::Right,
+                CBlasUpLo::Upper,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                3,
+                &beta,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(-1_f64, -4_f64),
+                Complex64::new(5_f64, -1_f64),
+                Complex64::new(7_f64, -5_f64),
+                Complex64::new(4_f64, -1_f64),
+                Complex64::new(4_f64, -6_f64),
+                Complex64::new(-9_f64, 15_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zhemm2() {
+        // row major test, A is on the left, only its lower triangle and the real parts of the diagonal are referenced
+        //
+        // alpha = 2, beta = i
+        // A:
+        // [2 1+2i -i]
+        // [1-2i 3 4+i]
+        // [i 4-i 5]
+        // B:
+        // [1 -i]
+        // [2+i 0]
+        // [-1 3-i]
+        // C:
+        // [1 i]
+        // [-2 1+i]
+        // [0 2]
+        let a = vec![
+            Complex64::new(2_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(1_f64, -2_f64),
+            Complex64::new(3_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(4_f64, -1_f64),
+            Complex64::new(5_f64, 7_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(-2_f64, 0_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(2_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(2_f64, 0_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zhemm(
+                CBlasOrder::RowMajor,
+                CBlasSide::Left,
+                CBlasUpLo::Lower,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                3,
+                b.as_ptr(),
+                2,
+                &beta,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(4_f64, 13_f64),
+                Complex64::new(-3_f64, -10_f64),
+                Complex64::new(6_f64, -2_f64),
+                Complex64::new(21_f64, -3_f64),
+                Complex64::new(8_f64, 6_f64),
+                Complex64::new(32_f64, -8_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zsyrk1() {
+        // col major test, only the upper triangle of C is updated
+        //
+        // alpha = 1+i, beta = 2
+        // A:
+        // [1+i 2]
+        // [3 -i]
+        // [i 2-i]
+        // C:
+        // [i 2 3-i]
+        // [2 4 5i]
+        // [3-i 5i 6]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(0_f64, 5_f64),
+            Complex64::new(6_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        let beta = Complex64::new(2_f64, 0_f64);
+        unsafe {
+            cblas_zsyrk(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                3,
+                &beta,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(2_f64, 8_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(6_f64, 4_f64),
+                Complex64::new(16_f64, 8_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(10_f64, 0_f64),
+                Complex64::new(-2_f64, 10_f64),
+                Complex64::new(18_f64, -2_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zsyrk2() {
+        // row major test, only the lower triangle of C is updated, A is transposed
+        //
+        // alpha = 2, beta = i
+        // A:
+        // [1+i 3 i]
+        // [2 -i 2-i]
+        // C:
+        // [i 2 3-i]
+        // [2 4 5i]
+        // [3-i 5i 6]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(0_f64, 5_f64),
+            Complex64::new(6_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(2_f64, 0_f64);
+        let beta = Complex64::new(0_f64, 1_f64);
+        unsafe {
+            cblas_zsyrk(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                3,
+                &beta,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(7_f64, 4_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(6_f64, 4_f64),
+                Complex64::new(16_f64, 4_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(7_f64, 1_f64),
+                Complex64::new(-7_f64, 2_f64),
+                Complex64::new(4_f64, -2_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zherk1() {
+        // col major test, only the lower triangle of C is updated
+        //
+        // alpha = 2, beta = 1
+        // A:
+        // [1+i 2]
+        // [3 -i]
+        // [i 2-i]
+        // C:
+        // [1 2+i 3]
+        // [2-i 4 -i]
+        // [3 i 6]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 7_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(4_f64, 7_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(6_f64, 7_f64),
+        ];
+        unsafe {
+            cblas_zherk(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                3,
+                2,
+                2_f64,
+                a.as_ptr(),
+                3,
+                1_f64,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(13_f64, 0_f64),
+                Complex64::new(8_f64, -11_f64),
+                Complex64::new(13_f64, -2_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(24_f64, 0_f64),
+                Complex64::new(2_f64, 11_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(18_f64, 0_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zherk2() {
+        // row major test, only the upper triangle of C is updated, A is conjugate transposed
+        //
+        // alpha = 1, beta = -1
+        // A:
+        // [1-i 3 -i]
+        // [2 i 2+i]
+        // C:
+        // [1 2+i 3]
+        // [2-i 4 -i]
+        // [3 i 6]
+        let a = vec![
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 1_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 7_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(4_f64, 7_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(6_f64, 7_f64),
+        ];
+        unsafe {
+            cblas_zherk(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                3,
+                2,
+                1_f64,
+                a.as_ptr(),
+                3,
+                -1_f64,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(5_f64, 0_f64),
+                Complex64::new(1_f64, 4_f64),
+                Complex64::new(2_f64, 1_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(6_f64, 0_f64),
+                Complex64::new(1_f64, -4_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(0_f64, 0_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zsyr2k1() {
+        // col major test, only the lower triangle of C is updated, A and B are transposed
+        //
+        // alpha = 1-i, beta = 1
+        // A:
+        // [1+i 3 i]
+        // [2 -i 2-i]
+        // B:
+        // [2 1-i -1]
+        // [i 1 3]
+        // C:
+        // [i 2 3-i]
+        // [2 4 5i]
+        // [3-i 5i 6]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let b = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(3_f64, 0_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(0_f64, 5_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(6_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(1_f64, -1_f64);
+        let beta = Complex64::new(1_f64, 0_f64);
+        unsafe {
+            cblas_zsyr2k(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::Trans,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                &beta,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(12_f64, 5_f64),
+                Complex64::new(13_f64, -11_f64),
+                Complex64::new(12_f64, -4_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(2_f64, -14_f64),
+                Complex64::new(-3_f64, 2_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(10_f64, -20_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zsyr2k2() {
+        // row major test, only the upper triangle of C is updated
+        //
+        // alpha = 2, beta = 0
+        // A:
+        // [1+i 2]
+        // [3 -i]
+        // [i 2-i]
+        // B:
+        // [2 i]
+        // [1-i 1]
+        // [-1 3]
+        // C:
+        // [i 2 3-i]
+        // [2 4 5i]
+        // [3-i 5i 6]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let b = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(3_f64, 0_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, -1_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(4_f64, 0_f64),
+            Complex64::new(0_f64, 5_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(6_f64, 0_f64),
+        ];
+        let alpha = Complex64::new(2_f64, 0_f64);
+        let beta = Complex64::new(0_f64, 0_f64);
+        unsafe {
+            cblas_zsyr2k(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::NoTrans,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                &beta,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(8_f64, 16_f64),
+                Complex64::new(22_f64, 0_f64),
+                Complex64::new(12_f64, 6_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(12_f64, -16_f64),
+                Complex64::new(0_f64, -6_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(24_f64, -16_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zher2k1() {
+        // col major test, only the upper triangle of C is updated, A and B are conjugate transposed
+        //
+        // alpha = 1+i, beta = 2
+        // A:
+        // [1-i 3 -i]
+        // [2 i 2+i]
+        // B:
+        // [2 1+i -1]
+        // [-i 1 3]
+        // C:
+        // [1 2+i 3]
+        // [2-i 4 -i]
+        // [3 i 6]
+        let a = vec![
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(2_f64, 1_f64),
+        ];
+        let b = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(3_f64, 0_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, 1_f64),
+            Complex64::new(4_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(6_f64, 7_f64),
+        ];
+        let alpha = Complex64::new(1_f64, 1_f64);
+        unsafe {
+            cblas_zher2k(
+                CBlasOrder::ColMajor,
+                CBlasUpLo::Upper,
+                CBlasTranspose::ConjTrans,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                2_f64,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(6_f64, 0_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(9_f64, 1_f64),
+                Complex64::new(10_f64, 0_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(11_f64, 5_f64),
+                Complex64::new(1_f64, -9_f64),
+                Complex64::new(32_f64, 0_f64)
+            ]
+        );
+    }
+
+    #[test]
+    fn zher2k2() {
+        // row major test, only the lower triangle of C is updated
+        //
+        // alpha = 2-i, beta = 1
+        // A:
+        // [1+i 2]
+        // [3 -i]
+        // [i 2-i]
+        // B:
+        // [2 i]
+        // [1-i 1]
+        // [-1 3]
+        // C:
+        // [1 2+i 3]
+        // [2-i 4 -i]
+        // [3 i 6]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, -1_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(2_f64, -1_f64),
+        ];
+        let b = vec![
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(1_f64, -1_f64),
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(-1_f64, 0_f64),
+            Complex64::new(3_f64, 0_f64),
+        ];
+        let mut c = vec![
+            Complex64::new(1_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(2_f64, -1_f64),
+            Complex64::new(4_f64, 7_f64),
+            Complex64::new(99_f64, 99_f64),
+            Complex64::new(3_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(6_f64, 7_f64),
+        ];
+        let alpha = Complex64::new(2_f64, -1_f64);
+        unsafe {
+            cblas_zher2k(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                3,
+                2,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                1_f64,
+                c.as_mut_ptr(),
+                3,
+            )
+        }
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(9_f64, 0_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(18_f64, -8_f64),
+                Complex64::new(20_f64, 0_f64),
+                Complex64::new(99_f64, 99_f64),
+                Complex64::new(10_f64, 8_f64),
+                Complex64::new(-7_f64, 3_f64),
+                Complex64::new(22_f64, 0_f64)
+            ]
+        );
+    }
+
+    // Check every side, uplo, transpose, diag and layout combination of trmm, or trsm if `solve`.
+    // The expected result is computed with a naive triple loop on the whole triangular matrix,
+    // and the diagonal is chosen so that the solutions of trsm are exact.
+    fn check_trmm(solve: bool) {
+        let (m, n) = (4_usize, 3_usize);
+        let alpha = Complex64::new(2_f64, 0_f64);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 5 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            2 => CBlasTranspose::ConjTrans,
+            _ => CBlasTranspose::ConjNoTrans,
+        };
+        for &row_major in &[false, true] {
+            for s in 0..2 {
+                for u in 0..2 {
+                    for t in 0..4 {
+                        for d in 0..2 {
+                            let (left, upper, unit) = (s == 0, u == 0, d == 1);
+                            // NoTrans and ConjNoTrans are not transposed, ConjTrans and ConjNoTrans are conjugated
+                            let no_trans = t == 0 || t == 3;
+                            let conj = t >= 2;
+                            let k = if left { m } else { n };
+                            // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                            let idx = |i: usize, j: usize, ld: usize| {
+                                if row_major {
+                                    i * ld + j
+                                } else {
+                                    i + j * ld
+                                }
+                            };
+                            let diag_vals = [
+                                Complex64::new(1_f64, 0_f64),
+                                Complex64::new(0_f64, 2_f64),
+                                Complex64::new(-1_f64, 0_f64),
+                                Complex64::new(0_f64, -1_f64),
+                            ];
+                            // the stored A, with garbage in the unreferenced parts, and the whole triangular matrix
+                            let mut a = gen(k * k, 1);
+                            let mut full = vec![Complex64::new(0_f64, 0_f64); k * k];
+                            for i in 0..k {
+                                for j in 0..k {
+                                    let pos = idx(i, j, k);
+                                    if i == j {
+                                        a[pos] = if unit {
+                                            Complex64::new(99_f64, 99_f64)
+                                        } else {
+                                            diag_vals[i]
+                                        };
+                                        full[i + j * k] = if unit {
+                                            Complex64::new(1_f64, 0_f64)
+                                        } else {
+                                            diag_vals[i]
+                                        };
+                                    } else if (i < j) == upper {
+                                        full[i + j * k] = a[pos];
+                                    } else {
+                                        a[pos] = Complex64::new(99_f64, 99_f64);
+                                    }
+                                }
+                            }
+                            // op(A)(i, j)
+                            let op_a = |i: usize, j: usize| {
+                                let aij = if no_trans {
+                                    full[i + j * k]
+                                } else {
+                                    full[j + i * k]
+                                };
+                                if conj {
+                                    aij.conj()
+                                } else {
+                                    aij
+                                }
+                            };
+                            let ldb = if row_major { n } else { m };
+                            // `x` is the input of trmm and the expected output of trsm
+                            let x = gen(m * n, 2);
+                            let mut product = vec![Complex64::new(0_f64, 0_f64); m * n];
+                            for i in 0..m {
+                                for j in 0..n {
+                                    let mut sum = Complex64::new(0_f64, 0_f64);
+                                    for l in 0..k {
+                                        sum += if left {
+                                            op_a(i, l) * x[idx(l, j, ldb)]
+                                        } else {
+                                            x[idx(i, l, ldb)] * op_a(l, j)
+                                        };
+                                    }
+                                    product[idx(i, j, ldb)] = sum;
+                                }
+                            }
+                            // trmm: B := alpha * op(A) * x, trsm: op(A) * X = alpha * product
+                            let (mut b, expected): (Vec<Complex64>, Vec<Complex64>) = if solve {
+                                (product, x.iter().map(|&v| alpha * v).collect())
+                            } else {
+                                (x, product.iter().map(|&v| alpha * v).collect())
+                            };
+                            let order = if row_major {
+                                CBlasOrder::RowMajor
+                            } else {
+                                CBlasOrder::ColMajor
+                            };
+                            let routine = if solve { cblas_ztrsm } else { cblas_ztrmm };
+                            unsafe {
+                                routine(
+                                    order,
+                                    if left {
+                                        CBlasSide::Left
+                                    } else {
+                                        CBlasSide::Right
+                                    },
+                                    if upper {
+                                        CBlasUpLo::Upper
+                                    } else {
+                                        CBlasUpLo::Lower
+                                    },
+                                    trans_of(t),
+                                    if unit {
+                                        CBlasDiag::Unit
+                                    } else {
+                                        CBlasDiag::NonUnit
+                                    },
+                                    m as i32,
+                                    n as i32,
+                                    &alpha,
+                                    a.as_ptr(),
+                                    k as i32,
+                                    b.as_mut_ptr(),
+                                    ldb as i32,
+                                )
+                            }
+                            assert_eq!(b, expected);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ztrmm_naive() {
+        check_trmm(false);
+    }
+
+    #[test]
+    fn ztrsm_naive() {
+        check_trmm(true);
+    }
+
+    #[test]
+    fn zgemmt1() {
+        // row major test, only the lower triangle of C is updated
+        //
+        // alpha = 1, beta = 0, op(A) = A, op(B) = B^H
+        // A:
+        // [1+i 2]
+        // [0   i]
+        // B:
+        // [1 i]
+        // [0 1]
+        // A * B^H:
+        // [1-i 2]
+        // [1   i]
+        let a = vec![
+            Complex64::new(1_f64, 1_f64),
+            Complex64::new(2_f64, 0_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+        ];
+        let b = vec![
+            Complex64::new(1_f64, 0_f64),
+            Complex64::new(0_f64, 1_f64),
+            Complex64::new(0_f64, 0_f64),
+            Complex64::new(1_f64, 0_f64),
+        ];
+        let mut c = vec![Complex64::new(9_f64, 9_f64); 4];
+        let alpha = Complex64::new(1_f64, 0_f64);
+        let beta = Complex64::new(0_f64, 0_f64);
+        unsafe {
+            cblas_zgemmt(
+                CBlasOrder::RowMajor,
+                CBlasUpLo::Lower,
+                CBlasTranspose::NoTrans,
+                CBlasTranspose::ConjTrans,
+                2,
+                2,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                &beta,
+                c.as_mut_ptr(),
+                2,
+            )
+        }
+        // the strictly upper part is left untouched
+        assert_eq!(
+            c,
+            vec![
+                Complex64::new(1_f64, -1_f64),
+                Complex64::new(9_f64, 9_f64),
+                Complex64::new(1_f64, 0_f64),
+                Complex64::new(0_f64, 1_f64),
+            ]
+        );
+    }
+
+    #[test]
+    fn zgemmt_naive() {
+        // compare every layout, triangle and transpose combination with a naive triple loop, the
+        // other triangle of C must be left untouched
+        let (n, k) = (4_usize, 3_usize);
+        let alpha = Complex64::new(2_f64, -1_f64);
+        let beta = Complex64::new(-1_f64, 1_f64);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 5 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            2 => CBlasTranspose::ConjTrans,
+            _ => CBlasTranspose::ConjNoTrans,
+        };
+        for &row_major in &[false, true] {
+            for &upper in &[true, false] {
+                for ta in 0..4 {
+                    for tb in 0..4 {
+                        let a = gen(n * k, 1);
+                        let b = gen(k * n, 2);
+                        let c0 = gen(n * n, 3);
+                        // NoTrans and ConjNoTrans are not transposed, ConjTrans and ConjNoTrans are conjugated
+                        let (nota, conja) = (ta == 0 || ta == 3, ta >= 2);
+                        let (notb, conjb) = (tb == 0 || tb == 3, tb >= 2);
+                        // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                        let idx = |i: usize, j: usize, ld: usize| {
+                            if row_major {
+                                i * ld + j
+                            } else {
+                                i + j * ld
+                            }
+                        };
+                        // leading dimensions of the stored A and B
+                        let lda = if row_major == nota { k } else { n };
+                        let ldb = if row_major == notb { n } else { k };
+                        let mut expected = c0.clone();
+                        for i in 0..n {
+                            for j in 0..n {
+                                if (upper && i > j) || (!upper && i < j) {
+                                    continue;
+                                }
+                                let mut sum = Complex64::new(0_f64, 0_f64);
+                                for l in 0..k {
+                                    let mut ail = if nota {
+                                        a[idx(i, l, lda)]
+                                    } else {
+                                        a[idx(l, i, lda)]
+                                    };
+                                    let mut blj = if notb {
+                                        b[idx(l, j, ldb)]
+                                    } else {
+                                        b[idx(j, l, ldb)]
+                                    };
+                                    if conja {
+                                        ail = ail.conj();
+                                    }
+                                    if conjb {
+                                        blj = blj.conj();
+                                    }
+                                    sum += ail * blj;
+                                }
+                                expected[idx(i, j, n)] = alpha * sum + beta * c0[idx(i, j, n)];
+                            }
+                        }
+                        let mut c = c0.clone();
+                        let order = if row_major {
+                            CBlasOrder::RowMajor
+                        } else {
+                            CBlasOrder::ColMajor
+                        };
+                        let uplo = if upper {
+                            CBlasUpLo::Upper
+                        } else {
+                            CBlasUpLo::Lower
+                        };
+                        unsafe {
+                            cblas_zgemmt(
+                                order,
+                                uplo,
+                                trans_of(ta),
+                                trans_of(tb),
+                                n as i32,
+                                k as i32,
+                                &alpha,
+                                a.as_ptr(),
+                                lda as i32,
+                                b.as_ptr(),
+                                ldb as i32,
+                                &beta,
+                                c.as_mut_ptr(),
+                                n as i32,
+                            )
+                        }
+                        assert_eq!(c, expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zgemm_batch() {
+        // two groups, the first one has two col major m = 3, n = 2, k = 4 products with
+        // op(A) = A^H, and the second one has three m = 2, n = 3, k = 1 products with
+        // op(B) = conj(B), compare
+        // them with zgemm
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 5 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let trans_a = vec![CBlasTranspose::ConjTrans, CBlasTranspose::NoTrans];
+        let trans_b = vec![CBlasTranspose::NoTrans, CBlasTranspose::ConjNoTrans];
+        let (m, n, k) = (vec![3, 2], vec![2, 3], vec![4, 1]);
+        let (lda, ldb, ldc) = (vec![4, 2], vec![4, 1], vec![3, 2]);
+        let alphas = vec![Complex64::new(2_f64, -1_f64), Complex64::new(1_f64, 0_f64)];
+        let betas = vec![Complex64::new(-1_f64, 1_f64), Complex64::new(0_f64, 0_f64)];
+        let group_size = vec![2_i32, 3];
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        let mut c = Vec::new();
+        for (g, &size) in group_size.iter().enumerate() {
+            for i in 0..size {
+                let seed = 3 * g + i as usize;
+                a.push(gen((k[g] * m[g]) as usize, seed));
+                b.push(gen((k[g] * n[g]) as usize, seed + 1));
+                c.push(gen((m[g] * n[g]) as usize, seed + 2));
+            }
+        }
+        let mut expected = c.clone();
+        let mut first = 0;
+        for (g, &size) in group_size.iter().enumerate() {
+            for i in first..first + size as usize {
+                unsafe {
+                    cblas_zgemm(
+                        CBlasOrder::ColMajor,
+                        if g == 0 {
+                            CBlasTranspose::ConjTrans
+                        } else {
+                            CBlasTranspose::NoTrans
+                        },
+                        if g == 0 {
+                            CBlasTranspose::NoTrans
+                        } else {
+                            CBlasTranspose::ConjNoTrans
+                        },
+                        m[g],
+                        n[g],
+                        k[g],
+                        &alphas[g],
+                        a[i].as_ptr(),
+                        lda[g],
+                        b[i].as_ptr(),
+                        ldb[g],
+                        &betas[g],
+                        expected[i].as_mut_ptr(),
+                        ldc[g],
+                    )
+                }
+            }
+            first += size as usize;
+        }
+        let a_array: Vec<_> = a.iter().map(|x| x.as_ptr()).collect();
+        let b_array: Vec<_> = b.iter().map(|x| x.as_ptr()).collect();
+        let c_array: Vec<_> = c.iter_mut().map(|x| x.as_mut_ptr()).collect();
+        unsafe {
+            cblas_zgemm_batch(
+                CBlasOrder::ColMajor,
+                trans_a.as_ptr(),
+                trans_b.as_ptr(),
+                m.as_ptr(),
+                n.as_ptr(),
+                k.as_ptr(),
+                alphas.as_ptr(),
+                a_array.as_ptr(),
+                lda.as_ptr(),
+                b_array.as_ptr(),
+                ldb.as_ptr(),
+                betas.as_ptr(),
+                c_array.as_ptr(),
+                ldc.as_ptr(),
+                2,
+                group_size.as_ptr(),
+            )
+        }
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn zgemm_batch_strided() {
+        // four row major m = 3, n = 2, k = 4 products whose matrices are stored one after another
+        // with some padding, compare them with zgemm
+        let (m, n, k, batch) = (3_usize, 2_usize, 4_usize, 4_usize);
+        let (stride_a, stride_b, stride_c) = (m * k + 1, k * n + 2, m * n + 3);
+        let alpha = Complex64::new(2_f64, -1_f64);
+        let beta = Complex64::new(-1_f64, 1_f64);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 5 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let a = gen(stride_a * batch, 1);
+        let b = gen(stride_b * batch, 2);
+        let c0 = gen(stride_c * batch, 3);
+        let mut expected = c0.clone();
+        for i in 0..batch {
+            unsafe {
+                cblas_zgemm(
+                    CBlasOrder::RowMajor,
+                    CBlasTranspose::ConjTrans,
+                    CBlasTranspose::NoTrans,
+                    m as i32,
+                    n as i32,
+                    k as i32,
+                    &alpha,
+                    a[i * stride_a..].as_ptr(),
+                    m as i32,
+                    b[i * stride_b..].as_ptr(),
+                    n as i32,
+                    &beta,
+                    expected[i * stride_c..].as_mut_ptr(),
+                    n as i32,
+                )
+            }
+        }
+        let mut c = c0.clone();
+        unsafe {
+            cblas_zgemm_batch_strided(
+                CBlasOrder::RowMajor,
+                CBlasTranspose::ConjTrans,
+                CBlasTranspose::NoTrans,
+                m as i32,
+                n as i32,
+                k as i32,
+                &alpha,
+                a.as_ptr(),
+                m as i32,
+                stride_a as i32,
+                b.as_ptr(),
+                n as i32,
+                stride_b as i32,
+                &beta,
+                c.as_mut_ptr(),
+                n as i32,
+                stride_c as i32,
+                batch as i32,
+            )
+        }
+        // the padding between matrices c is left untouched
+        assert_eq!(c, expected);
+        assert_ne!(c, c0);
+    }
+
+    #[test]
+    fn ztrmm_oop_naive() {
+        // compare every layout, side, triangle, transpose and diagonal combination with ztrmm
+        let (m, n) = (4_usize, 3_usize);
+        let alpha = Complex64::new(2_f64, -1_f64);
+        let beta = Complex64::new(-1_f64, 1_f64);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 5 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            2 => CBlasTranspose::ConjTrans,
+            _ => CBlasTranspose::ConjNoTrans,
+        };
+        let order_of = |row_major: bool| {
+            if row_major {
+                CBlasOrder::RowMajor
+            } else {
+                CBlasOrder::ColMajor
+            }
+        };
+        let side_of = |left: bool| {
+            if left {
+                CBlasSide::Left
+            } else {
+                CBlasSide::Right
+            }
+        };
+        let uplo_of = |upper: bool| {
+            if upper {
+                CBlasUpLo::Upper
+            } else {
+                CBlasUpLo::Lower
+            }
+        };
+        for &row_major in &[false, true] {
+            for &left in &[true, false] {
+                for &upper in &[true, false] {
+                    for t in 0..4 {
+                        for &unit in &[false, true] {
+                            let k = if left { m } else { n };
+                            let ldb = if row_major { n } else { m };
+                            let a = gen(k * k, 1);
+                            let b = gen(m * n, 2);
+                            let c0 = gen(m * n, 3);
+                            let diag_of = || {
+                                if unit {
+                                    CBlasDiag::Unit
+                                } else {
+                                    CBlasDiag::NonUnit
+                                }
+                            };
+                            // expected = (alpha * op(A) * B) + beta * C
+                            let mut expected = b.clone();
+                            let mut c = c0.clone();
+                            unsafe {
+                                cblas_ztrmm(
+                                    order_of(row_major),
+                                    side_of(left),
+                                    uplo_of(upper),
+                                    trans_of(t),
+                                    diag_of(),
+                                    m as i32,
+                                    n as i32,
+                                    &alpha,
+                                    a.as_ptr(),
+                                    k as i32,
+                                    expected.as_mut_ptr(),
+                                    ldb as i32,
+                                );
+                                cblas_ztrmm_oop(
+                                    order_of(row_major),
+                                    side_of(left),
+                                    uplo_of(upper),
+                                    trans_of(t),
+                                    diag_of(),
+                                    m as i32,
+                                    n as i32,
+                                    &alpha,
+                                    a.as_ptr(),
+                                    k as i32,
+                                    b.as_ptr(),
+                                    ldb as i32,
+                                    &beta,
+                                    c.as_mut_ptr(),
+                                    ldb as i32,
+                                );
+                            }
+                            for (e, &c0) in expected.iter_mut().zip(c0.iter()) {
+                                *e += beta * c0;
+                            }
+                            assert_eq!(c, expected);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zspmm_naive() {
+        // compare every layout, side and triangle combination with zsymm
+        let (m, n) = (4_usize, 3_usize);
+        let alpha = Complex64::new(2_f64, -1_f64);
+        let beta = Complex64::new(-1_f64, 1_f64);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 5 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let order_of = |row_major: bool| {
+            if row_major {
+                CBlasOrder::RowMajor
+            } else {
+                CBlasOrder::ColMajor
+            }
+        };
+        let side_of = |left: bool| {
+            if left {
+                CBlasSide::Left
+            } else {
+                CBlasSide::Right
+            }
+        };
+        let uplo_of = |upper: bool| {
+            if upper {
+                CBlasUpLo::Upper
+            } else {
+                CBlasUpLo::Lower
+            }
+        };
+        for &row_major in &[false, true] {
+            for &left in &[true, false] {
+                for &upper in &[true, false] {
+                    let k = if left { m } else { n };
+                    let ldb = if row_major { n } else { m };
+                    // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                    let idx = |i: usize, j: usize, ld: usize| {
+                        if row_major {
+                            i * ld + j
+                        } else {
+                            i + j * ld
+                        }
+                    };
+                    let a = gen(k * k, 1);
+                    let b = gen(m * n, 2);
+                    let c0 = gen(m * n, 3);
+                    // pack the `uplo` triangle of the stored A in the storage order of `order`
+                    let mut ap = Vec::new();
+                    for outer in 0..k {
+                        for inner in 0..k {
+                            let (i, j) = if row_major {
+                                (outer, inner)
+                            } else {
+                                (inner, outer)
+                            };
+                            if i == j || (i < j) == upper {
+                                ap.push(a[idx(i, j, k)]);
+                            }
+                        }
+                    }
+                    let mut expected = c0.clone();
+                    let mut c = c0.clone();
+                    unsafe {
+                        cblas_zsymm(
+                            order_of(row_major),
+                            side_of(left),
+                            uplo_of(upper),
+                            m as i32,
+                            n as i32,
+                            &alpha,
+                            a.as_ptr(),
+                            k as i32,
+                            b.as_ptr(),
+                            ldb as i32,
+                            &beta,
+                            expected.as_mut_ptr(),
+                            ldb as i32,
+                        );
+                        cblas_zspmm(
+                            order_of(row_major),
+                            side_of(left),
+                            uplo_of(upper),
+                            m as i32,
+                            n as i32,
+                            &alpha,
+                            ap.as_ptr(),
+                            b.as_ptr(),
+                            ldb as i32,
+                            &beta,
+                            c.as_mut_ptr(),
+                            ldb as i32,
+                        );
+                    }
+                    assert_eq!(c, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zgbmm_naive() {
+        // compare every layout, side and transpose combination with zgemm on the dense A
+        let (m, n, k, kl, ku) = (5_usize, 4_usize, 6_usize, 1_usize, 2_usize);
+        // one more row than needed in the band storage
+        let lda_band = kl + ku + 2;
+        let alpha = Complex64::new(2_f64, -1_f64);
+        let beta = Complex64::new(-1_f64, 1_f64);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 5 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            2 => CBlasTranspose::ConjTrans,
+            _ => CBlasTranspose::ConjNoTrans,
+        };
+        let order_of = |row_major: bool| {
+            if row_major {
+                CBlasOrder::RowMajor
+            } else {
+                CBlasOrder::ColMajor
+            }
+        };
+        let side_of = |left: bool| {
+            if left {
+                CBlasSide::Left
+            } else {
+                CBlasSide::Right
+            }
+        };
+        for &row_major in &[false, true] {
+            for &left in &[true, false] {
+                for t in 0..4 {
+                    let no_trans = t == 0 || t == 3;
+                    // the index of X(i, j) in a matrix whose leading dimension is `ld`
+                    let idx = |i: usize, j: usize, ld: usize| {
+                        if row_major {
+                            i * ld + j
+                        } else {
+                            i + j * ld
+                        }
+                    };
+                    // op(A) is m by k on the left and k by n on the right
+                    let (op_rows, op_cols) = if left { (m, k) } else { (k, n) };
+                    let (rows, cols) = if no_trans {
+                        (op_rows, op_cols)
+                    } else {
+                        (op_cols, op_rows)
+                    };
+                    let (b_rows, b_cols) = if left { (k, n) } else { (m, k) };
+                    let lda = if row_major { cols } else { rows };
+                    let ldb = if row_major { b_cols } else { b_rows };
+                    let ldc = if row_major { n } else { m };
+                    // the dense A and its band storage, which is filled with garbage outside the band
+                    let values = gen(rows * cols, 1);
+                    let mut dense = vec![Complex64::new(0_f64, 0_f64); rows * cols];
+                    let band_len = if row_major { rows } else { cols } * lda_band;
+                    let mut band = vec![Complex64::new(99_f64, 99_f64); band_len];
+                    for i in 0..rows {
+                        for j in 0..cols {
+                            if i + ku >= j && i <= j + kl {
+                                dense[idx(i, j, lda)] = values[idx(i, j, lda)];
+                                let pos = if row_major {
+                                    i * lda_band + kl + j - i
+                                } else {
+                                    ku + i - j + j * lda_band
+                                };
+                                band[pos] = values[idx(i, j, lda)];
+                            }
+                        }
+                    }
+                    let b = gen(b_rows * b_cols, 2);
+                    let c0 = gen(m * n, 3);
+                    let mut expected = c0.clone();
+                    let mut c = c0.clone();
+                    unsafe {
+                        if left {
+                            cblas_zgemm(
+                                order_of(row_major),
+                                trans_of(t),
+                                CBlasTranspose::NoTrans,
+                                m as i32,
+                                n as i32,
+                                k as i32,
+                                &alpha,
+                                dense.as_ptr(),
+                                lda as i32,
+                                b.as_ptr(),
+                                ldb as i32,
+                                &beta,
+                                expected.as_mut_ptr(),
+                                ldc as i32,
+                            );
+                        } else {
+                            cblas_zgemm(
+                                order_of(row_major),
+                                CBlasTranspose::NoTrans,
+                                trans_of(t),
+                                m as i32,
+                                n as i32,
+                                k as i32,
+                                &alpha,
+                                b.as_ptr(),
+                                ldb as i32,
+                                dense.as_ptr(),
+                                lda as i32,
+                                &beta,
+                                expected.as_mut_ptr(),
+                                ldc as i32,
+                            );
+                        }
+                        cblas_zgbmm(
+                            order_of(row_major),
+                            side_of(left),
+                            trans_of(t),
+                            m as i32,
+                            n as i32,
+                            k as i32,
+                            kl as i32,
+                            ku as i32,
+                            &alpha,
+                            band.as_ptr(),
+                            lda_band as i32,
+                            b.as_ptr(),
+                            ldb as i32,
+                            &beta,
+                            c.as_mut_ptr(),
+                            ldc as i32,
+                        );
+                    }
+                    assert_eq!(c, expected);
+                }
+            }
+        }
+    }
+
+    // compare `gemm` on matrices large enough to cross every cache block and leave partial
+    // register tiles with a naive triple loop
+    fn check_gemm_blocked(gemm: Gemm) {
+        let (m, n, k) = (211_usize, 67_usize, 301_usize);
+        let alpha = Complex64::new(1_f64, -2_f64);
+        let beta = Complex64::new(-1_f64, 1_f64);
+        // integer entries keep every product exact
+        let gen = |len: usize, seed: usize| -> Vec<Complex64> {
+            (0..len)
+                .map(|i| {
+                    Complex64::new(
+                        ((i * 7 + seed) % 11) as f64 - 5_f64,
+                        ((i * 3 + seed) % 7) as f64 - 3_f64,
+                    )
+                })
+                .collect()
+        };
+        let trans_of = |t: usize| match t {
+            0 => CBlasTranspose::NoTrans,
+            1 => CBlasTranspose::Trans,
+            _ => CBlasTranspose::ConjTrans,
+        };
+        // op(X)(i, j) of a col-major X with the leading dimension `ld`
+        let op = |x: &[Complex64], t: usize, i: usize, j: usize, ld: usize| match t {
+            0 => x[i + j * ld],
+            1 => x[j + i * ld],
+            _ => x[j + i * ld].conj(),
+        };
+        for ta in 0..3 {
+            for tb in 0..3 {
+                let (lda, ldb) = (if ta == 0 { m } else { k }, if tb == 0 { k } else { n });
+                let a = gen(m * k, 1);
+                let b = gen(k * n, 2);
+                let mut c = gen(m * n, 3);
+                let mut expected = c.clone();
+                for j in 0..n {
+                    for i in 0..m {
+                        let sum: Complex64 = (0..k)
+                            .map(|l| op(&a, ta, i, l, lda) * op(&b, tb, l, j, ldb))
+                            .sum();
+                        expected[i + j * m] = alpha * sum + beta * expected[i + j * m];
+                    }
+                }
+                unsafe {
+                    gemm(
+                        CBlasOrder::ColMajor,
+                        trans_of(ta),
+                        trans_of(tb),
+                        m as i32,
+                        n as i32,
+                        k as i32,
+                        &alpha,
+                        a.as_ptr(),
+                        lda as i32,
+                        b.as_ptr(),
+                        ldb as i32,
+                        &beta,
+                        c.as_mut_ptr(),
+                        m as i32,
+                    )
+                }
+                assert_eq!(c, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn zgemm_blocked() {
+        check_gemm_blocked(cblas_zgemm);
+    }
+
+    #[test]
+    fn zgemm3m_blocked() {
+        check_gemm_blocked(cblas_zgemm3m);
+    }
+}
@@ -1,2 +1,5 @@
+mod dispatch;
+mod error;
 mod level1;
 mod level2;
+mod level3;